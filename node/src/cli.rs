@@ -14,6 +14,11 @@ pub struct Cli {
     /// https://github.com/paritytech/polkadot-sdk/tree/master/substrate/client/consensus/manual-seal
     #[arg(long)]
     pub local_seal: bool,
+
+    /// Indexes the consensus values of every subnet epoch in the node's offchain database and
+    /// serves them through the `subspace_getEmissionHistory` RPC method.
+    #[arg(long)]
+    pub emission_history: bool,
 }
 
 #[derive(Debug, clap::Subcommand)]
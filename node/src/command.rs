@@ -222,9 +222,10 @@ pub fn run() -> sc_cli::Result<()> {
             let runner = cli.create_runner(&cli.run)?;
             runner.run_node_until_exit(|config| async move {
                 if cli.local_seal {
-                    crate::manual_seal_service::new_full(config).map_err(sc_cli::Error::Service)
+                    crate::manual_seal_service::new_full(config, cli.emission_history)
+                        .map_err(sc_cli::Error::Service)
                 } else {
-                    service::new_full(config).map_err(sc_cli::Error::Service)
+                    service::new_full(config, cli.emission_history).map_err(sc_cli::Error::Service)
                 }
             })
         }
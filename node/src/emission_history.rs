@@ -0,0 +1,435 @@
+//! Node-side index of the consensus values of every subnet epoch.
+//!
+//! `Incentive`, `Dividends`, `Emission`, `Trust` and the other per-UID vectors are overwritten
+//! on every epoch. When the node runs with `--emission-history`, this service listens to the
+//! `EpochFinished` events of finalized blocks, snapshots the subnet metagraph at that block into
+//! the node's offchain database and serves it through `subspace_getEmissionHistory`.
+//!
+//! The last indexed block is kept alongside the history. When the node starts behind finality,
+//! or restarts, the indexer first catches up from that block. A fresh index starts at the block
+//! finalized when the flag was first enabled.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, INVALID_PARAMS_CODE},
+};
+use node_subspace_runtime::{opaque::Block, pallet_subnet_emission, AccountId, Hash, RuntimeEvent};
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::twox_128, offchain::OffchainStorage, storage::StorageKey};
+use sp_runtime::traits::Header;
+use subspace_rpc::SubspaceRuntimeApi;
+
+/// Offchain database of a full node.
+pub type OffchainDb = <sc_service::TFullBackend<Block> as Backend<Block>>::OffchainStorage;
+
+/// Prefix of the emission history entries in the offchain database.
+const STORAGE_PREFIX: &[u8] = b"subspace-emission-history";
+/// Amount of blocks covered by each entry of the epoch index.
+const INDEX_BUCKET_SIZE: u64 = 10_000;
+/// Maximum amount of blocks a single history query can span.
+const MAX_QUERY_RANGE: u64 = 1_000_000;
+
+/// Consensus values of a module at the end of an epoch.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, serde::Serialize, serde::Deserialize)]
+pub struct EmissionSnapshot {
+    pub block: u64,
+    pub uid: u16,
+    /// Total stake delegated to the module, including its own.
+    pub stake: u64,
+    pub emission: u64,
+    pub incentive: u16,
+    pub dividends: u16,
+    pub trust: u16,
+    pub rank: u16,
+    pub consensus: u16,
+    pub validator_permit: bool,
+}
+
+/// The emission history kept in the offchain database.
+///
+/// Every epoch is stored under `(netuid, block)`, and the blocks subnets ran epochs at are
+/// indexed in buckets of [`INDEX_BUCKET_SIZE`] blocks, so range queries don't need to iterate
+/// the database.
+#[derive(Clone)]
+pub struct EmissionHistory<S> {
+    storage: S,
+}
+
+impl<S: OffchainStorage> EmissionHistory<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+
+    fn epoch_key(netuid: u16, block: u64) -> Vec<u8> {
+        (b"epoch", netuid, block).encode()
+    }
+
+    fn bucket(block: u64) -> u64 {
+        block.checked_div(INDEX_BUCKET_SIZE).unwrap_or_default()
+    }
+
+    fn index_key(netuid: u16, bucket: u64) -> Vec<u8> {
+        (b"index", netuid, bucket).encode()
+    }
+
+    fn last_indexed_key() -> Vec<u8> {
+        b"last-indexed".encode()
+    }
+
+    /// Returns the last block the indexer went through.
+    fn last_indexed(&self) -> Option<u64> {
+        self.get(&Self::last_indexed_key())
+    }
+
+    fn set_last_indexed(&mut self, block: u64) {
+        self.storage.set(STORAGE_PREFIX, &Self::last_indexed_key(), &block.encode());
+    }
+
+    fn get<T: Decode>(&self, key: &[u8]) -> Option<T> {
+        self.storage
+            .get(STORAGE_PREFIX, key)
+            .and_then(|value| T::decode(&mut &value[..]).ok())
+    }
+
+    /// Returns the blocks the subnet ran epochs at in the given index bucket.
+    fn epochs(&self, netuid: u16, bucket: u64) -> Vec<u64> {
+        self.get(&Self::index_key(netuid, bucket)).unwrap_or_default()
+    }
+
+    /// Stores the snapshot of the modules of a subnet at the end of an epoch.
+    fn insert(&mut self, netuid: u16, block: u64, modules: Vec<(AccountId, EmissionSnapshot)>) {
+        self.storage.set(
+            STORAGE_PREFIX,
+            &Self::epoch_key(netuid, block),
+            &modules.encode(),
+        );
+
+        let bucket = Self::bucket(block);
+        let mut epochs = self.epochs(netuid, bucket);
+        if !epochs.contains(&block) {
+            epochs.push(block);
+            epochs.sort_unstable();
+            self.storage.set(
+                STORAGE_PREFIX,
+                &Self::index_key(netuid, bucket),
+                &epochs.encode(),
+            );
+        }
+    }
+
+    /// Returns the snapshots of `key` on the subnet for the epochs that ran between the `from`
+    /// and `to` blocks, both included, oldest first.
+    pub fn history(
+        &self,
+        netuid: u16,
+        key: &AccountId,
+        from: u64,
+        to: u64,
+    ) -> Vec<EmissionSnapshot> {
+        (Self::bucket(from)..=Self::bucket(to))
+            .flat_map(|bucket| self.epochs(netuid, bucket))
+            .filter(|block| (from..=to).contains(block))
+            .filter_map(|block| {
+                self.get::<Vec<(AccountId, EmissionSnapshot)>>(&Self::epoch_key(netuid, block))
+            })
+            .filter_map(|modules| {
+                modules
+                    .into_iter()
+                    .find(|(module_key, _)| module_key == key)
+                    .map(|(_, snapshot)| snapshot)
+            })
+            .collect()
+    }
+}
+
+/// Indexes the epochs of every finalized block until the node shuts down, starting with the
+/// blocks finalized since the last indexed one.
+pub async fn run_indexer<C, S>(client: Arc<C>, mut history: EmissionHistory<S>)
+where
+    C: BlockchainEvents<Block>
+        + StorageProvider<Block, sc_service::TFullBackend<Block>>
+        + HeaderBackend<Block>
+        + ProvideRuntimeApi<Block>,
+    C::Api: SubspaceRuntimeApi<Block>,
+    S: OffchainStorage,
+{
+    // Subscribed before catching up, so no block finalized meanwhile is missed.
+    let mut finality_notifications = client.finality_notification_stream();
+
+    let finalized = client.info().finalized_number;
+    if history.last_indexed().is_none() {
+        history.set_last_indexed(finalized);
+    }
+    index_up_to(&*client, &mut history, finalized);
+
+    while let Some(notification) = finality_notifications.next().await {
+        index_up_to(&*client, &mut history, *notification.header.number());
+    }
+}
+
+/// Indexes the finalized blocks after the last indexed one, up to the given block included.
+fn index_up_to<C, S>(client: &C, history: &mut EmissionHistory<S>, to: u64)
+where
+    C: StorageProvider<Block, sc_service::TFullBackend<Block>>
+        + HeaderBackend<Block>
+        + ProvideRuntimeApi<Block>,
+    C::Api: SubspaceRuntimeApi<Block>,
+    S: OffchainStorage,
+{
+    let from = history.last_indexed().unwrap_or(to).saturating_add(1);
+    for block in from..=to {
+        match client.hash(block) {
+            Ok(Some(hash)) => {
+                if let Err(err) = index_block(client, history, hash) {
+                    log::warn!("could not index the emission history of block {hash}: {err}");
+                }
+            }
+            Ok(None) => log::warn!("finalized block {block} is unknown, skipping its epochs"),
+            Err(err) => log::warn!("could not get the hash of block {block}: {err}"),
+        }
+
+        // Blocks that failed are skipped, their state won't come back.
+        history.set_last_indexed(block);
+    }
+}
+
+/// Snapshots the subnets that finished an epoch on the given block.
+fn index_block<C, S>(client: &C, history: &mut EmissionHistory<S>, hash: Hash) -> Result<(), String>
+where
+    C: StorageProvider<Block, sc_service::TFullBackend<Block>>
+        + HeaderBackend<Block>
+        + ProvideRuntimeApi<Block>,
+    C::Api: SubspaceRuntimeApi<Block>,
+    S: OffchainStorage,
+{
+    // `frame_system::Events` is not public, its key is built by hand.
+    let events_key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+    let Some(events) = client.storage(hash, &events_key).map_err(|err| err.to_string())? else {
+        return Ok(());
+    };
+
+    let events = Vec::<frame_system::EventRecord<RuntimeEvent, Hash>>::decode(&mut &events.0[..])
+        .map_err(|err| format!("could not decode events: {err}"))?;
+
+    let netuids: Vec<u16> = events
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::SubnetEmissionModule(pallet_subnet_emission::Event::EpochFinished(
+                netuid,
+            )) => Some(netuid),
+            _ => None,
+        })
+        .collect();
+    if netuids.is_empty() {
+        return Ok(());
+    }
+
+    let block = client
+        .number(hash)
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "unknown block".to_string())?;
+
+    let api = client.runtime_api();
+    for netuid in netuids {
+        let metagraph = api
+            .get_subnet_metagraph(hash, netuid)
+            .map_err(|err| format!("could not get the metagraph of subnet {netuid}: {err}"))?;
+
+        let modules = metagraph
+            .modules
+            .into_iter()
+            .map(|module| {
+                let snapshot = EmissionSnapshot {
+                    block,
+                    uid: module.uid,
+                    stake: module.stake,
+                    emission: module.emission,
+                    incentive: module.incentive,
+                    dividends: module.dividends,
+                    trust: module.trust,
+                    rank: module.rank,
+                    consensus: module.consensus,
+                    validator_permit: module.validator_permit,
+                };
+                (module.key, snapshot)
+            })
+            .collect();
+
+        history.insert(netuid, block, modules);
+    }
+
+    Ok(())
+}
+
+#[rpc(server)]
+pub trait EmissionHistoryApi {
+    /// Returns the consensus values of a module at the end of every epoch its subnet ran
+    /// between the `from` and `to` blocks, both included.
+    #[method(name = "subspace_getEmissionHistory")]
+    fn get_emission_history(
+        &self,
+        netuid: u16,
+        key: AccountId,
+        from: u64,
+        to: u64,
+    ) -> RpcResult<Vec<EmissionSnapshot>>;
+}
+
+impl<S> EmissionHistoryApiServer for EmissionHistory<S>
+where
+    S: OffchainStorage + 'static,
+{
+    fn get_emission_history(
+        &self,
+        netuid: u16,
+        key: AccountId,
+        from: u64,
+        to: u64,
+    ) -> RpcResult<Vec<EmissionSnapshot>> {
+        if from > to || to.saturating_sub(from) > MAX_QUERY_RANGE {
+            return Err(ErrorObject::owned(
+                INVALID_PARAMS_CODE,
+                format!(
+                    "the block range must be ordered and span at most {MAX_QUERY_RANGE} blocks"
+                ),
+                None::<()>,
+            ));
+        }
+
+        Ok(self.history(netuid, &key, from, to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::offchain::storage::InMemOffchainStorage;
+
+    fn snapshot(block: u64, uid: u16) -> EmissionSnapshot {
+        EmissionSnapshot {
+            block,
+            uid,
+            stake: 1_000,
+            emission: block,
+            incentive: 1,
+            dividends: 2,
+            trust: 3,
+            rank: 4,
+            consensus: 5,
+            validator_permit: true,
+        }
+    }
+
+    fn history_with_epochs(netuid: u16, blocks: &[u64]) -> EmissionHistory<InMemOffchainStorage> {
+        let mut history = EmissionHistory::new(InMemOffchainStorage::default());
+        for &block in blocks {
+            history.insert(
+                netuid,
+                block,
+                vec![
+                    (AccountId::new([1; 32]), snapshot(block, 0)),
+                    (AccountId::new([2; 32]), snapshot(block, 1)),
+                ],
+            );
+        }
+        history
+    }
+
+    #[test]
+    fn keys_encode_netuid_and_block() {
+        assert_eq!(
+            EmissionHistory::<InMemOffchainStorage>::epoch_key(3, 42),
+            [&b"epoch"[..], &3u16.to_le_bytes(), &42u64.to_le_bytes()].concat()
+        );
+        assert_eq!(
+            EmissionHistory::<InMemOffchainStorage>::index_key(3, 1),
+            [&b"index"[..], &3u16.to_le_bytes(), &1u64.to_le_bytes()].concat()
+        );
+        assert_eq!(
+            EmissionHistory::<InMemOffchainStorage>::bucket(INDEX_BUCKET_SIZE - 1),
+            0
+        );
+        assert_eq!(
+            EmissionHistory::<InMemOffchainStorage>::bucket(INDEX_BUCKET_SIZE),
+            1
+        );
+    }
+
+    #[test]
+    fn indexes_epochs_once_and_in_order() {
+        let mut history = history_with_epochs(0, &[300, 100, 200]);
+        history.insert(0, 200, vec![]);
+
+        assert_eq!(history.epochs(0, 0), vec![100, 200, 300]);
+        assert!(history.epochs(1, 0).is_empty());
+    }
+
+    #[test]
+    fn history_covers_the_range_across_buckets() {
+        let history = history_with_epochs(
+            0,
+            &[
+                100,
+                INDEX_BUCKET_SIZE,
+                INDEX_BUCKET_SIZE + 100,
+                3 * INDEX_BUCKET_SIZE,
+            ],
+        );
+        let key = AccountId::new([2; 32]);
+
+        let blocks = |from, to| -> Vec<u64> {
+            history
+                .history(0, &key, from, to)
+                .into_iter()
+                .map(|snapshot| snapshot.block)
+                .collect()
+        };
+
+        assert_eq!(
+            blocks(0, 3 * INDEX_BUCKET_SIZE),
+            vec![
+                100,
+                INDEX_BUCKET_SIZE,
+                INDEX_BUCKET_SIZE + 100,
+                3 * INDEX_BUCKET_SIZE
+            ]
+        );
+        assert_eq!(
+            blocks(100, INDEX_BUCKET_SIZE + 100),
+            vec![100, INDEX_BUCKET_SIZE, INDEX_BUCKET_SIZE + 100]
+        );
+        assert_eq!(blocks(101, INDEX_BUCKET_SIZE - 1), Vec::<u64>::new());
+        assert!(history.history(0, &key, 0, 100).iter().all(|snapshot| snapshot.uid == 1));
+        assert!(history.history(1, &key, 0, 100).is_empty());
+        assert!(history.history(0, &AccountId::new([3; 32]), 0, 100).is_empty());
+    }
+
+    #[test]
+    fn keeps_the_last_indexed_block() {
+        let mut history = EmissionHistory::new(InMemOffchainStorage::default());
+        assert_eq!(history.last_indexed(), None);
+
+        history.set_last_indexed(42);
+        assert_eq!(history.last_indexed(), Some(42));
+    }
+
+    #[test]
+    fn rpc_rejects_invalid_ranges() {
+        let history = history_with_epochs(0, &[100]);
+        let key = AccountId::new([1; 32]);
+
+        assert!(history.get_emission_history(0, key.clone(), 200, 100).is_err());
+        assert!(history.get_emission_history(0, key.clone(), 0, MAX_QUERY_RANGE + 1).is_err());
+        assert_eq!(
+            history.get_emission_history(0, key, 0, MAX_QUERY_RANGE).unwrap(),
+            vec![snapshot(100, 0)]
+        );
+    }
+}
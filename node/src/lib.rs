@@ -1,4 +1,5 @@
 pub mod chain_spec;
+pub mod emission_history;
 pub mod manual_seal_service;
 pub mod rpc;
 pub mod service;
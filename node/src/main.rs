@@ -3,6 +3,7 @@
 #![warn(missing_docs)]
 
 mod chain_spec;
+mod emission_history;
 #[macro_use]
 mod service;
 
@@ -87,7 +87,10 @@ pub fn new_partial(
 }
 
 /// Builds a new service for a full client.
-pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError> {
+pub fn new_full(
+    mut config: Configuration,
+    emission_history: bool,
+) -> Result<TaskManager, ServiceError> {
     let sc_service::PartialComponents {
         client,
         backend,
@@ -137,6 +140,21 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 
     let prometheus_registry = config.prometheus_registry().cloned();
 
+    let emission_history = if emission_history {
+        let storage = backend.offchain_storage().ok_or_else(|| {
+            ServiceError::Other("the emission history needs the offchain database".into())
+        })?;
+        let history = crate::emission_history::EmissionHistory::new(storage);
+        task_manager.spawn_handle().spawn(
+            "emission-history",
+            None,
+            crate::emission_history::run_indexer(client.clone(), history.clone()),
+        );
+        Some(history)
+    } else {
+        None
+    };
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
@@ -146,6 +164,7 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
                 client: client.clone(),
                 pool: pool.clone(),
                 deny_unsafe,
+                emission_history: emission_history.clone(),
             };
             crate::rpc::create_full(deps).map_err(Into::into)
         })
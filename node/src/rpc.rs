@@ -14,6 +14,8 @@ use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
+use crate::emission_history::{EmissionHistory, OffchainDb};
+
 pub use sc_rpc_api::DenyUnsafe;
 
 /// Full client dependencies.
@@ -24,6 +26,8 @@ pub struct FullDeps<C, P> {
     pub pool: Arc<P>,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
+    /// Emission history index, if enabled
+    pub emission_history: Option<EmissionHistory<OffchainDb>>,
 }
 
 /// Instantiate all full RPC extensions.
@@ -40,6 +44,7 @@ where
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
+    use crate::emission_history::EmissionHistoryApiServer;
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use subspace_rpc::{SubspaceApiServer, SubspacePallet};
     use substrate_frame_rpc_system::{System, SystemApiServer};
@@ -49,11 +54,15 @@ where
         client,
         pool,
         deny_unsafe,
+        emission_history,
     } = deps;
 
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
     module.merge(SubspacePallet::new(client).into_rpc())?;
+    if let Some(emission_history) = emission_history {
+        module.merge(emission_history.into_rpc())?;
+    }
     // Extend this RPC with a custom API by using the following syntax.
     // `YourRpcStruct` should have a reference to a client, which is needed
     // to call into the runtime.
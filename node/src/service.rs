@@ -121,7 +121,10 @@ pub fn new_partial(
 }
 
 /// Builds a new service for a full client.
-pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
+pub fn new_full(
+    config: Configuration,
+    emission_history: bool,
+) -> Result<TaskManager, ServiceError> {
     let sc_service::PartialComponents {
         client,
         backend,
@@ -190,6 +193,21 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
     let enable_grandpa = !config.disable_grandpa;
     let prometheus_registry = config.prometheus_registry().cloned();
 
+    let emission_history = if emission_history {
+        let storage = backend.offchain_storage().ok_or_else(|| {
+            ServiceError::Other("the emission history needs the offchain database".into())
+        })?;
+        let history = crate::emission_history::EmissionHistory::new(storage);
+        task_manager.spawn_handle().spawn(
+            "emission-history",
+            None,
+            crate::emission_history::run_indexer(client.clone(), history.clone()),
+        );
+        Some(history)
+    } else {
+        None
+    };
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
@@ -199,6 +217,7 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
                 client: client.clone(),
                 pool: pool.clone(),
                 deny_unsafe,
+                emission_history: emission_history.clone(),
             };
             crate::rpc::create_full(deps).map_err(Into::into)
         })
@@ -1,6 +1,6 @@
 #![no_std]
 
-use frame_support::DebugNoBound;
+use frame_support::{weights::Weight, DebugNoBound};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::{prelude::collections::BTreeSet, TypeInfo};
 use sp_runtime::{DispatchResult, Percent};
@@ -11,6 +11,25 @@ pub enum VoteMode {
     Vote = 1,
 }
 
+/// The rules a proposal must meet to pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub struct ProposalRules {
+    /// Share of the stake that must vote, abstentions included, for the proposal to be decided.
+    pub quorum: Percent,
+    /// Share of the votes for and against the proposal that must be in favor for it to pass.
+    pub approval_threshold: Percent,
+}
+
+impl ProposalRules {
+    #[must_use]
+    pub fn new(quorum: u8, approval_threshold: u8) -> Self {
+        Self {
+            quorum: Percent::from_percent(quorum),
+            approval_threshold: Percent::from_percent(approval_threshold),
+        }
+    }
+}
+
 #[derive(Clone, TypeInfo, Decode, Encode, PartialEq, Eq, DebugNoBound, MaxEncodedLen)]
 pub struct GovernanceConfiguration {
     pub proposal_cost: u64,
@@ -19,6 +38,23 @@ pub struct GovernanceConfiguration {
     pub proposal_reward_treasury_allocation: Percent,
     pub max_proposal_reward_treasury_allocation: u64,
     pub proposal_reward_interval: u64,
+    /// Rules of the global and subnet custom proposals.
+    pub custom_proposal_rules: ProposalRules,
+    /// Rules of the global and subnet params proposals, patches included.
+    pub params_proposal_rules: ProposalRules,
+    /// Rules of the DAO treasury transfer proposals.
+    pub treasury_proposal_rules: ProposalRules,
+    /// Rules of the subnet pricing proposals.
+    pub subnet_pricing_proposal_rules: ProposalRules,
+    /// Rules of the runtime call proposals.
+    pub runtime_call_proposal_rules: ProposalRules,
+    /// Whether proposals are decided before their expiration once the votes left can't change
+    /// the outcome.
+    pub early_finalization: bool,
+    /// How many blocks the proposer can amend a new proposal for, before voting opens.
+    pub proposal_amendment_period: u64,
+    /// Share of the proposal cost burned when the proposer cancels a proposal that was voted on.
+    pub proposal_cancellation_burn: Percent,
 }
 
 impl Default for GovernanceConfiguration {
@@ -32,7 +68,70 @@ impl Default for GovernanceConfiguration {
             // Maximum reward allocation 10K.
             max_proposal_reward_treasury_allocation: 10_000_000_000_000,
             proposal_reward_interval: 75_600,
+            custom_proposal_rules: ProposalRules::new(50, 50),
+            params_proposal_rules: ProposalRules::new(40, 50),
+            treasury_proposal_rules: ProposalRules::new(50, 66),
+            subnet_pricing_proposal_rules: ProposalRules::new(50, 50),
+            runtime_call_proposal_rules: ProposalRules::new(60, 50),
+            early_finalization: false,
+            proposal_amendment_period: 0,
+            proposal_cancellation_burn: Percent::from_percent(50),
+        }
+    }
+}
+
+/// A partial update of a governance configuration. Fields left as `None` keep their current
+/// value.
+#[derive(Clone, Debug, Default, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub struct GovernanceConfigurationPatch {
+    pub proposal_cost: Option<u64>,
+    pub proposal_expiration: Option<u32>,
+    pub vote_mode: Option<VoteMode>,
+    pub proposal_reward_treasury_allocation: Option<Percent>,
+    pub max_proposal_reward_treasury_allocation: Option<u64>,
+    pub proposal_reward_interval: Option<u64>,
+    pub custom_proposal_rules: Option<ProposalRules>,
+    pub params_proposal_rules: Option<ProposalRules>,
+    pub treasury_proposal_rules: Option<ProposalRules>,
+    pub subnet_pricing_proposal_rules: Option<ProposalRules>,
+    pub runtime_call_proposal_rules: Option<ProposalRules>,
+    pub early_finalization: Option<bool>,
+    pub proposal_amendment_period: Option<u64>,
+    pub proposal_cancellation_burn: Option<Percent>,
+}
+
+impl GovernanceConfigurationPatch {
+    /// Returns the configuration with the fields set in the patch replaced.
+    #[must_use]
+    pub fn merge(self, mut config: GovernanceConfiguration) -> GovernanceConfiguration {
+        macro_rules! merge {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if let Some(value) = self.$field {
+                        config.$field = value;
+                    }
+                )*
+            };
         }
+
+        merge!(
+            proposal_cost,
+            proposal_expiration,
+            vote_mode,
+            proposal_reward_treasury_allocation,
+            max_proposal_reward_treasury_allocation,
+            proposal_reward_interval,
+            custom_proposal_rules,
+            params_proposal_rules,
+            treasury_proposal_rules,
+            subnet_pricing_proposal_rules,
+            runtime_call_proposal_rules,
+            early_finalization,
+            proposal_amendment_period,
+            proposal_cancellation_burn,
+        );
+
+        config
     }
 }
 
@@ -57,17 +156,32 @@ pub trait GovernanceApi<AccountId> {
         governance_config: GovernanceConfiguration,
     ) -> DispatchResult;
 
-    /// Returns whether this account is delegating their voting power to the modules it has stakes
-    /// on.
-    fn is_delegating_voting_power(delegator: &AccountId) -> bool;
-
-    /// Defines whether this account will delegate their voting power or not. This decision is
-    /// global.
-    fn update_delegating_voting_power(delegator: &AccountId, delegating: bool) -> DispatchResult;
+    /// Returns how much of the key's stake is locked by conviction votes.
+    fn get_locked_stake(key: &AccountId) -> u64;
 
     /// Handles the deregistration of a subnet.
     fn handle_subnet_removal(subnet_id: u16);
 
+    /// Moves the governance state of a module key to the key it was rotated to.
+    fn handle_module_key_rotation(key: &AccountId, new_key: &AccountId);
+
+    /// Whether the key holds governance state a module key rotation would merge into.
+    fn has_governance_state(key: &AccountId) -> bool;
+
+    /// Counts the governance entries moved by a rotation of the key, stopping past `limit`.
+    fn module_key_rotation_entries(key: &AccountId, limit: u32) -> u32;
+
+    /// Returns the weight of moving `entries` governance entries on a module key rotation.
+    fn module_key_rotation_weight(entries: u32) -> Weight;
+
+    /// Called right before the voting power of the key increases, or decreases, by the amount,
+    /// so open proposals keep the power it had when they were created and its delegates receive
+    /// the change.
+    fn handle_voting_power_change(key: &AccountId, amount: u64, increase: bool);
+
+    /// Returns the weight of `handle_voting_power_change`.
+    fn voting_power_change_weight() -> Weight;
+
     /// Executes the application in DAO.
     fn execute_application(user_id: &AccountId) -> DispatchResult;
 
@@ -2,10 +2,11 @@
 
 use crate::{Pallet as GovernanceMod, *};
 use frame_benchmarking::{account, benchmarks};
+use frame_support::{BoundedBTreeMap, BoundedVec};
 use frame_system::RawOrigin;
 pub use pallet::*;
 use pallet_subspace::{Pallet as SubspaceMod, SubnetBurn};
-use sp_std::vec::Vec;
+use sp_std::{boxed::Box, vec::Vec};
 
 fn submit_dao_application<T: Config>() -> Result<(), &'static str> {
     // First add the application
@@ -24,6 +25,27 @@ fn submit_dao_application<T: Config>() -> Result<(), &'static str> {
     Ok(())
 }
 
+/// Delegates the voting power of the key on all proposals and on the first subnets, up to
+/// `scopes` delegations.
+fn delegate_on_scopes<T: Config>(
+    key: &T::AccountId,
+    delegate: &T::AccountId,
+    scopes: u32,
+) -> Result<(), &'static str> {
+    for i in 0..scopes {
+        let scope = match i {
+            0 => DelegationScope::All,
+            i => DelegationScope::Subnet(i as u16),
+        };
+        GovernanceMod::<T>::delegate_voting_power(
+            RawOrigin::Signed(key.clone()).into(),
+            delegate.clone(),
+            scope,
+        )?;
+    }
+    Ok(())
+}
+
 fn register_mock<T: Config>(
     key: T::AccountId,
     module_key: T::AccountId,
@@ -51,6 +73,14 @@ fn register_mock<T: Config>(
     SubspaceMod::<T>::increase_stake(&key, &module_key, enough_stake);
     Ok(())
 }
+fn proposal_metadata() -> ProposalMetadata {
+    ProposalMetadata {
+        title: BoundedVec::truncate_from(b"test".to_vec()),
+        cid: BoundedVec::truncate_from(b"QmPK1s3pNYLi9ERiq3BDxKa4XosgWwFRQUydHUtz4YgpqB".to_vec()),
+        discussion_url: Some(BoundedVec::truncate_from(b"https://example.com".to_vec())),
+    }
+}
+
 benchmarks! {
     //---------------------------------
     //Adding proposals
@@ -67,7 +97,7 @@ benchmarks! {
 
         // Get the current global parameters
         let params = SubspaceMod::<T>::global_params();
-        let data = "ipfshash".as_bytes().to_vec();
+        let data = proposal_metadata();
 
         // Submit the proposal with the global parameters
         }: add_global_params_proposal(
@@ -90,7 +120,9 @@ benchmarks! {
             params.general_subnet_application_cost,     // general_subnet_application_cost
             params.kappa,
             params.rho,
-            params.subnet_immunity_period
+            params.subnet_immunity_period,
+            params.unbonding_period,
+            params.delegation_fee_notice_period
         )
 
 
@@ -105,7 +137,7 @@ benchmarks! {
 
         // Switch the vote mode to vote
         let params = SubspaceMod::<T>::subnet_params(netuid);
-        let data = "ipfshash".as_bytes().to_vec();
+        let data = proposal_metadata();
 
         SubspaceMod::<T>::update_subnet(
             RawOrigin::Signed(caller.clone()).into(),
@@ -129,7 +161,8 @@ benchmarks! {
             params.target_registrations_per_interval,
             params.max_registrations_per_interval,
             params.adjustment_alpha,
-            params.min_immunity_stake
+            params.min_immunity_stake,
+            params.unbonding_period
         )?;
 
         // add balance to submit the proposal
@@ -161,7 +194,10 @@ benchmarks! {
         params.target_registrations_per_interval,
         params.max_registrations_per_interval,
         params.adjustment_alpha,
-        params.min_immunity_stake
+        params.min_immunity_stake,
+        params.unbonding_period,
+        params.commit_reveal_weights,
+        params.record_epoch_emission
     )
 
     // 2
@@ -170,7 +206,7 @@ benchmarks! {
         // Add alice fund to submit the proposal
         SubspaceMod::<T>::add_balance_to_account(&caller,
         SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
-        let data =  "test".as_bytes().to_vec(); }: add_global_custom_proposal(RawOrigin::Signed(caller), data)
+        let data = proposal_metadata(); }: add_global_custom_proposal(RawOrigin::Signed(caller), data)
 
     // 3
     add_subnet_custom_proposal {
@@ -180,7 +216,7 @@ benchmarks! {
     "test".as_bytes().to_vec())?;     // Add alice fund to submit the proposal
         SubspaceMod::<T>::add_balance_to_account(&caller,
     SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
-    let data = "test".as_bytes().to_vec();
+    let data = proposal_metadata();
  }: add_subnet_custom_proposal(RawOrigin::Signed(caller), 0, data)
 
     // 4
@@ -194,7 +230,7 @@ benchmarks! {
         SubspaceMod::<T>::add_balance_to_account(&treasury_address,
     SubspaceMod::<T>::u64_to_balance(amount).unwrap());
 
-        let data = "test".as_bytes().to_vec();
+        let data = proposal_metadata();
         let destinations: T::AccountId = account("Bob", 0, 2);
     }: add_transfer_dao_treasury_proposal(RawOrigin::Signed(caller), data, amount, destinations)
 
@@ -213,7 +249,7 @@ benchmarks! {
         SubspaceMod::<T>::add_balance_to_account(&caller, SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
 
         // Submit a custom proposal
-        let data = "test".as_bytes().to_vec();
+        let data = proposal_metadata();
         GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(caller.clone()).into(), data)?;
 
         let proposal_id = 0;
@@ -230,7 +266,7 @@ benchmarks! {
         SubspaceMod::<T>::add_balance_to_account(&caller, SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
 
         // Submit a custom proposal
-        let data = "test".as_bytes().to_vec();
+        let data = proposal_metadata();
         GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(caller.clone()).into(), data)?;
 
         let proposal_id = 0;
@@ -240,15 +276,79 @@ benchmarks! {
 
     }: remove_vote_proposal(RawOrigin::Signed(caller), proposal_id)
 
-    // 7
-    enable_vote_power_delegation {
+    // 19
+    delegate_voting_power {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let delegate: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        SubspaceMod::<T>::add_balance_to_account(&caller, SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+
+        // The delegated power is recorded for the open proposal on every scope
+        GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(caller.clone()).into(), proposal_metadata())?;
+        delegate_on_scopes::<T>(&caller, &delegate, MAX_DELEGATION_SCOPES - 1)?;
+        let scope = DelegationScope::Subnet(MAX_DELEGATION_SCOPES as u16);
+    }: delegate_voting_power(RawOrigin::Signed(caller), delegate, scope)
+
+    // 20
+    undelegate_voting_power {
         let caller: T::AccountId = account("Alice", 0, 1);
-    }: enable_vote_power_delegation(RawOrigin::Signed(caller))
+        let delegate: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        SubspaceMod::<T>::add_balance_to_account(&caller, SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+
+        // Dropping the delegation on all proposals changes the power overridden on every scope
+        GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(caller.clone()).into(), proposal_metadata())?;
+        delegate_on_scopes::<T>(&caller, &delegate, MAX_DELEGATION_SCOPES)?;
+    }: undelegate_voting_power(RawOrigin::Signed(caller), DelegationScope::All)
 
-    // 8
-    disable_vote_power_delegation {
+    // 21
+    amend_proposal {
         let caller: T::AccountId = account("Alice", 0, 1);
-    }: disable_vote_power_delegation(RawOrigin::Signed(caller))
+        SubspaceMod::<T>::add_balance_to_account(&caller, SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+
+        // Keep the proposal in its amendment period
+        GlobalGovernanceConfig::<T>::mutate(|config| config.proposal_amendment_period = 100);
+        GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(caller.clone()).into(), proposal_metadata())?;
+    }: amend_proposal(RawOrigin::Signed(caller), 0, proposal_metadata(), Some(ProposalData::GlobalCustom))
+
+    // 22
+    cancel_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        // Register Alice such that she has funds to vote
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        SubspaceMod::<T>::add_balance_to_account(&caller, SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+
+        // A voted proposal has its cost partially burned
+        GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(caller.clone()).into(), proposal_metadata())?;
+        GovernanceMod::<T>::vote_proposal(RawOrigin::Signed(caller.clone()).into(), 0, true)?;
+    }: cancel_proposal(RawOrigin::Signed(caller), 0)
+
+    // 23
+    add_treasury_stream_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        // Add alice fund to submit the proposal
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        let amount = 1000;
+        // Add the amount to treasury funds
+        let treasury_address: T::AccountId = DaoTreasuryAddress::<T>::get();
+        SubspaceMod::<T>::add_balance_to_account(&treasury_address,
+        SubspaceMod::<T>::u64_to_balance(amount).unwrap());
+
+        let recipient: T::AccountId = account("Bob", 0, 2);
+    }: add_treasury_stream_proposal(RawOrigin::Signed(caller), proposal_metadata(), recipient, amount, 100, 10)
+
+    // 25
+    claim_stream {
+        let recipient: T::AccountId = account("Bob", 0, 2);
+        let amount = 1_000_000_000_000;
+        SubspaceMod::<T>::add_balance_to_account(&DaoTreasuryAddress::<T>::get(),
+        SubspaceMod::<T>::u64_to_balance(amount * 2).unwrap());
+
+        // Start the stream and let half of it vest
+        GovernanceMod::<T>::create_treasury_stream(0, recipient.clone(), amount, 100, 0)?;
+        frame_system::Pallet::<T>::set_block_number(50u32.into());
+    }: claim_stream(RawOrigin::Signed(recipient), 0)
 
     // ---------------------------------
     // Subnet 0 DAO
@@ -292,4 +392,154 @@ benchmarks! {
     application_key.clone(), 1)?; }: remove_from_whitelist(RawOrigin::Signed(caller),
     application_key)
 
+    // 13
+    add_subnet_pricing_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        // Add alice fund to submit the proposal
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        let data = proposal_metadata();
+    }: add_subnet_pricing_proposal(RawOrigin::Signed(caller), data, pallet_subnet_emission_api::SubnetPricingMechanism::Equal)
+
+    // 14
+    vote_proposal_with_conviction {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        // Register Alice such that she has stake on herself to lock
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+
+        // Add Alice's funds to submit the proposal
+        SubspaceMod::<T>::add_balance_to_account(&caller, SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+
+        // Submit a custom proposal
+        let data = proposal_metadata();
+        GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(caller.clone()).into(), data)?;
+
+        let proposal_id = 0;
+        let vote = true;
+    }: vote_proposal_with_conviction(RawOrigin::Signed(caller), proposal_id, vote, MAX_CONVICTION)
+
+    // 15
+    add_runtime_call_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        // Add alice fund to submit the proposal
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        let data = proposal_metadata();
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+    }: add_runtime_call_proposal(RawOrigin::Signed(caller), data, Box::new(call))
+
+    //---------------------------------
+    //Hooks
+    //---------------------------------
+
+    tick_proposals {
+        let p in 1 .. 100;
+        let v in 0 .. 1000;
+
+        let module: T::AccountId = account("Module", 0, 1);
+        register_mock::<T>(module.clone(), module.clone(), "test".as_bytes().to_vec())?;
+
+        for i in 0..p {
+            let proposer: T::AccountId = account("Proposer", i, 2);
+            SubspaceMod::<T>::add_balance_to_account(
+                &proposer,
+                SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+            );
+            let data = proposal_metadata();
+            GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(proposer).into(), data)?;
+        }
+
+        // The votes are weighed once their proposal is finalized
+        for i in 0..v {
+            let voter: T::AccountId = account("Voter", i, 3);
+            SubspaceMod::<T>::increase_stake(&voter, &module, 1_000_000_000);
+            GovernanceMod::<T>::vote_proposal(RawOrigin::Signed(voter).into(), (i % p) as u64, true)?;
+        }
+
+        // Proposals are ticked every 100 blocks, every proposal expires on this one
+        let expiration_block = Proposals::<T>::get(0).map(|proposal| proposal.expiration_block).unwrap_or_default();
+        let block_number = expiration_block.div_ceil(100).saturating_mul(100);
+    }: {
+        proposal::tick_proposals::<T>(block_number)
+    }
+
+    tick_proposal_rewards {
+        let s in 1 .. 256;
+        let p in 0 .. 100;
+
+        // The global proposals are rewarded along with the ones of every subnet
+        for netuid in 1..s {
+            pallet_subspace::N::<T>::insert(netuid as u16, 0);
+        }
+
+        SubspaceMod::<T>::add_balance_to_account(
+            &DaoTreasuryAddress::<T>::get(),
+            SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+
+        let interval = GlobalGovernanceConfig::<T>::get().proposal_reward_interval;
+        for i in 0..p {
+            let voter: T::AccountId = account("Voter", i, 1);
+            let mut votes_for = BoundedBTreeMap::new();
+            votes_for.try_insert(voter, 1_000_000_000).unwrap();
+
+            UnrewardedProposals::<T>::insert(i as u64, UnrewardedProposal::<T> {
+                subnet_id: None,
+                block: interval,
+                votes_for,
+                votes_against: BoundedBTreeMap::new(),
+            });
+        }
+    }: {
+        proposal::tick_proposal_rewards::<T>(interval)
+    }
+
+    //---------------------------------
+    //Api
+    //---------------------------------
+
+    rotate_module_key {
+        let e in 0 .. 100;
+
+        let key: T::AccountId = account("ModuleKey", 0, 1);
+        let new_key: T::AccountId = account("NewKey", 0, 2);
+        register_mock::<T>(key.clone(), key.clone(), "test".as_bytes().to_vec())?;
+
+        // Votes are the costliest entries to move
+        for i in 0..e {
+            let proposer: T::AccountId = account("Proposer", i, 2);
+            SubspaceMod::<T>::add_balance_to_account(
+                &proposer,
+                SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+            );
+            let data = proposal_metadata();
+            GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(proposer).into(), data)?;
+            GovernanceMod::<T>::vote_proposal(RawOrigin::Signed(key.clone()).into(), i as u64, true)?;
+        }
+    }: {
+        GovernanceMod::<T>::handle_module_key_rotation(&key, &new_key)
+    }
+
+    handle_voting_power_change {
+        let key: T::AccountId = account("ModuleKey", 0, 1);
+        let delegate: T::AccountId = account("Delegate", 0, 2);
+        register_mock::<T>(key.clone(), key.clone(), "test".as_bytes().to_vec())?;
+        delegate_on_scopes::<T>(&key, &delegate, MAX_DELEGATION_SCOPES)?;
+
+        // The key changed its voting power after every proposal but the last one
+        for i in 0..MAX_OPEN_PROPOSALS {
+            let proposer: T::AccountId = account("Proposer", i, 2);
+            SubspaceMod::<T>::add_balance_to_account(
+                &proposer,
+                SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+            );
+            let data = proposal_metadata();
+            GovernanceMod::<T>::add_global_custom_proposal(RawOrigin::Signed(proposer).into(), data)?;
+            if i + 1 < MAX_OPEN_PROPOSALS {
+                GovernanceMod::<T>::handle_voting_power_change(&key, 1, true);
+            }
+        }
+    }: {
+        GovernanceMod::<T>::handle_voting_power_change(&key, 1, true)
+    }
 }
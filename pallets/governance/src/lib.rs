@@ -8,6 +8,7 @@ mod benchmarking;
 pub mod dao;
 pub mod migrations;
 pub mod proposal;
+pub mod stream;
 pub mod voting;
 pub mod weights; // Weight benchmarks
 
@@ -17,11 +18,21 @@ use frame_support::{
     sp_runtime::{DispatchError, Percent},
 };
 use frame_system::pallet_prelude::OriginFor;
-use sp_std::vec::Vec;
+use sp_std::{boxed::Box, vec::Vec};
 
 pub use pallet::*;
 pub use pallet_governance_api::*;
-pub use proposal::{Proposal, ProposalData, ProposalId, ProposalStatus, UnrewardedProposal};
+pub use proposal::{
+    EncodedRuntimeCall, EnsureProposal, Proposal, ProposalData, ProposalId, ProposalMetadata,
+    ProposalStatus, UnrewardedProposal, MAX_OPEN_PROPOSALS, MAX_PROPOSAL_CID_LENGTH,
+    MAX_PROPOSAL_DISCUSSION_URL_LENGTH, MAX_PROPOSAL_TITLE_LENGTH, MAX_RUNTIME_CALL_SIZE,
+    RUNTIME_CALL_ENACTMENT_DELAY,
+};
+pub use stream::TreasuryStream;
+pub use voting::{
+    Checkpoints, DelegatedPower, DelegationScope, Vote, VoteLock, MAX_CONVICTION,
+    MAX_DELEGATION_SCOPES, MAX_VOTE_LOCKS,
+};
 
 type SubnetId = u16;
 
@@ -32,15 +43,17 @@ pub mod pallet {
     pub use crate::weights::WeightInfo;
     use crate::{dao::CuratorApplication, *};
     use frame_support::{
+        dispatch::{GetDispatchInfo, PostDispatchInfo},
         pallet_prelude::{ValueQuery, *},
-        traits::{Currency, StorageInstance},
+        traits::{Currency, IsSubType, StorageInstance},
         PalletId,
     };
     use frame_system::pallet_prelude::{ensure_signed, BlockNumberFor};
-    use pallet_subspace::DefaultKey;
-    use sp_runtime::traits::AccountIdConversion;
+    use pallet_subnet_emission_api::SubnetPricingMechanism;
+    use pallet_subspace::{global::GlobalParamsPatch, subnet::SubnetParamsPatch, DefaultKey};
+    use sp_runtime::traits::{AccountIdConversion, Dispatchable};
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -61,6 +74,27 @@ pub mod pallet {
 
         /// The weight information of this pallet.
         type WeightInfo: WeightInfo;
+
+        /// The runtime call type accepted runtime call proposals dispatch.
+        #[pallet::no_default]
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>
+            + IsSubType<frame_system::Call<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// The runtime origin, which accepted runtime calls are dispatched with as [`Origin`].
+        #[pallet::no_default]
+        type ProposalOrigin: From<Origin> + IsType<<Self as frame_system::Config>::RuntimeOrigin>;
+    }
+
+    /// The origin of the runtime calls dispatched by accepted proposals.
+    #[pallet::origin]
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub enum Origin {
+        /// The runtime call of the given proposal.
+        Proposal(ProposalId),
     }
 
     #[pallet::hooks]
@@ -69,10 +103,17 @@ pub mod pallet {
             let block_number: u64 =
                 block_number.try_into().ok().expect("blockchain won't pass 2 ^ 64 blocks");
 
-            proposal::tick_proposals::<T>(block_number);
-            proposal::tick_proposal_rewards::<T>(block_number);
-
-            Weight::zero()
+            let weight = proposal::tick_proposals::<T>(block_number)
+                .saturating_add(proposal::tick_proposal_rewards::<T>(block_number))
+                .saturating_add(voting::release_expired_vote_locks::<T>(block_number))
+                .saturating_add(migrations::convert_implicit_delegations::<T>(
+                    migrations::IMPLICIT_DELEGATIONS_PER_BLOCK,
+                ));
+
+            weight.saturating_add(proposal::tick_scheduled_runtime_calls::<T>(
+                block_number,
+                weight,
+            ))
         }
     }
 
@@ -114,19 +155,123 @@ pub mod pallet {
     #[pallet::storage]
     pub type Proposals<T: Config> = StorageMap<_, Identity, ProposalId, Proposal<T>>;
 
-    /// A map relating all modules and the stakers that are currently **NOT** delegating their
-    /// voting power.
-    ///
-    /// Indexed by the **staked** module and the subnet the stake is allocated to, the value is a
-    /// set of all modules that are delegating their voting power on that subnet.
+    /// The account each key delegates its voting power to, for each scope it delegated.
+    #[pallet::storage]
+    pub type VotingPowerDelegations<T: Config> =
+        StorageDoubleMap<_, Identity, T::AccountId, Identity, DelegationScope, T::AccountId>;
+
+    /// The keys delegating their voting power to an account, and the scope of the delegation.
+    #[pallet::storage]
+    pub type VotingPowerDelegators<T: Config> =
+        StorageDoubleMap<_, Identity, T::AccountId, Identity, (T::AccountId, DelegationScope), ()>;
+
+    /// The raw `StakeTo` key the conversion of the implicit voting power delegations resumes
+    /// after, empty to start from the first staker. Set by the storage migration to version 2 and
+    /// dropped once every staker was converted.
+    #[pallet::storage]
+    pub type ImplicitDelegationsCursor<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<256>>>;
+
+    /// The past delegations of each key still needed by open proposals, recorded like the voting
+    /// power checkpoints.
+    #[pallet::storage]
+    pub type VotingPowerDelegationCheckpoints<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        T::AccountId,
+        Identity,
+        DelegationScope,
+        Checkpoints<Option<T::AccountId>>,
+        ValueQuery,
+    >;
+
+    /// The voting power delegated to each key on each scope, updated as the delegators' voting
+    /// power and delegations change.
+    #[pallet::storage]
+    pub type DelegatedVotingPower<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        T::AccountId,
+        Identity,
+        DelegationScope,
+        DelegatedPower,
+        ValueQuery,
+    >;
+
+    /// The past values of `DelegatedVotingPower` still needed by open proposals, recorded like
+    /// the voting power checkpoints.
+    #[pallet::storage]
+    pub type DelegatedVotingPowerCheckpoints<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        T::AccountId,
+        Identity,
+        DelegationScope,
+        Checkpoints<DelegatedPower>,
+        ValueQuery,
+    >;
+
+    /// The proposals open for voting, whose voting power checkpoints are kept, oldest first.
+    #[pallet::storage]
+    pub type OpenProposals<T: Config> =
+        StorageValue<_, BoundedVec<ProposalId, ConstU32<MAX_OPEN_PROPOSALS>>, ValueQuery>;
+
+    /// The voting power of all keys when the proposal was created, bounding the weight of the
+    /// votes it can receive.
+    #[pallet::storage]
+    pub type ProposalTotalVotingPower<T: Config> = StorageMap<_, Identity, ProposalId, u64>;
+
+    /// The voting power keys had when the proposal was created, recorded when they vote.
+    #[pallet::storage]
+    pub type VotingPowerSnapshots<T: Config> =
+        StorageDoubleMap<_, Identity, ProposalId, Identity, T::AccountId, u64>;
+
+    /// The past voting powers of each key still needed by open proposals. Recorded right before
+    /// the voting power of the key changes, and only when an open proposal was created since the
+    /// last record. Keys without a record for a proposal still have the power they had then.
     #[pallet::storage]
-    pub type NotDelegatingVotingPower<T: Config> =
-        StorageValue<_, BoundedBTreeSet<T::AccountId, ConstU32<{ u32::MAX }>>, ValueQuery>;
+    pub type VotingPowerCheckpoints<T: Config> =
+        StorageMap<_, Identity, T::AccountId, Checkpoints<u64>, ValueQuery>;
+
+    /// The open proposals each key voted on.
+    #[pallet::storage]
+    pub type VotedProposals<T: Config> =
+        StorageDoubleMap<_, Identity, T::AccountId, Identity, ProposalId, ()>;
+
+    /// The voting power the delegators of a key took back by voting on the open proposal
+    /// themselves.
+    #[pallet::storage]
+    pub type ReclaimedVotingPower<T: Config> =
+        StorageDoubleMap<_, Identity, ProposalId, Identity, T::AccountId, u64, ValueQuery>;
 
     #[pallet::storage]
     pub type UnrewardedProposals<T: Config> =
         StorageMap<_, Identity, ProposalId, UnrewardedProposal<T>>;
 
+    /// Accepted runtime call proposals waiting for their enactment block.
+    #[pallet::storage]
+    pub type ScheduledRuntimeCalls<T: Config> =
+        StorageMap<_, Identity, ProposalId, (u64, EncodedRuntimeCall)>;
+
+    /// The treasury streams started by accepted stream proposals, indexed by the proposal ID.
+    #[pallet::storage]
+    pub type TreasuryStreams<T: Config> = StorageMap<_, Identity, ProposalId, TreasuryStream<T>>;
+
+    /// Stake locks placed by conviction votes, indexed by the voter.
+    #[pallet::storage]
+    pub type VoteLocks<T: Config> = StorageMap<
+        _,
+        Identity,
+        T::AccountId,
+        BoundedVec<VoteLock, ConstU32<MAX_VOTE_LOCKS>>,
+        ValueQuery,
+    >;
+
+    /// The keys holding a conviction lock that expires at the block, so expired locks are
+    /// released without going through every lock.
+    #[pallet::storage] // --- DMAP ( unlock_block, key ) --> ()
+    pub type VoteLockExpirations<T: Config> =
+        StorageDoubleMap<_, Identity, u64, Identity, T::AccountId, ()>;
+
     // ---------------------------------
     // Treasury
     // ---------------------------------
@@ -177,7 +322,7 @@ pub mod pallet {
         #[pallet::weight((<T as pallet::Config>::WeightInfo::add_global_params_proposal(), DispatchClass::Normal, Pays::No))]
         pub fn add_global_params_proposal(
             origin: OriginFor<T>,
-            data: Vec<u8>,
+            metadata: ProposalMetadata,
             max_name_length: u16,
             min_name_length: u16,
             max_allowed_subnets: u16,
@@ -196,6 +341,8 @@ pub mod pallet {
             kappa: u16,
             rho: u16,
             subnet_immunity_period: u64,
+            unbonding_period: u64,
+            delegation_fee_notice_period: u64,
         ) -> DispatchResult {
             let mut params = pallet_subspace::Pallet::<T>::global_params();
             params.max_name_length = max_name_length;
@@ -216,7 +363,9 @@ pub mod pallet {
             params.kappa = kappa;
             params.rho = rho;
             params.subnet_immunity_period = subnet_immunity_period;
-            Self::do_add_global_params_proposal(origin, data, params)
+            params.unbonding_period = unbonding_period;
+            params.delegation_fee_notice_period = delegation_fee_notice_period;
+            Self::do_add_global_params_proposal(origin, metadata, params)
         }
 
         #[pallet::call_index(1)]
@@ -224,7 +373,7 @@ pub mod pallet {
         pub fn add_subnet_params_proposal(
             origin: OriginFor<T>,
             subnet_id: u16,
-            data: Vec<u8>,
+            metadata: ProposalMetadata,
             founder: T::AccountId,
             name: BoundedVec<u8, ConstU32<256>>,
             founder_share: u16,
@@ -244,6 +393,9 @@ pub mod pallet {
             max_registrations_per_interval: u16,
             adjustment_alpha: u64,
             min_validator_stake: u64,
+            unbonding_period: Option<u64>,
+            commit_reveal_weights: bool,
+            record_epoch_emission: bool,
         ) -> DispatchResult {
             let mut params = pallet_subspace::Pallet::subnet_params(subnet_id);
             params.founder = founder;
@@ -265,14 +417,20 @@ pub mod pallet {
             params.max_registrations_per_interval = max_registrations_per_interval;
             params.adjustment_alpha = adjustment_alpha;
             params.min_validator_stake = min_validator_stake;
+            params.unbonding_period = unbonding_period;
+            params.commit_reveal_weights = commit_reveal_weights;
+            params.record_epoch_emission = record_epoch_emission;
 
-            Self::do_add_subnet_params_proposal(origin, subnet_id, data, params)
+            Self::do_add_subnet_params_proposal(origin, subnet_id, metadata, params)
         }
 
         #[pallet::call_index(2)]
         #[pallet::weight((<T as pallet::Config>::WeightInfo::add_global_custom_proposal(), DispatchClass::Normal, Pays::No))]
-        pub fn add_global_custom_proposal(origin: OriginFor<T>, data: Vec<u8>) -> DispatchResult {
-            Self::do_add_global_custom_proposal(origin, data)
+        pub fn add_global_custom_proposal(
+            origin: OriginFor<T>,
+            metadata: ProposalMetadata,
+        ) -> DispatchResult {
+            Self::do_add_global_custom_proposal(origin, metadata)
         }
 
         #[pallet::call_index(3)]
@@ -280,20 +438,20 @@ pub mod pallet {
         pub fn add_subnet_custom_proposal(
             origin: OriginFor<T>,
             subnet_id: u16,
-            data: Vec<u8>,
+            metadata: ProposalMetadata,
         ) -> DispatchResult {
-            Self::do_add_subnet_custom_proposal(origin, subnet_id, data)
+            Self::do_add_subnet_custom_proposal(origin, subnet_id, metadata)
         }
 
         #[pallet::call_index(4)]
         #[pallet::weight((<T as pallet::Config>::WeightInfo::add_transfer_dao_treasury_proposal(), DispatchClass::Normal, Pays::No))]
         pub fn add_transfer_dao_treasury_proposal(
             origin: OriginFor<T>,
-            data: Vec<u8>,
+            metadata: ProposalMetadata,
             value: u64,
             dest: T::AccountId,
         ) -> DispatchResult {
-            Self::do_add_transfer_dao_treasury_proposal(origin, data, value, dest)
+            Self::do_add_transfer_dao_treasury_proposal(origin, metadata, value, dest)
         }
 
         // ---------------------------------
@@ -308,7 +466,19 @@ pub mod pallet {
             proposal_id: u64,
             agree: bool,
         ) -> DispatchResult {
-            Self::do_vote_proposal(origin, proposal_id, agree)
+            Self::do_vote_proposal(origin, proposal_id, agree.into())
+        }
+
+        // Also pays fee, as it is just a vote with extra steps.
+        #[pallet::call_index(14)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::vote_proposal_with_conviction(), DispatchClass::Normal, Pays::Yes))]
+        pub fn vote_proposal_with_conviction(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            agree: bool,
+            conviction: u8,
+        ) -> DispatchResult {
+            Self::do_vote_proposal_with_conviction(origin, proposal_id, agree, conviction)
         }
 
         #[pallet::call_index(6)]
@@ -317,20 +487,6 @@ pub mod pallet {
             Self::do_remove_vote_proposal(origin, proposal_id)
         }
 
-        #[pallet::call_index(7)]
-        #[pallet::weight((<T as pallet::Config>::WeightInfo::enable_vote_power_delegation(), DispatchClass::Normal, Pays::No))]
-        pub fn enable_vote_power_delegation(origin: OriginFor<T>) -> DispatchResult {
-            let key = ensure_signed(origin)?;
-            Self::update_delegating_voting_power(&key, true)
-        }
-
-        #[pallet::call_index(8)]
-        #[pallet::weight((<T as pallet::Config>::WeightInfo::disable_vote_power_delegation(), DispatchClass::Normal, Pays::No))]
-        pub fn disable_vote_power_delegation(origin: OriginFor<T>) -> DispatchResult {
-            let key = ensure_signed(origin)?;
-            Self::update_delegating_voting_power(&key, false)
-        }
-
         // ---------------------------------
         // Subnet 0 DAO
         // ---------------------------------
@@ -369,6 +525,121 @@ pub mod pallet {
         ) -> DispatchResult {
             Self::do_remove_from_whitelist(origin, module_key)
         }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_subnet_pricing_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_subnet_pricing_proposal(
+            origin: OriginFor<T>,
+            metadata: ProposalMetadata,
+            mechanism: SubnetPricingMechanism,
+        ) -> DispatchResult {
+            Self::do_add_subnet_pricing_proposal(origin, metadata, mechanism)
+        }
+
+        #[pallet::call_index(15)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_runtime_call_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_runtime_call_proposal(
+            origin: OriginFor<T>,
+            metadata: ProposalMetadata,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResult {
+            Self::do_add_runtime_call_proposal(origin, metadata, call)
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_global_params_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_global_params_patch_proposal(
+            origin: OriginFor<T>,
+            metadata: ProposalMetadata,
+            patch: GlobalParamsPatch<T>,
+        ) -> DispatchResult {
+            Self::do_add_global_params_patch_proposal(origin, metadata, patch)
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_subnet_params_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_subnet_params_patch_proposal(
+            origin: OriginFor<T>,
+            subnet_id: u16,
+            metadata: ProposalMetadata,
+            patch: SubnetParamsPatch<T>,
+        ) -> DispatchResult {
+            Self::do_add_subnet_params_patch_proposal(origin, subnet_id, metadata, patch)
+        }
+
+        // Pays fee like any other vote.
+        #[pallet::call_index(18)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::vote_proposal(), DispatchClass::Normal, Pays::Yes))]
+        pub fn abstain_proposal(origin: OriginFor<T>, proposal_id: u64) -> DispatchResult {
+            Self::do_vote_proposal(origin, proposal_id, Vote::Abstain)
+        }
+
+        #[pallet::call_index(19)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::delegate_voting_power(), DispatchClass::Normal, Pays::No))]
+        pub fn delegate_voting_power(
+            origin: OriginFor<T>,
+            delegate: T::AccountId,
+            scope: DelegationScope,
+        ) -> DispatchResult {
+            Self::do_delegate_voting_power(origin, delegate, scope)
+        }
+
+        #[pallet::call_index(20)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::undelegate_voting_power(), DispatchClass::Normal, Pays::No))]
+        pub fn undelegate_voting_power(
+            origin: OriginFor<T>,
+            scope: DelegationScope,
+        ) -> DispatchResult {
+            Self::do_undelegate_voting_power(origin, scope)
+        }
+
+        #[pallet::call_index(21)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::amend_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn amend_proposal(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            metadata: ProposalMetadata,
+            data: Option<ProposalData<T>>,
+        ) -> DispatchResult {
+            Self::do_amend_proposal(origin, proposal_id, metadata, data)
+        }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::cancel_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: u64) -> DispatchResult {
+            Self::do_cancel_proposal(origin, proposal_id)
+        }
+
+        #[pallet::call_index(23)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_treasury_stream_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_treasury_stream_proposal(
+            origin: OriginFor<T>,
+            metadata: ProposalMetadata,
+            recipient: T::AccountId,
+            amount: u64,
+            duration: u64,
+            cliff: u64,
+        ) -> DispatchResult {
+            Self::do_add_treasury_stream_proposal(
+                origin, metadata, recipient, amount, duration, cliff,
+            )
+        }
+
+        #[pallet::call_index(24)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_treasury_stream_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_cancel_treasury_stream_proposal(
+            origin: OriginFor<T>,
+            metadata: ProposalMetadata,
+            stream_id: ProposalId,
+        ) -> DispatchResult {
+            Self::do_add_cancel_treasury_stream_proposal(origin, metadata, stream_id)
+        }
+
+        #[pallet::call_index(25)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::claim_stream(), DispatchClass::Normal, Pays::No))]
+        pub fn claim_stream(origin: OriginFor<T>, stream_id: ProposalId) -> DispatchResult {
+            Self::do_claim_stream(origin, stream_id)
+        }
     }
 
     // ---------------------------------
@@ -384,12 +655,34 @@ pub mod pallet {
         ProposalAccepted(ProposalId),
         /// A proposal has been refused.
         ProposalRefused(ProposalId),
+        /// A proposal has been cancelled by its proposer.
+        ProposalCancelled(ProposalId),
+        /// A proposal has been amended by its proposer.
+        ProposalAmended(ProposalId),
+        /// A treasury stream has started paying the given amount to the recipient.
+        TreasuryStreamCreated(ProposalId, T::AccountId, u64),
+        /// The recipient of a treasury stream has claimed the given amount.
+        TreasuryStreamClaimed(ProposalId, u64),
+        /// A treasury stream has been cancelled, returning the given amount to the treasury.
+        TreasuryStreamCancelled(ProposalId, u64),
         /// A proposal has expired.
         ProposalExpired(ProposalId),
         /// A vote has been cast on a proposal.
         ProposalVoted(u64, T::AccountId, bool),
+        /// A key delegated its voting power on the given scope to an account.
+        VotingPowerDelegated(T::AccountId, T::AccountId, DelegationScope),
+        /// A key stopped delegating its voting power on the given scope.
+        VotingPowerUndelegated(T::AccountId, DelegationScope),
+        /// A key abstained from voting on a proposal.
+        ProposalVoteAbstained(u64, T::AccountId),
         /// A vote has been unregistered from a proposal.
         ProposalVoteUnregistered(u64, T::AccountId),
+        /// An accepted runtime call proposal will be dispatched at the given block.
+        RuntimeCallScheduled(ProposalId, u64),
+        /// The runtime call of an accepted proposal was dispatched.
+        RuntimeCallDispatched(ProposalId, DispatchResult),
+        /// A conviction vote locked the voter's stake until the given block.
+        ProposalVoteLocked(u64, T::AccountId, u64, u64),
         /// A module account has been added to the whitelist.
         WhitelistModuleAdded(T::AccountId),
         /// A module account has been removed from the whitelist.
@@ -413,20 +706,22 @@ pub mod pallet {
         InvalidProposalCost,
         /// Negative expiration when setting global or subnet governance configuration.
         InvalidProposalExpiration,
+        /// Approval threshold below 50% when setting global or subnet governance configuration.
+        InvalidProposalRules,
         /// Key doesn't have enough tokens to create a proposal.
         NotEnoughBalanceToPropose,
-        /// Proposal data is empty.
-        ProposalDataTooSmall,
-        /// Proposal data is bigger than 256 characters.
-        ProposalDataTooLarge,
+        /// Proposal title is empty or isn't composed by valid UTF-8 characters.
+        InvalidProposalTitle,
+        /// Proposal CID isn't a valid IPFS CID.
+        InvalidProposalCid,
         /// The staked module is already delegating for 2 ^ 32 keys.
         ModuleDelegatingForMaxStakers,
         /// Proposal with given id doesn't exist.
         ProposalNotFound,
         /// Proposal was either accepted, refused or expired and cannot accept votes.
         ProposalClosed,
-        /// Proposal data isn't composed by valid UTF-8 characters.
-        InvalidProposalData,
+        /// Proposal discussion URL isn't a valid `https` URL.
+        InvalidProposalDiscussionUrl,
         /// Invalid value given when transforming a u64 into T::Currency.
         InvalidCurrencyConversionValue,
         /// Dao Treasury doesn't have enough funds to be transferred.
@@ -439,9 +734,10 @@ pub mod pallet {
         NotVoted,
         /// Key doesn't have enough stake to vote.
         InsufficientStake,
-        /// The voter is delegating its voting power to their staked modules. Disable voting power
-        /// delegation.
-        VoterIsDelegatingVotingPower,
+        /// A key can't delegate its voting power to itself.
+        DelegationCycle,
+        /// The key is not delegating its voting power on the given scope.
+        NotDelegating,
         /// The network vote mode must be authority for changes to be imposed.
         VoteModeIsNotAuthority,
         /// An internal error occurred, probably relating to the size of the bounded sets.
@@ -468,6 +764,41 @@ pub mod pallet {
         NotWhitelisted,
         /// Failed to convert the given value to a balance.
         CouldNotConvertToBalance,
+        /// The fixed allocation table lists a subnet twice or has no positive share.
+        InvalidSubnetPricingMechanism,
+        /// The conviction must be between 1 and the maximum conviction.
+        InvalidConviction,
+        /// The key already holds the maximum number of conviction locks.
+        TooManyVoteLocks,
+        /// The encoded runtime call is larger than the proposal limit.
+        RuntimeCallTooLarge,
+        /// The runtime call would not fit in a block.
+        RuntimeCallTooHeavy,
+        /// The runtime call could not be decoded.
+        InvalidRuntimeCall,
+        /// Only the proposer can amend or cancel the proposal.
+        NotProposer,
+        /// The proposal can no longer be amended, as voting has opened.
+        AmendmentPeriodOver,
+        /// The amended proposal data is of a different kind or targets a different subnet.
+        InvalidProposalAmendment,
+        /// The proposal is still in its amendment period and can't be voted on yet.
+        VotingNotOpen,
+        /// The stream amount and duration must be positive, and the cliff can't exceed the
+        /// duration.
+        InvalidTreasuryStream,
+        /// Treasury stream with given id doesn't exist.
+        TreasuryStreamNotFound,
+        /// Only the recipient can claim the stream.
+        NotStreamRecipient,
+        /// Nothing has vested on the stream since the last claim.
+        NothingToClaim,
+        /// The maximum number of proposals are already open for voting.
+        TooManyOpenProposals,
+        /// The key already delegates its voting power on the maximum number of scopes.
+        TooManyDelegations,
+        /// The votes on the proposal already settled it, they can no longer be removed.
+        ProposalSettled,
     }
 }
 
@@ -484,32 +815,23 @@ impl<T: Config> Pallet<T> {
             config.proposal_expiration > 0,
             Error::<T>::InvalidProposalExpiration
         );
+        for rules in [
+            config.custom_proposal_rules,
+            config.params_proposal_rules,
+            config.treasury_proposal_rules,
+            config.subnet_pricing_proposal_rules,
+            config.runtime_call_proposal_rules,
+        ] {
+            ensure!(
+                rules.approval_threshold >= Percent::from_percent(50),
+                Error::<T>::InvalidProposalRules
+            );
+        }
         Ok(config)
     }
 }
 
 impl<T: Config> Pallet<T> {
-    pub fn is_delegating_voting_power(delegator: &T::AccountId) -> bool {
-        !NotDelegatingVotingPower::<T>::get().contains(delegator)
-    }
-
-    pub fn update_delegating_voting_power(
-        delegator: &T::AccountId,
-        delegating: bool,
-    ) -> DispatchResult {
-        NotDelegatingVotingPower::<T>::mutate(|delegators| {
-            if !delegating {
-                delegators
-                    .try_insert(delegator.clone())
-                    .map(|_| ())
-                    .map_err(|_| Error::<T>::InternalError.into())
-            } else {
-                delegators.remove(delegator);
-                Ok(())
-            }
-        })
-    }
-
     pub fn update_global_governance_configuration(
         config: GovernanceConfiguration,
     ) -> DispatchResult {
@@ -529,5 +851,160 @@ impl<T: Config> Pallet<T> {
 
     pub fn handle_subnet_removal(subnet_id: u16) {
         SubnetGovernanceConfig::<T>::remove(subnet_id);
+
+        // Open proposals of the subnet keep the delegations recorded for them
+        let scope = DelegationScope::Subnet(subnet_id);
+        let delegators: Vec<_> = VotingPowerDelegations::<T>::iter()
+            .filter(|(_, delegation_scope, _)| *delegation_scope == scope)
+            .map(|(delegator, ..)| delegator)
+            .collect();
+        for delegator in delegators {
+            Self::set_delegation(&delegator, scope, None);
+        }
+    }
+
+    pub fn handle_module_key_rotation(key: &T::AccountId, new_key: &T::AccountId) {
+        // Delegations made by the key and to it follow the key, along with their past values.
+        let delegations: Vec<_> = VotingPowerDelegations::<T>::drain_prefix(key).collect();
+        for (scope, delegate) in delegations {
+            VotingPowerDelegators::<T>::remove(&delegate, (key.clone(), scope));
+            VotingPowerDelegators::<T>::insert(&delegate, (new_key.clone(), scope), ());
+            VotingPowerDelegations::<T>::insert(new_key, scope, delegate);
+        }
+        let checkpoints: Vec<_> =
+            VotingPowerDelegationCheckpoints::<T>::drain_prefix(key).collect();
+        for (scope, checkpoints) in checkpoints {
+            VotingPowerDelegationCheckpoints::<T>::insert(new_key, scope, checkpoints);
+        }
+        let delegators: Vec<_> = VotingPowerDelegators::<T>::drain_prefix(key).collect();
+        for ((delegator, scope), ()) in delegators {
+            VotingPowerDelegations::<T>::insert(&delegator, scope, new_key);
+            VotingPowerDelegators::<T>::insert(new_key, (delegator.clone(), scope), ());
+            VotingPowerDelegationCheckpoints::<T>::mutate_exists(
+                &delegator,
+                scope,
+                |checkpoints| {
+                    for (_, delegate) in
+                        checkpoints.iter_mut().flat_map(|checkpoints| checkpoints.iter_mut())
+                    {
+                        if delegate.as_ref() == Some(key) {
+                            *delegate = Some(new_key.clone());
+                        }
+                    }
+                },
+            );
+        }
+
+        // So does the power delegated to the key, and the power its delegators took back from it.
+        let delegated: Vec<_> = DelegatedVotingPower::<T>::drain_prefix(key).collect();
+        for (scope, power) in delegated {
+            DelegatedVotingPower::<T>::insert(new_key, scope, power);
+        }
+        let checkpoints: Vec<_> = DelegatedVotingPowerCheckpoints::<T>::drain_prefix(key).collect();
+        for (scope, checkpoints) in checkpoints {
+            DelegatedVotingPowerCheckpoints::<T>::insert(new_key, scope, checkpoints);
+        }
+        for proposal_id in OpenProposals::<T>::get() {
+            let reclaimed = ReclaimedVotingPower::<T>::take(proposal_id, key);
+            if reclaimed > 0 {
+                ReclaimedVotingPower::<T>::insert(proposal_id, new_key, reclaimed);
+            }
+        }
+
+        if LegitWhitelist::<T>::contains_key(key) {
+            LegitWhitelist::<T>::insert(new_key, LegitWhitelist::<T>::take(key));
+        }
+
+        // The new key holds no stake, so none of its locks can be active and they are replaced.
+        let locks = VoteLocks::<T>::take(key);
+        for lock in &locks {
+            VoteLockExpirations::<T>::remove(lock.unlock_block, key);
+            VoteLockExpirations::<T>::insert(lock.unlock_block, new_key, ());
+        }
+        if !locks.is_empty() {
+            VoteLocks::<T>::insert(new_key, locks);
+        }
+
+        // The past voting powers of the key follow it, along with its stake.
+        if VotingPowerCheckpoints::<T>::contains_key(key) {
+            VotingPowerCheckpoints::<T>::insert(new_key, VotingPowerCheckpoints::<T>::take(key));
+        }
+
+        // Votes on open proposals follow the key, as they are weighted by its stake.
+        let voted: Vec<_> = VotedProposals::<T>::drain_prefix(key).map(|(id, ())| id).collect();
+        for proposal_id in voted {
+            VotedProposals::<T>::insert(new_key, proposal_id, ());
+            if let Some(power) = VotingPowerSnapshots::<T>::take(proposal_id, key) {
+                VotingPowerSnapshots::<T>::insert(proposal_id, new_key, power);
+            }
+            let Some(mut proposal) = Proposals::<T>::get(proposal_id) else {
+                continue;
+            };
+
+            let ProposalStatus::Open {
+                votes_for,
+                votes_against,
+                votes_abstain,
+                ..
+            } = &mut proposal.status
+            else {
+                continue;
+            };
+
+            let mut voted = false;
+            for votes in [votes_for, votes_against, votes_abstain] {
+                if votes.remove(key) {
+                    let _ = votes.try_insert(new_key.clone());
+                    voted = true;
+                }
+            }
+
+            if voted {
+                Proposals::<T>::insert(proposal.id, proposal);
+            }
+        }
+    }
+
+    /// Whether the key delegates voting power or has power delegated to it, voted on open
+    /// proposals, holds conviction locks, past voting powers or a whitelist entry. A module key
+    /// can't be rotated to such a key, as its state would be overwritten by the module's.
+    pub fn has_governance_state(key: &T::AccountId) -> bool {
+        VotingPowerDelegations::<T>::iter_key_prefix(key).next().is_some()
+            || VotingPowerDelegators::<T>::iter_key_prefix(key).next().is_some()
+            || VotingPowerDelegationCheckpoints::<T>::iter_key_prefix(key).next().is_some()
+            || DelegatedVotingPower::<T>::iter_key_prefix(key).next().is_some()
+            || DelegatedVotingPowerCheckpoints::<T>::iter_key_prefix(key).next().is_some()
+            || VotedProposals::<T>::iter_key_prefix(key).next().is_some()
+            || !VoteLocks::<T>::get(key).is_empty()
+            || !VotingPowerCheckpoints::<T>::get(key).is_empty()
+            || LegitWhitelist::<T>::contains_key(key)
+            || OpenProposals::<T>::get()
+                .iter()
+                .any(|proposal_id| ReclaimedVotingPower::<T>::contains_key(proposal_id, key))
+    }
+
+    /// Counts the entries `handle_module_key_rotation` moves for the key: its voting power
+    /// delegations, given and received, the power delegated to it, their checkpoints, its votes
+    /// on open proposals and the power its delegators took back on them. Stops counting past
+    /// `limit`.
+    pub fn module_key_rotation_entries(key: &T::AccountId, limit: u32) -> u32 {
+        let entries = VotingPowerDelegations::<T>::iter_key_prefix(key)
+            .map(|_| ())
+            .chain(VotingPowerDelegators::<T>::iter_key_prefix(key).map(|_| ()))
+            .chain(VotingPowerDelegationCheckpoints::<T>::iter_key_prefix(key).map(|_| ()))
+            .chain(DelegatedVotingPower::<T>::iter_key_prefix(key).map(|_| ()))
+            .chain(DelegatedVotingPowerCheckpoints::<T>::iter_key_prefix(key).map(|_| ()))
+            .chain(
+                OpenProposals::<T>::get()
+                    .into_iter()
+                    .filter(|proposal_id| ReclaimedVotingPower::<T>::contains_key(proposal_id, key))
+                    .map(|_| ()),
+            )
+            .chain(VotingPowerCheckpoints::<T>::contains_key(key).then_some(()))
+            .chain(VotedProposals::<T>::iter_key_prefix(key).map(|_| ()))
+            .take(limit.saturating_add(1) as usize)
+            .count();
+
+        entries as u32
     }
 }
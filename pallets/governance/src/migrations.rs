@@ -1,6 +1,11 @@
 use crate::*;
 use core::marker::PhantomData;
-use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{ConstU32, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+    BoundedBTreeSet, BoundedVec,
+};
 
 pub type MigrationV1<T> =
     VersionedMigration<0, 1, _MigrationV1<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
@@ -15,3 +20,445 @@ impl<T: Config + pallet_subspace::Config> UncheckedOnRuntimeUpgrade for _Migrati
         frame_support::weights::Weight::zero()
     }
 }
+
+pub type MigrationV2<T> =
+    VersionedMigration<1, 2, _MigrationV2<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+/// The governance types as they were stored before the storage version 2.
+pub mod v1 {
+    use super::*;
+    use frame_support::BoundedVec;
+    use pallet_subspace::global::BurnConfiguration;
+    use parity_scale_codec::{Decode, Encode};
+    use sp_runtime::Percent;
+
+    #[derive(Decode, Encode)]
+    pub struct GovernanceConfiguration {
+        pub proposal_cost: u64,
+        pub proposal_expiration: u32,
+        pub vote_mode: VoteMode,
+        pub proposal_reward_treasury_allocation: Percent,
+        pub max_proposal_reward_treasury_allocation: u64,
+        pub proposal_reward_interval: u64,
+    }
+
+    impl GovernanceConfiguration {
+        /// Returns the given configuration with the fields of the old one.
+        pub fn merge(
+            self,
+            config: crate::GovernanceConfiguration,
+        ) -> crate::GovernanceConfiguration {
+            crate::GovernanceConfiguration {
+                proposal_cost: self.proposal_cost,
+                proposal_expiration: self.proposal_expiration,
+                vote_mode: self.vote_mode,
+                proposal_reward_treasury_allocation: self.proposal_reward_treasury_allocation,
+                max_proposal_reward_treasury_allocation: self
+                    .max_proposal_reward_treasury_allocation,
+                proposal_reward_interval: self.proposal_reward_interval,
+                ..config
+            }
+        }
+    }
+
+    impl From<GovernanceConfiguration> for crate::GovernanceConfiguration {
+        fn from(old: GovernanceConfiguration) -> Self {
+            old.merge(Default::default())
+        }
+    }
+
+    #[derive(Decode, Encode)]
+    pub struct GlobalParams<T: Config> {
+        pub max_name_length: u16,
+        pub min_name_length: u16,
+        pub max_allowed_subnets: u16,
+        pub max_allowed_modules: u16,
+        pub max_registrations_per_block: u16,
+        pub max_allowed_weights: u16,
+        pub floor_delegation_fee: Percent,
+        pub floor_founder_share: u8,
+        pub min_weight_stake: u64,
+        pub curator: T::AccountId,
+        pub general_subnet_application_cost: u64,
+        pub subnet_immunity_period: u64,
+        pub burn_config: BurnConfiguration<T>,
+        pub governance_config: GovernanceConfiguration,
+        pub kappa: u16,
+        pub rho: u16,
+    }
+
+    impl<T: Config> GlobalParams<T> {
+        /// The params added since keep their current value.
+        pub fn migrate(self) -> pallet_subspace::GlobalParams<T> {
+            let current = pallet_subspace::Pallet::<T>::global_params();
+            pallet_subspace::GlobalParams {
+                max_name_length: self.max_name_length,
+                min_name_length: self.min_name_length,
+                max_allowed_subnets: self.max_allowed_subnets,
+                max_allowed_modules: self.max_allowed_modules,
+                max_registrations_per_block: self.max_registrations_per_block,
+                max_allowed_weights: self.max_allowed_weights,
+                floor_delegation_fee: self.floor_delegation_fee,
+                floor_founder_share: self.floor_founder_share,
+                min_weight_stake: self.min_weight_stake,
+                curator: self.curator,
+                general_subnet_application_cost: self.general_subnet_application_cost,
+                subnet_immunity_period: self.subnet_immunity_period,
+                burn_config: self.burn_config,
+                governance_config: self.governance_config.merge(GlobalGovernanceConfig::<T>::get()),
+                kappa: self.kappa,
+                rho: self.rho,
+                ..current
+            }
+        }
+    }
+
+    #[derive(Decode, Encode)]
+    pub struct SubnetParams<T: Config> {
+        pub founder: T::AccountId,
+        pub founder_share: u16,
+        pub immunity_period: u16,
+        pub incentive_ratio: u16,
+        pub max_allowed_uids: u16,
+        pub max_allowed_weights: u16,
+        pub min_allowed_weights: u16,
+        pub max_weight_age: u64,
+        pub name: BoundedVec<u8, ConstU32<256>>,
+        pub metadata: Option<BoundedVec<u8, ConstU32<59>>>,
+        pub tempo: u16,
+        pub trust_ratio: u16,
+        pub maximum_set_weight_calls_per_epoch: u16,
+        pub bonds_ma: u64,
+        pub target_registrations_interval: u16,
+        pub target_registrations_per_interval: u16,
+        pub max_registrations_per_interval: u16,
+        pub adjustment_alpha: u64,
+        pub min_validator_stake: u64,
+        pub governance_config: GovernanceConfiguration,
+    }
+
+    impl<T: Config> SubnetParams<T> {
+        /// The params added since keep the current value of the subnet.
+        pub fn migrate(self, subnet_id: SubnetId) -> pallet_subspace::SubnetParams<T> {
+            let current = pallet_subspace::Pallet::<T>::subnet_params(subnet_id);
+            pallet_subspace::SubnetParams {
+                founder: self.founder,
+                founder_share: self.founder_share,
+                immunity_period: self.immunity_period,
+                incentive_ratio: self.incentive_ratio,
+                max_allowed_uids: self.max_allowed_uids,
+                max_allowed_weights: self.max_allowed_weights,
+                min_allowed_weights: self.min_allowed_weights,
+                max_weight_age: self.max_weight_age,
+                name: self.name,
+                metadata: self.metadata,
+                tempo: self.tempo,
+                trust_ratio: self.trust_ratio,
+                maximum_set_weight_calls_per_epoch: self.maximum_set_weight_calls_per_epoch,
+                bonds_ma: self.bonds_ma,
+                target_registrations_interval: self.target_registrations_interval,
+                target_registrations_per_interval: self.target_registrations_per_interval,
+                max_registrations_per_interval: self.max_registrations_per_interval,
+                adjustment_alpha: self.adjustment_alpha,
+                min_validator_stake: self.min_validator_stake,
+                governance_config: self
+                    .governance_config
+                    .merge(SubnetGovernanceConfig::<T>::get(subnet_id)),
+                ..current
+            }
+        }
+    }
+
+    #[derive(Decode, Encode)]
+    pub enum ProposalData<T: Config> {
+        GlobalCustom,
+        GlobalParams(GlobalParams<T>),
+        SubnetCustom {
+            subnet_id: SubnetId,
+        },
+        SubnetParams {
+            subnet_id: SubnetId,
+            params: SubnetParams<T>,
+        },
+        TransferDaoTreasury {
+            account: T::AccountId,
+            amount: u64,
+        },
+    }
+
+    impl<T: Config> ProposalData<T> {
+        pub fn migrate(self) -> crate::ProposalData<T> {
+            match self {
+                Self::GlobalCustom => crate::ProposalData::GlobalCustom,
+                Self::GlobalParams(params) => crate::ProposalData::GlobalParams(params.migrate()),
+                Self::SubnetCustom { subnet_id } => crate::ProposalData::SubnetCustom { subnet_id },
+                Self::SubnetParams { subnet_id, params } => crate::ProposalData::SubnetParams {
+                    subnet_id,
+                    params: params.migrate(subnet_id),
+                },
+                Self::TransferDaoTreasury { account, amount } => {
+                    crate::ProposalData::TransferDaoTreasury { account, amount }
+                }
+            }
+        }
+    }
+
+    #[derive(Decode, Encode)]
+    pub enum ProposalStatus<T: Config> {
+        Open {
+            votes_for: BoundedBTreeSet<T::AccountId, ConstU32<{ u32::MAX }>>,
+            votes_against: BoundedBTreeSet<T::AccountId, ConstU32<{ u32::MAX }>>,
+            stake_for: u64,
+            stake_against: u64,
+        },
+        Accepted {
+            block: u64,
+            stake_for: u64,
+            stake_against: u64,
+        },
+        Refused {
+            block: u64,
+            stake_for: u64,
+            stake_against: u64,
+        },
+        Expired,
+    }
+
+    #[derive(Decode, Encode)]
+    pub struct Proposal<T: Config> {
+        pub id: ProposalId,
+        pub proposer: T::AccountId,
+        pub expiration_block: u64,
+        pub data: ProposalData<T>,
+        pub status: ProposalStatus<T>,
+        pub metadata: BoundedVec<u8, ConstU32<256>>,
+        pub proposal_cost: u64,
+        pub creation_block: u64,
+    }
+
+    /// The stakers that kept their voting power instead of passing it to the staked modules.
+    #[frame_support::storage_alias]
+    pub type NotDelegatingVotingPower<T: Config> = StorageValue<
+        Pallet<T>,
+        BoundedBTreeSet<<T as frame_system::Config>::AccountId, ConstU32<{ u32::MAX }>>,
+        ValueQuery,
+    >;
+
+    /// The old metadata was free text, usually the IPFS CID of the proposal.
+    pub fn metadata(old: BoundedVec<u8, ConstU32<256>>) -> ProposalMetadata {
+        if proposal::is_valid_cid(&old) {
+            ProposalMetadata {
+                cid: BoundedVec::truncate_from(old.into_inner()),
+                ..Default::default()
+            }
+        } else {
+            ProposalMetadata {
+                title: BoundedVec::truncate_from(old.into_inner()),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+#[doc(hidden)]
+pub struct _MigrationV2<T>(PhantomData<T>);
+
+/// Adds the proposal rules to the governance configurations and the abstentions to the open
+/// proposals, and moves the proposals metadata to the structured format. The params proposals get
+/// the current value of the params added since they were stored. The voting power of the voters of
+/// the open proposals is recorded, so the tallies stop following stake changes, and their votes are
+/// indexed by voter. The implicit voting power delegation to the staked modules is turned into an
+/// explicit delegation on all proposals to the module holding most of the staker's stake, over the
+/// following blocks by [`convert_implicit_delegations`]. The open proposals only count the power of
+/// their voters, as the delegations are made after they were created.
+impl<T: Config + pallet_subspace::Config> UncheckedOnRuntimeUpgrade for _MigrationV2<T> {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        let mut reads = 1u64;
+        let mut writes = 1u64;
+
+        let _ =
+            GlobalGovernanceConfig::<T>::translate(|old: Option<v1::GovernanceConfiguration>| {
+                old.map(Into::into)
+            });
+        SubnetGovernanceConfig::<T>::translate(|_, old: v1::GovernanceConfiguration| {
+            reads = reads.saturating_add(1);
+            writes = writes.saturating_add(1);
+            Some(old.into())
+        });
+
+        // Stakers passed their voting power to the modules they staked on unless they opted out.
+        // Their delegations are converted a few stakers per block from the governance hook.
+        ImplicitDelegationsCursor::<T>::put(BoundedVec::new());
+        writes = writes.saturating_add(1);
+
+        let mut open_proposals = Vec::new();
+        Proposals::<T>::translate(|_, old: v1::Proposal<T>| {
+            reads = reads.saturating_add(1);
+            writes = writes.saturating_add(1);
+
+            let status = match old.status {
+                v1::ProposalStatus::Open {
+                    votes_for,
+                    votes_against,
+                    ..
+                } => {
+                    open_proposals.push(old.id);
+                    for voter in votes_for.iter().chain(votes_against.iter()) {
+                        let power = Pallet::<T>::get_voting_power(voter);
+                        VotingPowerSnapshots::<T>::insert(old.id, voter, power);
+                        VotedProposals::<T>::insert(voter, old.id, ());
+                        writes = writes.saturating_add(2);
+                    }
+
+                    // The tallies are computed below, once the proposal is built
+                    ProposalStatus::Open {
+                        votes_for,
+                        votes_against,
+                        votes_abstain: BoundedBTreeSet::new(),
+                        stake_for: 0,
+                        stake_against: 0,
+                        stake_abstain: 0,
+                    }
+                }
+                v1::ProposalStatus::Accepted {
+                    block,
+                    stake_for,
+                    stake_against,
+                } => ProposalStatus::Accepted {
+                    block,
+                    stake_for,
+                    stake_against,
+                },
+                v1::ProposalStatus::Refused {
+                    block,
+                    stake_for,
+                    stake_against,
+                } => ProposalStatus::Refused {
+                    block,
+                    stake_for,
+                    stake_against,
+                },
+                v1::ProposalStatus::Expired => ProposalStatus::Expired,
+            };
+
+            let mut proposal = Proposal {
+                id: old.id,
+                proposer: old.proposer,
+                expiration_block: old.expiration_block,
+                data: old.data.migrate(),
+                status,
+                metadata: v1::metadata(old.metadata),
+                proposal_cost: old.proposal_cost,
+                creation_block: old.creation_block,
+                voting_start_block: old.creation_block,
+            };
+            let weights = Pallet::<T>::get_vote_weights(&proposal);
+            Pallet::<T>::update_tallies(&mut proposal, &weights);
+
+            Some(proposal)
+        });
+
+        // Proposals beyond the limit keep weighing the live voting power of keys that didn't vote
+        open_proposals.sort_unstable();
+        let migrated = open_proposals.len();
+        if migrated > MAX_OPEN_PROPOSALS as usize {
+            log::warn!(
+                "only the first {MAX_OPEN_PROPOSALS} of {migrated} open proposals are tracked"
+            );
+        }
+        OpenProposals::<T>::put(BoundedVec::truncate_from(open_proposals));
+        writes = writes.saturating_add(1);
+
+        log::info!("recorded the voting power snapshots of {migrated} open proposals");
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+/// How many stakers get their implicit voting power delegation converted per block.
+pub const IMPLICIT_DELEGATIONS_PER_BLOCK: u32 = 64;
+
+/// Converts the implicit voting power delegations of up to `limit` stakers, resuming after
+/// [`ImplicitDelegationsCursor`]. Each staker that didn't opt out of the implicit delegation, nor
+/// delegated on all proposals since, delegates on all proposals to the module holding most of its
+/// stake. `NotDelegatingVotingPower` and the cursor are dropped once every staker was converted.
+/// Returns the weight consumed.
+pub fn convert_implicit_delegations<T: Config>(limit: u32) -> Weight {
+    let Some(cursor) = ImplicitDelegationsCursor::<T>::get() else {
+        return Weight::zero();
+    };
+    let not_delegating = v1::NotDelegatingVotingPower::<T>::get();
+    let mut reads = 2u64;
+    let mut writes = 0u64;
+    let mut converted = 0u64;
+
+    let mut stakes = if cursor.is_empty() {
+        pallet_subspace::StakeTo::<T>::iter()
+    } else {
+        pallet_subspace::StakeTo::<T>::iter_from(cursor.into_inner())
+    };
+
+    // The stakes of a staker are next to each other, a staker is converted once its last one is
+    // read, and the cursor is left on it
+    let convert = |staker: &T::AccountId, largest: Option<(T::AccountId, u64)>| {
+        let Some((module, _)) = largest else {
+            return false;
+        };
+        if not_delegating.contains(staker)
+            || VotingPowerDelegations::<T>::contains_key(staker, DelegationScope::All)
+        {
+            return false;
+        }
+
+        Pallet::<T>::set_delegation(staker, DelegationScope::All, Some(module));
+        true
+    };
+
+    let mut stakers = 0u32;
+    let mut current: Option<(T::AccountId, Option<(T::AccountId, u64)>)> = None;
+    let mut last_key = Vec::new();
+    let done = loop {
+        let Some((staker, staked, stake)) = stakes.next() else {
+            break true;
+        };
+        reads = reads.saturating_add(1);
+
+        if current.as_ref().map_or(true, |(current, _)| *current != staker) {
+            if let Some((previous, largest)) = current.take() {
+                reads = reads.saturating_add(1);
+                converted = converted.saturating_add(convert(&previous, largest) as u64);
+                stakers = stakers.saturating_add(1);
+                if stakers >= limit {
+                    break false;
+                }
+            }
+            current = Some((staker.clone(), None));
+        }
+
+        if let Some((_, largest)) = current.as_mut() {
+            if staker != staked && largest.as_ref().map_or(true, |(_, largest)| stake > *largest) {
+                *largest = Some((staked, stake));
+            }
+        }
+        last_key = stakes.last_raw_key().to_vec();
+    };
+
+    if done {
+        if let Some((previous, largest)) = current.take() {
+            reads = reads.saturating_add(1);
+            converted = converted.saturating_add(convert(&previous, largest) as u64);
+        }
+        ImplicitDelegationsCursor::<T>::kill();
+        v1::NotDelegatingVotingPower::<T>::kill();
+        writes = writes.saturating_add(2);
+        log::info!("converted the implicit voting power delegations");
+    } else {
+        ImplicitDelegationsCursor::<T>::put(BoundedVec::truncate_from(last_key));
+        writes = writes.saturating_add(1);
+    }
+
+    T::DbWeight::get()
+        .reads_writes(reads, writes)
+        .saturating_add(T::WeightInfo::delegate_voting_power().saturating_mul(converted))
+}
@@ -1,24 +1,96 @@
 use crate::*;
 use frame_support::{
-    dispatch::DispatchResult,
+    dispatch::{DispatchResult, GetDispatchInfo},
     ensure,
-    sp_runtime::{DispatchError, SaturatedConversion},
+    sp_runtime::{traits::Dispatchable, DispatchError, SaturatedConversion},
     storage::with_storage_layer,
-    traits::ConstU32,
-    BoundedBTreeMap, BoundedBTreeSet, BoundedVec, DebugNoBound,
+    traits::{ConstU32, EnsureOrigin, Get, IsSubType},
+    weights::Weight,
+    BoundedBTreeMap, BoundedBTreeSet, BoundedVec, CloneNoBound, DebugNoBound,
 };
-use frame_system::ensure_signed;
+use frame_system::{ensure_signed, RawOrigin};
+use pallet_subnet_emission_api::{SubnetEmissionApi, SubnetPricingMechanism};
 use pallet_subspace::{
-    subnet::SubnetChangeset, Event as SubspaceEvent, GlobalParams, Pallet as PalletSubspace,
-    SubnetParams, TotalStake,
+    global::GlobalParamsPatch,
+    subnet::{SubnetChangeset, SubnetParamsPatch},
+    Event as SubspaceEvent, GlobalParams, Pallet as PalletSubspace, SubnetParams, TotalStake,
 };
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+use sp_std::{boxed::Box, mem, vec::Vec};
 use substrate_fixed::types::I92F36;
 
 pub type ProposalId = u64;
 
+/// The largest encoded runtime call a proposal can carry. Runtime upgrades fit by going through
+/// `frame_system::authorize_upgrade`, which only takes the code hash.
+pub const MAX_RUNTIME_CALL_SIZE: u32 = 4 * 1024;
+
+/// How many blocks an accepted runtime call waits before being dispatched, so users can react.
+pub const RUNTIME_CALL_ENACTMENT_DELAY: u64 = 7_200;
+
+pub type EncodedRuntimeCall = BoundedVec<u8, ConstU32<MAX_RUNTIME_CALL_SIZE>>;
+
+/// How many proposals can be open for voting at the same time.
+pub const MAX_OPEN_PROPOSALS: u32 = 128;
+
+/// The longest proposal title, in bytes.
+pub const MAX_PROPOSAL_TITLE_LENGTH: u32 = 128;
+
+/// The longest IPFS CID a proposal can point to, in bytes.
+pub const MAX_PROPOSAL_CID_LENGTH: u32 = 128;
+
+/// The longest proposal discussion URL, in bytes.
+pub const MAX_PROPOSAL_DISCUSSION_URL_LENGTH: u32 = 256;
+
+/// Describes a proposal. The full text of the proposal is stored on IPFS.
+#[derive(Clone, Debug, Default, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub struct ProposalMetadata {
+    /// A short UTF-8 title.
+    pub title: BoundedVec<u8, ConstU32<MAX_PROPOSAL_TITLE_LENGTH>>,
+    /// The CID of the proposal text, either a base58 CIDv0 or a base32 CIDv1.
+    pub cid: BoundedVec<u8, ConstU32<MAX_PROPOSAL_CID_LENGTH>>,
+    /// An `https` URL where the proposal is discussed.
+    pub discussion_url: Option<BoundedVec<u8, ConstU32<MAX_PROPOSAL_DISCUSSION_URL_LENGTH>>>,
+}
+
+impl ProposalMetadata {
+    pub fn validate<T: Config>(&self) -> DispatchResult {
+        let title =
+            sp_std::str::from_utf8(&self.title).map_err(|_| Error::<T>::InvalidProposalTitle)?;
+        ensure!(!title.trim().is_empty(), Error::<T>::InvalidProposalTitle);
+
+        ensure!(is_valid_cid(&self.cid), Error::<T>::InvalidProposalCid);
+
+        if let Some(url) = &self.discussion_url {
+            ensure!(
+                url.starts_with(b"https://")
+                    && url.len() > b"https://".len()
+                    && url.iter().all(u8::is_ascii_graphic),
+                Error::<T>::InvalidProposalDiscussionUrl
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the bytes are a CIDv0, `Qm` followed by base58, or a CIDv1 in its default base32
+/// encoding, `b` followed by lowercase base32.
+pub(crate) fn is_valid_cid(cid: &[u8]) -> bool {
+    const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    match cid {
+        [b'Q', b'm', rest @ ..] if cid.len() == 46 => {
+            rest.iter().all(|c| BASE58_ALPHABET.contains(c))
+        }
+        [b'b', rest @ ..] if cid.len() >= 59 => {
+            rest.iter().all(|c| matches!(c, b'a'..=b'z' | b'2'..=b'7'))
+        }
+        _ => false,
+    }
+}
+
 #[derive(DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 pub struct Proposal<T: Config> {
@@ -27,9 +99,12 @@ pub struct Proposal<T: Config> {
     pub expiration_block: u64,
     pub data: ProposalData<T>,
     pub status: ProposalStatus<T>,
-    pub metadata: BoundedVec<u8, ConstU32<256>>,
+    pub metadata: ProposalMetadata,
     pub proposal_cost: u64,
     pub creation_block: u64,
+    /// The first block where the proposal can be voted on. The proposer can amend the proposal
+    /// until then.
+    pub voting_start_block: u64,
 }
 
 impl<T: Config> Proposal<T> {
@@ -42,11 +117,7 @@ impl<T: Config> Proposal<T> {
     /// Returns the subnet ID that this proposal impact.s
     #[must_use]
     pub fn subnet_id(&self) -> Option<u16> {
-        match &self.data {
-            ProposalData::SubnetParams { subnet_id, .. }
-            | ProposalData::SubnetCustom { subnet_id, .. } => Some(*subnet_id),
-            _ => None,
-        }
+        self.data.subnet_id()
     }
 
     /// Marks a proposal as accepted and overrides the storage value.
@@ -60,6 +131,7 @@ impl<T: Config> Proposal<T> {
         };
 
         Proposals::<T>::insert(self.id, &self);
+        Self::clear_voting_power_snapshots(self.id);
         Pallet::<T>::deposit_event(Event::ProposalAccepted(self.id));
 
         self.execute_proposal()?;
@@ -67,6 +139,22 @@ impl<T: Config> Proposal<T> {
         Ok(())
     }
 
+    /// Drops the voting power records, the votes index and the power reclaimed from delegates of
+    /// a proposal that is no longer open. Every voter has a voting power record, and took power
+    /// back from a single delegate at most, so there are no more reclaimed power records than
+    /// voting power ones.
+    fn clear_voting_power_snapshots(proposal_id: ProposalId) {
+        OpenProposals::<T>::mutate(|open| open.retain(|id| *id != proposal_id));
+        ProposalTotalVotingPower::<T>::remove(proposal_id);
+
+        let mut voters: u32 = 0;
+        for (key, _) in VotingPowerSnapshots::<T>::drain_prefix(proposal_id) {
+            VotedProposals::<T>::remove(key, proposal_id);
+            voters = voters.saturating_add(1);
+        }
+        let _ = ReclaimedVotingPower::<T>::clear_prefix(proposal_id, voters, None);
+    }
+
     fn execute_proposal(self) -> DispatchResult {
         PalletSubspace::<T>::add_balance_to_account(
             &self.proposer,
@@ -83,7 +171,7 @@ impl<T: Config> Proposal<T> {
                 PalletSubspace::<T>::deposit_event(SubspaceEvent::GlobalParamsUpdated(params));
             }
             ProposalData::SubnetParams { subnet_id, params } => {
-                let changeset = SubnetChangeset::<T>::update(subnet_id, params)?;
+                let changeset = SubnetChangeset::<T>::update_with_founder(subnet_id, params)?;
                 changeset.apply(subnet_id)?;
                 PalletSubspace::<T>::deposit_event(SubspaceEvent::SubnetParamsUpdated(subnet_id));
             }
@@ -94,6 +182,41 @@ impl<T: Config> Proposal<T> {
                     amount,
                 )?;
             }
+            ProposalData::SubnetPricing(mechanism) => {
+                // Takes effect after a delay, so indexers can follow the switch.
+                T::schedule_subnet_pricing_mechanism(mechanism);
+            }
+            ProposalData::RuntimeCall(call) => {
+                let enactment_block = PalletSubspace::<T>::get_current_block_number()
+                    .saturating_add(RUNTIME_CALL_ENACTMENT_DELAY);
+                ScheduledRuntimeCalls::<T>::insert(self.id, (enactment_block, call));
+                Pallet::<T>::deposit_event(Event::RuntimeCallScheduled(self.id, enactment_block));
+            }
+            ProposalData::GlobalParamsPatch(patch) => {
+                // Merged onto the params at execution time, so unrelated changes made while the
+                // proposal was open are kept.
+                let mut params = patch.merge(PalletSubspace::<T>::global_params());
+                params.governance_config = Pallet::<T>::validate(params.governance_config)?;
+                PalletSubspace::<T>::set_global_params(params.clone())?;
+                PalletSubspace::<T>::deposit_event(SubspaceEvent::GlobalParamsUpdated(params));
+            }
+            ProposalData::SubnetParamsPatch { subnet_id, patch } => {
+                let params = patch.merge(PalletSubspace::<T>::subnet_params(subnet_id));
+                let changeset = SubnetChangeset::<T>::update_with_founder(subnet_id, params)?;
+                changeset.apply(subnet_id)?;
+                PalletSubspace::<T>::deposit_event(SubspaceEvent::SubnetParamsUpdated(subnet_id));
+            }
+            ProposalData::TreasuryStream {
+                recipient,
+                amount,
+                duration,
+                cliff,
+            } => {
+                Pallet::<T>::create_treasury_stream(self.id, recipient, amount, duration, cliff)?;
+            }
+            ProposalData::CancelTreasuryStream { stream_id } => {
+                Pallet::<T>::cancel_treasury_stream(stream_id)?;
+            }
         }
 
         Ok(())
@@ -110,11 +233,58 @@ impl<T: Config> Proposal<T> {
         };
 
         Proposals::<T>::insert(self.id, &self);
+        Self::clear_voting_power_snapshots(self.id);
         Pallet::<T>::deposit_event(Event::ProposalRefused(self.id));
 
         Ok(())
     }
 
+    /// Marks a proposal as cancelled by its proposer and overrides the storage value. The
+    /// proposal cost is refunded, minus the cancellation burn if the proposal was voted on.
+    pub fn cancel(mut self, block: u64) -> DispatchResult {
+        let ProposalStatus::Open {
+            votes_for,
+            votes_against,
+            votes_abstain,
+            ..
+        } = &self.status
+        else {
+            return Err(Error::<T>::ProposalIsFinished.into());
+        };
+
+        let voters: Vec<_> = votes_for
+            .iter()
+            .chain(votes_against.iter())
+            .chain(votes_abstain.iter())
+            .collect();
+        let burn = if voters.is_empty() {
+            Percent::zero()
+        } else {
+            GlobalGovernanceConfig::<T>::get().proposal_cancellation_burn
+        };
+
+        // The votes no longer count, so they don't keep the stake locked
+        for voter in voters {
+            VoteLocks::<T>::mutate(voter, |locks| {
+                locks.retain(|lock| lock.proposal_id != self.id)
+            });
+        }
+
+        let refund = self.proposal_cost.saturating_sub(burn.mul_floor(self.proposal_cost));
+        let Some(refund) = PalletSubspace::<T>::u64_to_balance(refund) else {
+            return Err(Error::<T>::InvalidCurrencyConversionValue.into());
+        };
+        PalletSubspace::<T>::add_balance_to_account(&self.proposer, refund);
+
+        self.status = ProposalStatus::Cancelled { block };
+
+        Proposals::<T>::insert(self.id, &self);
+        Self::clear_voting_power_snapshots(self.id);
+        Pallet::<T>::deposit_event(Event::ProposalCancelled(self.id));
+
+        Ok(())
+    }
+
     /// Marks a proposal as expired and overrides the storage value.
     pub fn expire(mut self, block_number: u64) -> DispatchResult {
         ensure!(self.is_active(), Error::<T>::ProposalIsFinished);
@@ -126,6 +296,7 @@ impl<T: Config> Proposal<T> {
         self.status = ProposalStatus::Expired;
 
         Proposals::<T>::insert(self.id, &self);
+        Self::clear_voting_power_snapshots(self.id);
         Pallet::<T>::deposit_event(Event::ProposalExpired(self.id));
 
         Ok(())
@@ -138,8 +309,10 @@ pub enum ProposalStatus<T: Config> {
     Open {
         votes_for: BoundedBTreeSet<T::AccountId, ConstU32<{ u32::MAX }>>,
         votes_against: BoundedBTreeSet<T::AccountId, ConstU32<{ u32::MAX }>>,
+        votes_abstain: BoundedBTreeSet<T::AccountId, ConstU32<{ u32::MAX }>>,
         stake_for: u64,
         stake_against: u64,
+        stake_abstain: u64,
     },
     Accepted {
         block: u64,
@@ -152,9 +325,12 @@ pub enum ProposalStatus<T: Config> {
         stake_against: u64,
     },
     Expired,
+    Cancelled {
+        block: u64,
+    },
 }
 
-#[derive(DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+#[derive(CloneNoBound, DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
 #[scale_info(skip_type_params(T))]
 pub enum ProposalData<T: Config> {
     GlobalCustom,
@@ -170,17 +346,55 @@ pub enum ProposalData<T: Config> {
         account: T::AccountId,
         amount: u64,
     },
+    SubnetPricing(SubnetPricingMechanism),
+    /// A SCALE encoded `RuntimeCall`, dispatched with root origin once accepted.
+    RuntimeCall(EncodedRuntimeCall),
+    /// Only the fields set in the patch are changed once accepted.
+    GlobalParamsPatch(GlobalParamsPatch<T>),
+    SubnetParamsPatch {
+        subnet_id: SubnetId,
+        patch: SubnetParamsPatch<T>,
+    },
+    /// Pays the amount from the DAO treasury to the recipient over `duration` blocks once
+    /// accepted. Nothing can be claimed during the first `cliff` blocks.
+    TreasuryStream {
+        recipient: T::AccountId,
+        amount: u64,
+        duration: u64,
+        cliff: u64,
+    },
+    /// Stops a treasury stream, returning what hasn't vested yet to the treasury.
+    CancelTreasuryStream {
+        stream_id: ProposalId,
+    },
 }
 
 impl<T: Config> ProposalData<T> {
-    /// The required amount of stake each of the proposal types requires in order to pass.
+    /// Returns the subnet ID that this proposal impacts.
     #[must_use]
-    pub fn required_stake(&self) -> Percent {
+    pub fn subnet_id(&self) -> Option<u16> {
         match self {
-            Self::GlobalCustom | Self::SubnetCustom { .. } | Self::TransferDaoTreasury { .. } => {
-                Percent::from_parts(50)
-            }
-            Self::GlobalParams(_) | Self::SubnetParams { .. } => Percent::from_parts(40),
+            Self::SubnetParams { subnet_id, .. }
+            | Self::SubnetParamsPatch { subnet_id, .. }
+            | Self::SubnetCustom { subnet_id, .. } => Some(*subnet_id),
+            _ => None,
+        }
+    }
+
+    /// The rules the proposal type must meet in order to pass.
+    #[must_use]
+    pub fn rules(&self, config: &GovernanceConfiguration) -> ProposalRules {
+        match self {
+            Self::GlobalCustom | Self::SubnetCustom { .. } => config.custom_proposal_rules,
+            Self::TransferDaoTreasury { .. }
+            | Self::TreasuryStream { .. }
+            | Self::CancelTreasuryStream { .. } => config.treasury_proposal_rules,
+            Self::SubnetPricing(_) => config.subnet_pricing_proposal_rules,
+            Self::RuntimeCall(_) => config.runtime_call_proposal_rules,
+            Self::GlobalParams(_)
+            | Self::SubnetParams { .. }
+            | Self::GlobalParamsPatch(_)
+            | Self::SubnetParamsPatch { .. } => config.params_proposal_rules,
         }
     }
 }
@@ -204,12 +418,16 @@ impl<T: Config> Pallet<T> {
 
     pub fn add_proposal(
         key: T::AccountId,
-        metadata: BoundedVec<u8, ConstU32<256>>,
+        metadata: ProposalMetadata,
         data: ProposalData<T>,
     ) -> DispatchResult {
+        metadata.validate::<T>()?;
+        let data = Self::check_proposal_data(data)?;
+
         let GovernanceConfiguration {
             proposal_cost,
             proposal_expiration,
+            proposal_amendment_period,
             ..
         } = GlobalGovernanceConfig::<T>::get();
 
@@ -225,7 +443,9 @@ impl<T: Config> Pallet<T> {
 
         let proposal_id = Self::get_next_proposal_id();
         let current_block = PalletSubspace::<T>::get_current_block_number();
-        let expiration_block = current_block.saturating_add(proposal_expiration as u64);
+        // Voting only opens once the amendment period is over
+        let voting_start_block = current_block.saturating_add(proposal_amendment_period);
+        let expiration_block = voting_start_block.saturating_add(proposal_expiration as u64);
 
         // TODO: extract rounding function
         let expiration_block = if expiration_block % 100 == 0 {
@@ -244,146 +464,456 @@ impl<T: Config> Pallet<T> {
             status: ProposalStatus::Open {
                 votes_for: BoundedBTreeSet::new(),
                 votes_against: BoundedBTreeSet::new(),
+                votes_abstain: BoundedBTreeSet::new(),
                 stake_for: 0,
                 stake_against: 0,
+                stake_abstain: 0,
             },
             proposal_cost,
             creation_block: current_block,
+            voting_start_block,
             metadata,
         };
 
         // Burn the proposal cost from the proposer's balance
         PalletSubspace::<T>::remove_balance_from_account(&key, removed_balance_as_currency)?;
 
+        OpenProposals::<T>::try_mutate(|open| open.try_push(proposal_id))
+            .map_err(|_| Error::<T>::TooManyOpenProposals)?;
+        ProposalTotalVotingPower::<T>::insert(proposal_id, TotalStake::<T>::get());
         Proposals::<T>::insert(proposal_id, proposal);
 
         Self::deposit_event(Event::<T>::ProposalCreated(proposal_id));
         Ok(())
     }
 
+    /// Checks the proposal data when the proposal is created or amended, returning it with its
+    /// governance configuration validated.
+    fn check_proposal_data(data: ProposalData<T>) -> Result<ProposalData<T>, DispatchError> {
+        let data = match data {
+            ProposalData::GlobalCustom | ProposalData::SubnetCustom { .. } => data,
+            ProposalData::TransferDaoTreasury { account, amount } => {
+                ensure!(
+                    pallet_subspace::Pallet::<T>::has_enough_balance(
+                        &DaoTreasuryAddress::<T>::get(),
+                        amount
+                    ),
+                    Error::<T>::InsufficientDaoTreasuryFunds
+                );
+                ProposalData::TransferDaoTreasury { account, amount }
+            }
+            ProposalData::TreasuryStream {
+                amount,
+                duration,
+                cliff,
+                ..
+            } => {
+                ensure!(
+                    amount > 0 && duration > 0 && cliff <= duration,
+                    Error::<T>::InvalidTreasuryStream
+                );
+                ensure!(
+                    pallet_subspace::Pallet::<T>::has_enough_balance(
+                        &DaoTreasuryAddress::<T>::get(),
+                        amount
+                    ),
+                    Error::<T>::InsufficientDaoTreasuryFunds
+                );
+                data
+            }
+            ProposalData::CancelTreasuryStream { stream_id } => {
+                ensure!(
+                    TreasuryStreams::<T>::contains_key(stream_id),
+                    Error::<T>::TreasuryStreamNotFound
+                );
+                data
+            }
+            ProposalData::GlobalParams(mut params) => {
+                params.governance_config = Self::validate(params.governance_config)?;
+                pallet_subspace::Pallet::check_global_params(&params)?;
+                ProposalData::GlobalParams(params)
+            }
+            ProposalData::SubnetParams {
+                subnet_id,
+                mut params,
+            } => {
+                Self::ensure_vote_mode(subnet_id)?;
+                params.governance_config = Self::validate(params.governance_config)?;
+                SubnetChangeset::<T>::update_with_founder(subnet_id, params.clone())?;
+                ProposalData::SubnetParams { subnet_id, params }
+            }
+            ProposalData::GlobalParamsPatch(ref patch) => {
+                let mut params = patch.clone().merge(PalletSubspace::<T>::global_params());
+                params.governance_config = Self::validate(params.governance_config)?;
+                pallet_subspace::Pallet::check_global_params(&params)?;
+                data
+            }
+            ProposalData::SubnetParamsPatch {
+                subnet_id,
+                ref patch,
+            } => {
+                Self::ensure_vote_mode(subnet_id)?;
+                let mut params = patch.clone().merge(PalletSubspace::<T>::subnet_params(subnet_id));
+                params.governance_config = Self::validate(params.governance_config)?;
+                SubnetChangeset::<T>::update_with_founder(subnet_id, params)?;
+                data
+            }
+            ProposalData::SubnetPricing(ref mechanism) => {
+                ensure!(
+                    mechanism.is_valid(),
+                    Error::<T>::InvalidSubnetPricingMechanism
+                );
+                data
+            }
+            ProposalData::RuntimeCall(ref call) => {
+                let call = <T as Config>::RuntimeCall::decode(&mut &call[..])
+                    .map_err(|_| Error::<T>::InvalidRuntimeCall)?;
+
+                // A call that can't fit in a block would never be dispatched
+                let max_weight = <T as frame_system::Config>::BlockWeights::get().max_block;
+                ensure!(
+                    call.get_dispatch_info().weight.all_lte(max_weight),
+                    Error::<T>::RuntimeCallTooHeavy
+                );
+                data
+            }
+        };
+
+        Ok(data)
+    }
+
+    fn ensure_vote_mode(subnet_id: SubnetId) -> DispatchResult {
+        ensure!(
+            matches!(
+                SubnetGovernanceConfig::<T>::get(subnet_id).vote_mode,
+                VoteMode::Vote
+            ),
+            Error::<T>::NotVoteMode
+        );
+        Ok(())
+    }
+
     pub fn do_add_global_custom_proposal(
         origin: T::RuntimeOrigin,
-        data: Vec<u8>,
+        metadata: ProposalMetadata,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
-        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
-        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
-        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
 
         let proposal_data = ProposalData::GlobalCustom;
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::add_proposal(key, metadata, proposal_data)
     }
 
     pub fn do_add_subnet_custom_proposal(
         origin: T::RuntimeOrigin,
         subnet_id: u16,
-        data: Vec<u8>,
+        metadata: ProposalMetadata,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
-        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
-        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
-        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
 
         let proposal_data = ProposalData::SubnetCustom { subnet_id };
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::add_proposal(key, metadata, proposal_data)
     }
 
     pub fn do_add_transfer_dao_treasury_proposal(
         origin: T::RuntimeOrigin,
-        data: Vec<u8>,
+        metadata: ProposalMetadata,
         value: u64,
         dest: T::AccountId,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
-        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
-        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
-        ensure!(
-            pallet_subspace::Pallet::<T>::has_enough_balance(
-                &DaoTreasuryAddress::<T>::get(),
-                value
-            ),
-            Error::<T>::InsufficientDaoTreasuryFunds
-        );
-        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
 
         let proposal_data = ProposalData::TransferDaoTreasury {
             amount: value,
             account: dest,
         };
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::add_proposal(key, metadata, proposal_data)
     }
 
     pub fn do_add_global_params_proposal(
         origin: T::RuntimeOrigin,
-        data: Vec<u8>,
-        mut params: GlobalParams<T>,
+        metadata: ProposalMetadata,
+        params: GlobalParams<T>,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
 
-        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
-        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
-
-        params.governance_config = Self::validate(params.governance_config)?;
-        pallet_subspace::Pallet::check_global_params(&params)?;
-
         let proposal_data = ProposalData::GlobalParams(params);
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::add_proposal(key, metadata, proposal_data)
     }
 
     pub fn do_add_subnet_params_proposal(
         origin: T::RuntimeOrigin,
         subnet_id: u16,
-        data: Vec<u8>,
-        mut params: SubnetParams<T>,
+        metadata: ProposalMetadata,
+        params: SubnetParams<T>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let proposal_data = ProposalData::SubnetParams { subnet_id, params };
+        Self::add_proposal(key, metadata, proposal_data)
+    }
+
+    pub fn do_add_global_params_patch_proposal(
+        origin: T::RuntimeOrigin,
+        metadata: ProposalMetadata,
+        patch: GlobalParamsPatch<T>,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
 
+        let proposal_data = ProposalData::GlobalParamsPatch(patch);
+        Self::add_proposal(key, metadata, proposal_data)
+    }
+
+    pub fn do_add_subnet_params_patch_proposal(
+        origin: T::RuntimeOrigin,
+        subnet_id: u16,
+        metadata: ProposalMetadata,
+        patch: SubnetParamsPatch<T>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let proposal_data = ProposalData::SubnetParamsPatch { subnet_id, patch };
+        Self::add_proposal(key, metadata, proposal_data)
+    }
+
+    pub fn do_add_subnet_pricing_proposal(
+        origin: T::RuntimeOrigin,
+        metadata: ProposalMetadata,
+        mechanism: SubnetPricingMechanism,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let proposal_data = ProposalData::SubnetPricing(mechanism);
+        Self::add_proposal(key, metadata, proposal_data)
+    }
+
+    pub fn do_add_runtime_call_proposal(
+        origin: T::RuntimeOrigin,
+        metadata: ProposalMetadata,
+        call: Box<<T as Config>::RuntimeCall>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let call = EncodedRuntimeCall::try_from(call.encode())
+            .map_err(|_| Error::<T>::RuntimeCallTooLarge)?;
+
+        let proposal_data = ProposalData::RuntimeCall(call);
+        Self::add_proposal(key, metadata, proposal_data)
+    }
+
+    pub fn do_add_treasury_stream_proposal(
+        origin: T::RuntimeOrigin,
+        metadata: ProposalMetadata,
+        recipient: T::AccountId,
+        amount: u64,
+        duration: u64,
+        cliff: u64,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let proposal_data = ProposalData::TreasuryStream {
+            recipient,
+            amount,
+            duration,
+            cliff,
+        };
+        Self::add_proposal(key, metadata, proposal_data)
+    }
+
+    pub fn do_add_cancel_treasury_stream_proposal(
+        origin: T::RuntimeOrigin,
+        metadata: ProposalMetadata,
+        stream_id: ProposalId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let proposal_data = ProposalData::CancelTreasuryStream { stream_id };
+        Self::add_proposal(key, metadata, proposal_data)
+    }
+
+    /// Amends the metadata of an open proposal of the caller, and its data if given, before
+    /// voting opens. The new data must be of the same kind and target the same subnet.
+    pub fn do_amend_proposal(
+        origin: T::RuntimeOrigin,
+        proposal_id: ProposalId,
+        metadata: ProposalMetadata,
+        data: Option<ProposalData<T>>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let Some(mut proposal) = Proposals::<T>::get(proposal_id) else {
+            return Err(Error::<T>::ProposalNotFound.into());
+        };
+        ensure!(proposal.proposer == key, Error::<T>::NotProposer);
+        ensure!(proposal.is_active(), Error::<T>::ProposalIsFinished);
         ensure!(
-            matches!(
-                SubnetGovernanceConfig::<T>::get(subnet_id).vote_mode,
-                VoteMode::Vote
-            ),
-            Error::<T>::NotVoteMode
+            PalletSubspace::<T>::get_current_block_number() < proposal.voting_start_block,
+            Error::<T>::AmendmentPeriodOver
         );
 
-        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
-        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        metadata.validate::<T>()?;
+        if let Some(data) = data {
+            ensure!(
+                mem::discriminant(&data) == mem::discriminant(&proposal.data)
+                    && data.subnet_id() == proposal.subnet_id(),
+                Error::<T>::InvalidProposalAmendment
+            );
+            proposal.data = Self::check_proposal_data(data)?;
+        }
+        proposal.metadata = metadata;
 
-        params.governance_config = Self::validate(params.governance_config)?;
-        SubnetChangeset::<T>::update(subnet_id, params.clone())?;
+        Proposals::<T>::insert(proposal_id, proposal);
 
-        let proposal_data = ProposalData::SubnetParams { subnet_id, params };
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::deposit_event(Event::<T>::ProposalAmended(proposal_id));
+        Ok(())
     }
-}
 
-pub fn tick_proposals<T: Config>(block_number: u64) {
-    let not_delegating = NotDelegatingVotingPower::<T>::get().into_inner();
+    /// Cancels an open proposal of the caller.
+    pub fn do_cancel_proposal(origin: T::RuntimeOrigin, proposal_id: ProposalId) -> DispatchResult {
+        let key = ensure_signed(origin)?;
 
-    let proposals = Proposals::<T>::iter().filter(|(_, p)| p.is_active());
+        let Some(proposal) = Proposals::<T>::get(proposal_id) else {
+            return Err(Error::<T>::ProposalNotFound.into());
+        };
+        ensure!(proposal.proposer == key, Error::<T>::NotProposer);
+
+        proposal.cancel(PalletSubspace::<T>::get_current_block_number())
+    }
+}
 
+/// Ticks the active proposals every 100 blocks. Returns the weight consumed.
+pub fn tick_proposals<T: Config>(block_number: u64) -> Weight {
     if block_number % 100 != 0 {
-        return;
+        return Weight::zero();
+    }
+
+    let mut proposals: u32 = 0;
+    let mut votes: u32 = 0;
+    for (id, proposal) in Proposals::<T>::iter() {
+        proposals = proposals.saturating_add(1);
+        if !proposal.is_active() {
+            continue;
+        }
+
+        match with_storage_layer(|| tick_proposal(block_number, proposal)) {
+            Ok(weighed) => votes = votes.saturating_add(weighed),
+            Err(err) => log::error!("failed to tick proposal {id}: {err:?}, skipping..."),
+        }
     }
 
-    for (id, proposal) in proposals {
-        let res = with_storage_layer(|| tick_proposal(&not_delegating, block_number, proposal));
-        if let Err(err) = res {
-            log::error!("failed to tick proposal {id}: {err:?}, skipping...");
+    <T as Config>::WeightInfo::tick_proposals(proposals, votes)
+}
+
+/// Dispatches the accepted runtime calls whose enactment delay is over, oldest first, as long as
+/// they fit in what is left of the block once `consumed` is accounted for. Calls that don't fit
+/// stay scheduled for the next blocks. Returns the weight consumed, including the weight of the
+/// dispatched calls.
+pub fn tick_scheduled_runtime_calls<T: Config>(block_number: u64, consumed: Weight) -> Weight {
+    let db_weight = T::DbWeight::get();
+
+    let mut weight = Weight::zero();
+    let mut scheduled: u64 = 0;
+    let mut due = Vec::new();
+    for (proposal_id, (enactment_block, call)) in ScheduledRuntimeCalls::<T>::iter() {
+        scheduled = scheduled.saturating_add(1);
+        if enactment_block <= block_number {
+            due.push((enactment_block, proposal_id, call));
         }
     }
+    weight = weight.saturating_add(db_weight.reads(scheduled));
+    due.sort_unstable_by_key(|(enactment_block, proposal_id, _)| (*enactment_block, *proposal_id));
+
+    let remaining = <T as frame_system::Config>::BlockWeights::get()
+        .max_block
+        .saturating_sub(frame_system::Pallet::<T>::block_weight().total())
+        .saturating_sub(consumed);
+
+    for (_, proposal_id, call) in due {
+        let call = <T as Config>::RuntimeCall::decode(&mut &call[..]);
+        let info = call.as_ref().map(GetDispatchInfo::get_dispatch_info).ok();
+
+        let needed = weight
+            .saturating_add(db_weight.writes(1))
+            .saturating_add(info.map(|info| info.weight).unwrap_or_default());
+        if !needed.all_lte(remaining) {
+            log::info!("runtime call of proposal {proposal_id} deferred, it doesn't fit the block");
+            break;
+        }
+
+        ScheduledRuntimeCalls::<T>::remove(proposal_id);
+        weight = weight.saturating_add(db_weight.writes(1));
+
+        let result: DispatchResult = match (call, info) {
+            (Ok(call), Some(info)) => {
+                let post_info = call.dispatch(runtime_call_origin::<T>(proposal_id, &call));
+                let actual_weight = match post_info {
+                    Ok(ref post_info) => post_info.calc_actual_weight(&info),
+                    Err(ref err) => err.post_info.calc_actual_weight(&info),
+                };
+                weight = weight.saturating_add(actual_weight);
+
+                post_info.map(|_| ()).map_err(|err| err.error)
+            }
+            _ => Err(Error::<T>::InvalidRuntimeCall.into()),
+        };
+        if let Err(err) = result {
+            log::error!("runtime call of proposal {proposal_id} failed: {err:?}");
+        }
+
+        Pallet::<T>::deposit_event(Event::RuntimeCallDispatched(proposal_id, result));
+    }
+
+    weight
+}
+
+/// Returns the origin the runtime call of the given proposal is dispatched with. Runtime upgrade
+/// authorizations are dispatched as root, as `frame_system` accepts no other origin for them;
+/// every other call gets the governance [`Origin`].
+fn runtime_call_origin<T: Config>(
+    proposal_id: ProposalId,
+    call: &<T as Config>::RuntimeCall,
+) -> <T as frame_system::Config>::RuntimeOrigin {
+    match call.is_sub_type() {
+        Some(
+            frame_system::Call::authorize_upgrade { .. }
+            | frame_system::Call::authorize_upgrade_without_checks { .. },
+        ) => RawOrigin::Root.into(),
+        _ => T::ProposalOrigin::from(Origin::Proposal(proposal_id)).into(),
+    }
+}
+
+/// Ensures an origin is the runtime call of an accepted proposal, yielding the proposal ID.
+/// Runtime pallets use it to accept governance calls where they would otherwise require root.
+pub struct EnsureProposal;
+
+impl<O> EnsureOrigin<O> for EnsureProposal
+where
+    O: Into<Result<Origin, O>> + From<Origin>,
+{
+    type Success = ProposalId;
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().map(|Origin::Proposal(proposal_id)| proposal_id)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(Origin::Proposal(0)))
+    }
+}
+
+/// Returns the stake that can vote on the proposals of the given subnet, or the global ones.
+pub fn get_voting_stake<T: Config>(subnet_id: Option<u16>) -> u64 {
+    match subnet_id {
+        Some(specific_subnet_id) => PalletSubspace::<T>::get_total_subnet_stake(specific_subnet_id),
+        None => TotalStake::<T>::get(),
+    }
 }
 
 pub fn get_minimal_stake_to_execute_with_percentage<T: Config>(
     threshold: Percent,
     subnet_id: Option<u16>,
 ) -> u64 {
-    let stake = match subnet_id {
-        Some(specific_subnet_id) => PalletSubspace::<T>::get_total_subnet_stake(specific_subnet_id),
-        None => TotalStake::<T>::get(),
-    };
-
-    stake
+    get_voting_stake::<T>(subnet_id)
         .saturated_into::<u128>()
         .checked_mul(threshold.deconstruct() as u128)
         .unwrap_or_default()
@@ -391,61 +921,148 @@ pub fn get_minimal_stake_to_execute_with_percentage<T: Config>(
         .unwrap_or_default() as u64
 }
 
-fn tick_proposal<T: Config>(
-    not_delegating: &BTreeSet<T::AccountId>,
-    block_number: u64,
-    mut proposal: Proposal<T>,
-) -> DispatchResult {
-    let subnet_id = proposal.subnet_id();
-
-    let ProposalStatus::Open {
-        votes_for,
-        votes_against,
-        ..
-    } = &proposal.status
-    else {
-        return Err(Error::<T>::ProposalIsFinished.into());
-    };
+/// Whether the share of the votes in favor reaches the approval threshold. Abstentions are not
+/// part of the share.
+fn reaches_approval(stake_for: u64, stake_against: u64, threshold: Percent) -> bool {
+    let decided = (stake_for as u128).saturating_add(stake_against as u128);
+    stake_for > 0
+        && (stake_for as u128).saturating_mul(100)
+            >= decided.saturating_mul(threshold.deconstruct() as u128)
+}
 
-    let votes_for: Vec<(T::AccountId, u64)> = votes_for
-        .iter()
-        .cloned()
-        .map(|id| {
-            let stake = calc_stake::<T>(not_delegating, &id);
-            (id, stake)
-        })
-        .collect();
-    let votes_against: Vec<(T::AccountId, u64)> = votes_against
-        .iter()
-        .cloned()
-        .map(|id| {
-            let stake = calc_stake::<T>(not_delegating, &id);
-            (id, stake)
-        })
-        .collect();
+pub(crate) enum ProposalOutcome {
+    Accept,
+    Refuse,
+    Expire,
+}
 
-    let stake_for_sum: u64 = votes_for.iter().map(|(_, stake)| stake).sum();
-    let stake_against_sum: u64 = votes_against.iter().map(|(_, stake)| stake).sum();
+/// The tallies of an open proposal and the rules deciding it.
+pub(crate) struct ProposalTally {
+    subnet_id: Option<SubnetId>,
+    stake_for: u64,
+    stake_against: u64,
+    stake_abstain: u64,
+    rules: ProposalRules,
+    early_finalization: bool,
+}
 
-    if block_number < proposal.expiration_block {
-        if let ProposalStatus::Open {
+impl ProposalTally {
+    /// Reads the tallies of the proposal, if it is open, and the rules of its subnet or of the
+    /// global proposals.
+    pub(crate) fn of<T: Config>(proposal: &Proposal<T>) -> Option<Self> {
+        let ProposalStatus::Open {
             stake_for,
             stake_against,
+            stake_abstain,
             ..
-        } = &mut proposal.status
-        {
-            *stake_for = stake_for_sum;
-            *stake_against = stake_against_sum;
+        } = &proposal.status
+        else {
+            return None;
+        };
+
+        let subnet_id = proposal.subnet_id();
+        let governance_config = match subnet_id {
+            Some(subnet_id) => SubnetGovernanceConfig::<T>::get(subnet_id),
+            None => GlobalGovernanceConfig::<T>::get(),
+        };
+
+        Some(Self {
+            subnet_id,
+            stake_for: *stake_for,
+            stake_against: *stake_against,
+            stake_abstain: *stake_abstain,
+            rules: proposal.data.rules(&governance_config),
+            early_finalization: governance_config.early_finalization,
+        })
+    }
+
+    fn quorum_reached<T: Config>(&self) -> bool {
+        let total_stake = self
+            .stake_for
+            .saturating_add(self.stake_against)
+            .saturating_add(self.stake_abstain);
+        total_stake
+            >= get_minimal_stake_to_execute_with_percentage::<T>(self.rules.quorum, self.subnet_id)
+    }
+
+    /// The outcome of the proposal once it expired.
+    fn outcome_at_expiration<T: Config>(&self) -> ProposalOutcome {
+        if !self.quorum_reached::<T>() {
+            ProposalOutcome::Expire
+        } else if reaches_approval(
+            self.stake_for,
+            self.stake_against,
+            self.rules.approval_threshold,
+        ) {
+            ProposalOutcome::Accept
+        } else {
+            ProposalOutcome::Refuse
         }
-        Proposals::<T>::set(proposal.id, Some(proposal));
-        return Ok(());
     }
 
-    let total_stake = stake_for_sum.saturating_add(stake_against_sum);
-    let minimal_stake_to_execute = get_minimal_stake_to_execute_with_percentage::<T>(
-        proposal.data.required_stake(),
-        subnet_id,
-    );
+    /// The outcome the votes settled the proposal on, if early finalization is enabled and one
+    /// side holds the approval threshold against the power of every key that held stake when the
+    /// proposal was created, voting at the highest conviction. The votes of a settled proposal
+    /// can't be removed, so no vote left can turn it. Proposals created before the total voting
+    /// power was recorded are never settled.
+    pub(crate) fn settled_outcome<T: Config>(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Option<ProposalOutcome> {
+        if !self.early_finalization {
+            return None;
+        }
+
+        let max_stake =
+            ProposalTotalVotingPower::<T>::get(proposal_id)?.saturating_mul(MAX_CONVICTION as u64);
+        let threshold = self.rules.approval_threshold;
+        if reaches_approval(
+            self.stake_for,
+            max_stake.saturating_sub(self.stake_for),
+            threshold,
+        ) {
+            Some(ProposalOutcome::Accept)
+        } else if !reaches_approval(
+            max_stake.saturating_sub(self.stake_against),
+            self.stake_against,
+            threshold,
+        ) {
+            Some(ProposalOutcome::Refuse)
+        } else {
+            None
+        }
+    }
+}
+
+/// Decides the outcome of the proposal once it is settled. Returns the number of votes weighed to
+/// record the voters' rewards, zero while the proposal stays open.
+fn tick_proposal<T: Config>(
+    block_number: u64,
+    proposal: Proposal<T>,
+) -> Result<u32, DispatchError> {
+    // Nothing to decide while the proposal can still be amended
+    if block_number < proposal.voting_start_block {
+        return Ok(0);
+    }
+
+    let tally = ProposalTally::of(&proposal).ok_or(Error::<T>::ProposalIsFinished)?;
+    let outcome = if block_number >= proposal.expiration_block {
+        tally.outcome_at_expiration::<T>()
+    } else if let Some(outcome) =
+        tally.settled_outcome::<T>(proposal.id).filter(|_| tally.quorum_reached::<T>())
+    {
+        // Settled proposals are finalized early once they reach the quorum
+        outcome
+    } else {
+        return Ok(0);
+    };
+    let (stake_for_sum, stake_against_sum) = (tally.stake_for, tally.stake_against);
+
+    let [votes_for, votes_against, votes_abstain] = Pallet::<T>::get_vote_weights(&proposal);
+    let votes = votes_for
+        .len()
+        .saturating_add(votes_against.len())
+        .saturating_add(votes_abstain.len()) as u32;
 
     let mut reward_votes_for = BoundedBTreeMap::new();
     for (key, value) in votes_for {
@@ -470,65 +1087,54 @@ fn tick_proposal<T: Config>(
         },
     );
 
-    if total_stake >= minimal_stake_to_execute {
-        if stake_against_sum > stake_for_sum {
-            proposal.refuse(block_number, stake_for_sum, stake_against_sum)
-        } else {
-            proposal.accept(block_number, stake_for_sum, stake_against_sum)
-        }
-    } else {
-        proposal.expire(block_number)
-    }
+    match outcome {
+        ProposalOutcome::Accept => proposal.accept(block_number, stake_for_sum, stake_against_sum),
+        ProposalOutcome::Refuse => proposal.refuse(block_number, stake_for_sum, stake_against_sum),
+        ProposalOutcome::Expire => proposal.expire(block_number),
+    }?;
+
+    Ok(votes)
 }
 
-pub fn tick_proposal_rewards<T: Config>(block_number: u64) {
+/// Rewards the voters of the subnet and global proposals whose reward interval was reached.
+/// Returns the weight consumed.
+pub fn tick_proposal_rewards<T: Config>(block_number: u64) -> Weight {
     let mut to_tick: Vec<_> = pallet_subspace::N::<T>::iter_keys()
         .map(|subnet_id| (Some(subnet_id), SubnetGovernanceConfig::<T>::get(subnet_id)))
         .collect();
     to_tick.push((None, GlobalGovernanceConfig::<T>::get()));
 
-    to_tick.into_iter().for_each(|(subnet_id, governance_config)| {
-        execute_proposal_rewards::<T>(block_number, subnet_id, governance_config);
+    let subnets = to_tick.len() as u32;
+    let proposals = to_tick.into_iter().fold(0u32, |proposals, (subnet_id, governance_config)| {
+        let read = execute_proposal_rewards::<T>(block_number, subnet_id, governance_config);
+        proposals.saturating_add(read)
     });
-}
-
-fn calc_stake<T: Config>(not_delegating: &BTreeSet<T::AccountId>, voter: &T::AccountId) -> u64 {
-    let own_stake = if !not_delegating.contains(voter) {
-        0
-    } else {
-        pallet_subspace::Pallet::<T>::get_delegated_stake(voter)
-    };
 
-    let calculate_delegated = || -> u64 {
-        PalletSubspace::<T>::get_stake_from_vector(voter)
-            .into_iter()
-            .filter(|(staker, _)| !not_delegating.contains(staker))
-            .map(|(_, stake)| stake)
-            .sum()
-    };
-
-    let delegated_stake = calculate_delegated();
-
-    own_stake.saturating_add(delegated_stake)
+    <T as Config>::WeightInfo::tick_proposal_rewards(subnets, proposals)
 }
 
+/// Rewards the voters of the proposals of the given subnet, or the global ones, when the reward
+/// interval is reached. Returns the amount of unrewarded proposals read.
 pub fn execute_proposal_rewards<T: Config>(
     block_number: u64,
     subnet_id: Option<u16>,
     governance_config: GovernanceConfiguration,
-) {
+) -> u32 {
     let reached_interval = block_number
         .checked_rem(governance_config.proposal_reward_interval)
         .is_some_and(|r| r == 0);
     if !reached_interval {
-        return;
+        return 0;
     }
 
+    let mut proposals_read: u32 = 0;
+
     let mut n: u16 = 0;
     let mut account_stakes: BoundedBTreeMap<T::AccountId, u64, ConstU32<{ u32::MAX }>> =
         BoundedBTreeMap::new();
     let mut total_allocation: I92F36 = I92F36::from_num(0);
     for (proposal_id, unrewarded_proposal) in UnrewardedProposals::<T>::iter() {
+        proposals_read = proposals_read.saturating_add(1);
         if subnet_id != unrewarded_proposal.subnet_id {
             continue;
         }
@@ -565,6 +1171,8 @@ pub fn execute_proposal_rewards<T: Config>(
     }
 
     distribute_proposal_rewards::<T>(account_stakes, total_allocation);
+
+    proposals_read
 }
 
 pub fn get_reward_allocation<T: crate::Config>(
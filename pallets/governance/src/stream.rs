@@ -0,0 +1,146 @@
+use crate::*;
+use frame_support::{
+    pallet_prelude::DispatchResult,
+    sp_runtime::traits::Zero,
+    traits::{BalanceStatus, ReservableCurrency},
+    DebugNoBound,
+};
+use frame_system::ensure_signed;
+use pallet_subspace::Pallet as PalletSubspace;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+type SubspaceCurrency<T> = <T as pallet_subspace::Config>::Currency;
+
+/// Treasury funds paid out to a recipient over time, created by an accepted treasury stream
+/// proposal. The streamed amount stays reserved on the treasury until it is claimed.
+#[derive(Clone, DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+#[scale_info(skip_type_params(T))]
+pub struct TreasuryStream<T: Config> {
+    pub recipient: T::AccountId,
+    pub amount: u64,
+    /// How much of the amount the recipient has claimed so far.
+    pub claimed: u64,
+    pub start_block: u64,
+    /// How many blocks the amount vests over, linearly.
+    pub duration: u64,
+    /// How many blocks after the start nothing can be claimed. What vested meanwhile becomes
+    /// claimable at once afterwards.
+    pub cliff: u64,
+}
+
+impl<T: Config> TreasuryStream<T> {
+    /// Returns how much of the amount has vested by the given block.
+    #[must_use]
+    pub fn vested(&self, block: u64) -> u64 {
+        let elapsed = block.saturating_sub(self.start_block);
+        if elapsed < self.cliff {
+            0
+        } else if elapsed >= self.duration {
+            self.amount
+        } else {
+            (self.amount as u128)
+                .saturating_mul(elapsed as u128)
+                .checked_div(self.duration as u128)
+                .unwrap_or_default() as u64
+        }
+    }
+
+    /// Returns how much the recipient can claim at the given block.
+    #[must_use]
+    pub fn claimable(&self, block: u64) -> u64 {
+        self.vested(block).saturating_sub(self.claimed)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Starts the stream of an accepted proposal, reserving its amount on the treasury.
+    pub(crate) fn create_treasury_stream(
+        stream_id: ProposalId,
+        recipient: T::AccountId,
+        amount: u64,
+        duration: u64,
+        cliff: u64,
+    ) -> DispatchResult {
+        let Some(balance) = PalletSubspace::<T>::u64_to_balance(amount) else {
+            return Err(Error::<T>::InvalidCurrencyConversionValue.into());
+        };
+        SubspaceCurrency::<T>::reserve(&DaoTreasuryAddress::<T>::get(), balance)
+            .map_err(|_| Error::<T>::InsufficientDaoTreasuryFunds)?;
+
+        let stream = TreasuryStream::<T> {
+            recipient: recipient.clone(),
+            amount,
+            claimed: 0,
+            start_block: PalletSubspace::<T>::get_current_block_number(),
+            duration,
+            cliff,
+        };
+        TreasuryStreams::<T>::insert(stream_id, stream);
+
+        Self::deposit_event(Event::<T>::TreasuryStreamCreated(
+            stream_id, recipient, amount,
+        ));
+        Ok(())
+    }
+
+    /// Stops a stream. What already vested is paid to the recipient, the rest is returned to the
+    /// treasury. A stream claimed in full is already gone, and there is nothing left to stop.
+    pub(crate) fn cancel_treasury_stream(stream_id: ProposalId) -> DispatchResult {
+        let Some(stream) = TreasuryStreams::<T>::take(stream_id) else {
+            return Ok(());
+        };
+
+        let block = PalletSubspace::<T>::get_current_block_number();
+        Self::pay_treasury_stream(&stream.recipient, stream.claimable(block))?;
+
+        let remainder = stream.amount.saturating_sub(stream.vested(block));
+        let Some(balance) = PalletSubspace::<T>::u64_to_balance(remainder) else {
+            return Err(Error::<T>::InvalidCurrencyConversionValue.into());
+        };
+        SubspaceCurrency::<T>::unreserve(&DaoTreasuryAddress::<T>::get(), balance);
+
+        Self::deposit_event(Event::<T>::TreasuryStreamCancelled(stream_id, remainder));
+        Ok(())
+    }
+
+    /// Pays the caller what vested on its stream since its last claim.
+    pub fn do_claim_stream(origin: T::RuntimeOrigin, stream_id: ProposalId) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let Some(mut stream) = TreasuryStreams::<T>::get(stream_id) else {
+            return Err(Error::<T>::TreasuryStreamNotFound.into());
+        };
+        ensure!(stream.recipient == key, Error::<T>::NotStreamRecipient);
+
+        let amount = stream.claimable(PalletSubspace::<T>::get_current_block_number());
+        ensure!(amount > 0, Error::<T>::NothingToClaim);
+        Self::pay_treasury_stream(&key, amount)?;
+
+        stream.claimed = stream.claimed.saturating_add(amount);
+        if stream.claimed >= stream.amount {
+            TreasuryStreams::<T>::remove(stream_id);
+        } else {
+            TreasuryStreams::<T>::insert(stream_id, stream);
+        }
+
+        Self::deposit_event(Event::<T>::TreasuryStreamClaimed(stream_id, amount));
+        Ok(())
+    }
+
+    /// Moves the amount from the treasury reserve to the recipient. Fails if the reserve no
+    /// longer holds all of it, e.g. after a slash, so the caller doesn't record it as paid.
+    fn pay_treasury_stream(recipient: &T::AccountId, amount: u64) -> DispatchResult {
+        let Some(balance) = PalletSubspace::<T>::u64_to_balance(amount) else {
+            return Err(Error::<T>::InvalidCurrencyConversionValue.into());
+        };
+        let unpaid = SubspaceCurrency::<T>::repatriate_reserved(
+            &DaoTreasuryAddress::<T>::get(),
+            recipient,
+            balance,
+            BalanceStatus::Free,
+        )?;
+        ensure!(unpaid.is_zero(), Error::<T>::InsufficientDaoTreasuryFunds);
+        Ok(())
+    }
+}
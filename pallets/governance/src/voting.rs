@@ -1,13 +1,140 @@
-use crate::{proposal::ProposalStatus, *};
-use frame_support::pallet_prelude::DispatchResult;
+use crate::{
+    proposal::{ProposalStatus, ProposalTally},
+    *,
+};
+use frame_support::{
+    pallet_prelude::{ConstU32, DispatchResult},
+    traits::Get,
+    weights::Weight,
+    BoundedVec,
+};
 use frame_system::ensure_signed;
+use pallet_subspace::Pallet as PalletSubspace;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// The highest multiple of the proposal duration a voter can lock their stake for.
+pub const MAX_CONVICTION: u8 = 6;
+
+/// How many conviction locks a single key can hold at the same time.
+pub const MAX_VOTE_LOCKS: u32 = 64;
+
+/// On how many scopes a single key can delegate its voting power at the same time.
+pub const MAX_DELEGATION_SCOPES: u32 = 8;
+
+/// A vote cast on a proposal. Abstentions count towards the quorum, but not towards the
+/// approval threshold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub enum Vote {
+    For,
+    Against,
+    Abstain,
+}
+
+impl From<bool> for Vote {
+    fn from(agree: bool) -> Self {
+        if agree {
+            Self::For
+        } else {
+            Self::Against
+        }
+    }
+}
+
+/// The proposals a voting power delegation applies to. Global and subnet delegations take
+/// precedence over the ones made for all proposals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub enum DelegationScope {
+    All,
+    Global,
+    Subnet(SubnetId),
+}
+
+impl DelegationScope {
+    /// The scope of the proposals of the given subnet, or of the global ones.
+    #[must_use]
+    pub fn of(subnet_id: Option<SubnetId>) -> Self {
+        match subnet_id {
+            Some(subnet_id) => Self::Subnet(subnet_id),
+            None => Self::Global,
+        }
+    }
+}
+
+/// The voting power delegated to a key on a scope. On a global or subnet scope, `overridden` is
+/// the part of the power delegated to the key on all proposals whose delegators delegated that
+/// scope too, and that doesn't flow to the key on it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub struct DelegatedPower {
+    pub delegated: u64,
+    pub overridden: u64,
+}
+
+/// The weight of each vote of an open proposal, for, against and abstaining.
+pub type VoteWeights<AccountId> = [Vec<(AccountId, u64)>; 3];
+
+/// Past values of a key, each paired with the id of the first proposal it no longer applies to.
+/// A value applies to the proposals created after the previous one stopped applying.
+pub type Checkpoints<V> = BoundedVec<(ProposalId, V), ConstU32<MAX_OPEN_PROPOSALS>>;
+
+/// Returns the value that applied when the proposal was created.
+pub(crate) fn checkpoint_value_at<V: Clone>(
+    checkpoints: &Checkpoints<V>,
+    proposal_id: ProposalId,
+    current: impl FnOnce() -> V,
+) -> V {
+    checkpoints
+        .iter()
+        .find(|(next_id, _)| *next_id > proposal_id)
+        .map(|(_, value)| value.clone())
+        .unwrap_or_else(current)
+}
+
+/// Records the current value before it changes, if an open proposal was created since the last
+/// record, and drops the records no open proposal needs anymore. Returns whether the checkpoints
+/// changed.
+pub(crate) fn record_checkpoint<V>(
+    checkpoints: &mut Checkpoints<V>,
+    open_proposals: &[ProposalId],
+    current: impl FnOnce() -> V,
+) -> bool {
+    let len = checkpoints.len();
+    let mut first_id = 0;
+    checkpoints.retain(|(next_id, _)| {
+        let needed = open_proposals.iter().any(|id| (first_id..*next_id).contains(id));
+        first_id = *next_id;
+        needed
+    });
+    let mut changed = checkpoints.len() != len;
+
+    let last_id = checkpoints.last().map(|(next_id, _)| *next_id).unwrap_or_default();
+    if let Some(latest) = open_proposals.last().filter(|latest| **latest >= last_id) {
+        // Proposals are opened in order, and records are only kept for open proposals, so
+        // there is always room for the new one
+        changed |= checkpoints.try_push((latest.saturating_add(1), current())).is_ok();
+    }
+
+    changed
+}
+
+/// A lock placed on the voter's stake by a conviction vote.
+#[derive(Clone, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub struct VoteLock {
+    pub proposal_id: ProposalId,
+    /// Multiplier applied to the locked amount when tallying the vote.
+    pub conviction: u8,
+    pub amount: u64,
+    /// The first block where the stake can be withdrawn again.
+    pub unlock_block: u64,
+}
 
 impl<T: Config> Pallet<T> {
     /// Votes on proposals,
     pub fn do_vote_proposal(
         origin: T::RuntimeOrigin,
         proposal_id: u64,
-        agree: bool,
+        vote: Vote,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
 
@@ -15,9 +142,15 @@ impl<T: Config> Pallet<T> {
             return Err(Error::<T>::ProposalNotFound.into());
         };
 
+        ensure!(
+            PalletSubspace::<T>::get_current_block_number() >= proposal.voting_start_block,
+            Error::<T>::VotingNotOpen
+        );
+
         let ProposalStatus::Open {
             votes_for,
             votes_against,
+            votes_abstain,
             ..
         } = &mut proposal.status
         else {
@@ -25,33 +158,45 @@ impl<T: Config> Pallet<T> {
         };
 
         ensure!(
-            !votes_for.contains(&key) && !votes_against.contains(&key),
+            !votes_for.contains(&key)
+                && !votes_against.contains(&key)
+                && !votes_abstain.contains(&key),
             Error::<T>::AlreadyVoted
         );
 
-        let voter_stake = pallet_subspace::Pallet::<T>::get_delegated_stake(&key);
-
-        ensure!(voter_stake > 0, Error::<T>::InsufficientStake);
+        // The vote weighs the power the voter had when the proposal was created
+        let power = Self::get_proposal_voting_power(proposal_id, &key);
+        VotingPowerSnapshots::<T>::insert(proposal_id, &key, power);
 
-        let stake_from_vector = pallet_subspace::Pallet::<T>::get_stake_from_vector(&key);
-        let has_stake_from =
-            || stake_from_vector.iter().any(|(k, stakes)| k == &key && *stakes > 0);
-        if !NotDelegatingVotingPower::<T>::get().contains(&key) && !has_stake_from() {
-            return Err(Error::<T>::VoterIsDelegatingVotingPower.into());
-        }
+        let votes = match vote {
+            Vote::For => votes_for,
+            Vote::Against => votes_against,
+            Vote::Abstain => votes_abstain,
+        };
+        votes
+            .try_insert(key.clone())
+            .map_err(|_| Error::<T>::InvalidProposalVotingParameters)?;
+        VotedProposals::<T>::insert(&key, proposal_id, ());
 
-        if agree {
-            votes_for
-                .try_insert(key.clone())
-                .map_err(|_| Error::<T>::InvalidProposalVotingParameters)?;
-        } else {
-            votes_against
-                .try_insert(key.clone())
-                .map_err(|_| Error::<T>::InvalidProposalVotingParameters)?;
+        // Voting takes back the power the voter delegated, along with the power delegated to it
+        let weight = Self::get_vote_weight(proposal_id, &key, proposal.subnet_id());
+        ensure!(weight > 0, Error::<T>::InsufficientStake);
+        if let Some(tally) = Self::vote_tally(&mut proposal.status, &key) {
+            *tally = tally.saturating_add(weight);
         }
+        Self::reclaim_delegated_power(&mut proposal, &key, power, true);
 
         Proposals::<T>::insert(proposal_id, proposal);
-        Self::deposit_event(Event::<T>::ProposalVoted(proposal_id, key, agree));
+        match vote {
+            Vote::For | Vote::Against => Self::deposit_event(Event::<T>::ProposalVoted(
+                proposal_id,
+                key,
+                vote == Vote::For,
+            )),
+            Vote::Abstain => {
+                Self::deposit_event(Event::<T>::ProposalVoteAbstained(proposal_id, key))
+            }
+        }
         Ok(())
     }
 
@@ -63,23 +208,500 @@ impl<T: Config> Pallet<T> {
             return Err(Error::<T>::ProposalNotFound.into());
         };
 
+        ensure!(proposal.is_active(), Error::<T>::ProposalClosed);
+
+        // No vote can turn a settled proposal, so none can be taken back either
+        ensure!(
+            ProposalTally::of(&proposal)
+                .and_then(|tally| tally.settled_outcome::<T>(proposal_id))
+                .is_none(),
+            Error::<T>::ProposalSettled
+        );
+
+        // The vote no longer counts, and the voter's delegate gets the power it took back
+        let weight = Self::get_vote_weight(proposal_id, &key, proposal.subnet_id());
+        let tally = Self::vote_tally(&mut proposal.status, &key).ok_or(Error::<T>::NotVoted)?;
+        *tally = tally.saturating_sub(weight);
+        let power = Self::get_proposal_voting_power(proposal_id, &key);
+        Self::reclaim_delegated_power(&mut proposal, &key, power, false);
+
+        if let ProposalStatus::Open {
+            votes_for,
+            votes_against,
+            votes_abstain,
+            ..
+        } = &mut proposal.status
+        {
+            for votes in [votes_for, votes_against, votes_abstain] {
+                votes.remove(&key);
+            }
+        }
+        VotedProposals::<T>::remove(&key, proposal_id);
+
+        // A vote that no longer counts does not keep the stake locked
+        VoteLocks::<T>::mutate(&key, |locks| {
+            locks.retain(|lock| lock.proposal_id != proposal_id)
+        });
+
+        // Update the proposal in storage
+        Proposals::<T>::insert(proposal.id, proposal);
+        Self::deposit_event(Event::<T>::ProposalVoteUnregistered(proposal_id, key));
+        Ok(())
+    }
+
+    /// Votes on a proposal locking the voter's stake, wherever it is staked, for `conviction` times
+    /// the proposal duration, counted from the proposal expiration. The locked amount is counted
+    /// `conviction` times in the tallies.
+    pub fn do_vote_proposal_with_conviction(
+        origin: T::RuntimeOrigin,
+        proposal_id: u64,
+        agree: bool,
+        conviction: u8,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin.clone())?;
+
+        ensure!(
+            (1..=MAX_CONVICTION).contains(&conviction),
+            Error::<T>::InvalidConviction
+        );
+
+        let owned_stake = PalletSubspace::<T>::get_owned_stake(&key);
+        ensure!(owned_stake > 0, Error::<T>::InsufficientStake);
+
+        Self::do_vote_proposal(origin, proposal_id, agree.into())?;
+
+        // Stake added after the proposal was created doesn't back the conviction either
+        let amount = owned_stake.min(Self::get_proposal_voting_power(proposal_id, &key));
+
+        let Some(mut proposal) = Proposals::<T>::get(proposal_id) else {
+            return Err(Error::<T>::ProposalNotFound.into());
+        };
+
+        let duration = proposal.expiration_block.saturating_sub(proposal.voting_start_block);
+        let unlock_block = proposal
+            .expiration_block
+            .saturating_add(duration.saturating_mul(u64::from(conviction)));
+
+        let current_block = PalletSubspace::<T>::get_current_block_number();
+        VoteLocks::<T>::try_mutate(&key, |locks| {
+            locks.retain(|lock| lock.unlock_block > current_block);
+            locks
+                .try_push(VoteLock {
+                    proposal_id,
+                    conviction,
+                    amount,
+                    unlock_block,
+                })
+                .map_err(|_| Error::<T>::TooManyVoteLocks)
+        })?;
+        VoteLockExpirations::<T>::insert(unlock_block, &key, ());
+
+        let bonus = Self::get_conviction_bonus(&key, proposal_id);
+        if let Some(tally) = Self::vote_tally(&mut proposal.status, &key) {
+            *tally = tally.saturating_add(bonus);
+        }
+        Proposals::<T>::insert(proposal_id, proposal);
+
+        Self::deposit_event(Event::<T>::ProposalVoteLocked(
+            proposal_id,
+            key,
+            amount,
+            unlock_block,
+        ));
+        Ok(())
+    }
+
+    /// Returns how much of the key's stake is held by unexpired conviction locks. Locks overlap,
+    /// so the same stake can back votes on several proposals.
+    pub fn get_locked_stake(key: &T::AccountId) -> u64 {
+        let current_block = PalletSubspace::<T>::get_current_block_number();
+        VoteLocks::<T>::get(key)
+            .iter()
+            .filter(|lock| lock.unlock_block > current_block)
+            .map(|lock| lock.amount)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the voting power of the key: the stake it owns, wherever it is staked.
+    pub fn get_voting_power(key: &T::AccountId) -> u64 {
+        PalletSubspace::<T>::get_owned_stake(key)
+    }
+
+    /// Returns the voting power the key had when the proposal was created.
+    pub fn get_proposal_voting_power(proposal_id: ProposalId, key: &T::AccountId) -> u64 {
+        VotingPowerSnapshots::<T>::get(proposal_id, key).unwrap_or_else(|| {
+            checkpoint_value_at(&VotingPowerCheckpoints::<T>::get(key), proposal_id, || {
+                Self::get_voting_power(key)
+            })
+        })
+    }
+
+    /// Records the current voting power of the key for the open proposals created since its last
+    /// record. Must be called before the voting power of the key changes, so votes keep weighing
+    /// the power keys had when the proposal was created.
+    fn snapshot_voting_power(key: &T::AccountId) {
+        let open_proposals = OpenProposals::<T>::get();
+        let mut checkpoints = VotingPowerCheckpoints::<T>::get(key);
+        if !record_checkpoint(&mut checkpoints, &open_proposals, || {
+            Self::get_voting_power(key)
+        }) {
+            return;
+        }
+
+        if checkpoints.is_empty() {
+            VotingPowerCheckpoints::<T>::remove(key);
+        } else {
+            VotingPowerCheckpoints::<T>::insert(key, checkpoints);
+        }
+    }
+
+    /// Must be called right before the voting power of the key increases, or decreases, by the
+    /// amount. Records the current power and moves the change to the keys it delegates to.
+    pub fn handle_voting_power_change(key: &T::AccountId, amount: u64, increase: bool) {
+        Self::snapshot_voting_power(key);
+        Self::update_delegated_power(&OpenProposals::<T>::get(), key, amount, increase);
+    }
+
+    /// Adds the amount to, or removes it from, the power the key delegates to each of its
+    /// delegates.
+    fn update_delegated_power(
+        open_proposals: &[ProposalId],
+        key: &T::AccountId,
+        amount: u64,
+        increase: bool,
+    ) {
+        if amount == 0 {
+            return;
+        }
+
+        let apply = |value: &mut u64| {
+            *value = if increase {
+                value.saturating_add(amount)
+            } else {
+                value.saturating_sub(amount)
+            };
+        };
+
+        let delegations: Vec<_> = VotingPowerDelegations::<T>::iter_prefix(key).collect();
+        let all_delegate = delegations
+            .iter()
+            .find(|(scope, _)| *scope == DelegationScope::All)
+            .map(|(_, delegate)| delegate.clone());
+        for (scope, delegate) in &delegations {
+            Self::mutate_delegated_power(open_proposals, delegate, *scope, |power| {
+                apply(&mut power.delegated)
+            });
+
+            // The delegation takes precedence over the one made on all proposals
+            if let Some(all_delegate) =
+                all_delegate.as_ref().filter(|_| *scope != DelegationScope::All)
+            {
+                Self::mutate_delegated_power(open_proposals, all_delegate, *scope, |power| {
+                    apply(&mut power.overridden)
+                });
+            }
+        }
+    }
+
+    /// Changes the power delegated to the key on the scope, recording its current value for the
+    /// open proposals first.
+    fn mutate_delegated_power(
+        open_proposals: &[ProposalId],
+        key: &T::AccountId,
+        scope: DelegationScope,
+        f: impl FnOnce(&mut DelegatedPower),
+    ) {
+        let mut power = DelegatedVotingPower::<T>::get(key, scope);
+
+        let mut checkpoints = DelegatedVotingPowerCheckpoints::<T>::get(key, scope);
+        if record_checkpoint(&mut checkpoints, open_proposals, || power) {
+            if checkpoints.is_empty() {
+                DelegatedVotingPowerCheckpoints::<T>::remove(key, scope);
+            } else {
+                DelegatedVotingPowerCheckpoints::<T>::insert(key, scope, checkpoints);
+            }
+        }
+
+        f(&mut power);
+        if power == DelegatedPower::default() {
+            DelegatedVotingPower::<T>::remove(key, scope);
+        } else {
+            DelegatedVotingPower::<T>::insert(key, scope, power);
+        }
+    }
+
+    /// Returns the account the key delegated its voting power to on the proposal when it was
+    /// created.
+    pub fn get_proposal_delegate(
+        proposal_id: ProposalId,
+        key: &T::AccountId,
+        subnet_id: Option<SubnetId>,
+    ) -> Option<T::AccountId> {
+        let delegate_on = |scope| {
+            checkpoint_value_at(
+                &VotingPowerDelegationCheckpoints::<T>::get(key, scope),
+                proposal_id,
+                || VotingPowerDelegations::<T>::get(key, scope),
+            )
+        };
+
+        delegate_on(DelegationScope::of(subnet_id)).or_else(|| delegate_on(DelegationScope::All))
+    }
+
+    /// Returns the voting power delegated to the key on the proposal when it was created.
+    pub fn get_proposal_delegated_power(
+        proposal_id: ProposalId,
+        key: &T::AccountId,
+        subnet_id: Option<SubnetId>,
+    ) -> u64 {
+        let power_on = |scope| {
+            checkpoint_value_at(
+                &DelegatedVotingPowerCheckpoints::<T>::get(key, scope),
+                proposal_id,
+                || DelegatedVotingPower::<T>::get(key, scope),
+            )
+        };
+
+        let scoped = power_on(DelegationScope::of(subnet_id));
+        scoped
+            .delegated
+            .saturating_add(power_on(DelegationScope::All).delegated)
+            .saturating_sub(scoped.overridden)
+    }
+
+    /// Returns the weight of the key's vote on the proposal: the power it had when the proposal
+    /// was created, its conviction bonus and the power delegated to it, minus the power its
+    /// delegators took back by voting themselves.
+    pub fn get_vote_weight(
+        proposal_id: ProposalId,
+        key: &T::AccountId,
+        subnet_id: Option<SubnetId>,
+    ) -> u64 {
+        Self::get_proposal_voting_power(proposal_id, key)
+            .saturating_add(Self::get_conviction_bonus(key, proposal_id))
+            .saturating_add(Self::get_proposal_delegated_power(
+                proposal_id,
+                key,
+                subnet_id,
+            ))
+            .saturating_sub(ReclaimedVotingPower::<T>::get(proposal_id, key))
+    }
+
+    /// Returns the weight of each vote of the proposal.
+    pub fn get_vote_weights(proposal: &Proposal<T>) -> VoteWeights<T::AccountId> {
         let ProposalStatus::Open {
             votes_for,
             votes_against,
+            votes_abstain,
+            ..
+        } = &proposal.status
+        else {
+            return Default::default();
+        };
+
+        let subnet_id = proposal.subnet_id();
+        let weigh = |voter: &T::AccountId| {
+            let weight = Self::get_vote_weight(proposal.id, voter, subnet_id);
+            (voter.clone(), weight)
+        };
+
+        [
+            votes_for.iter().map(weigh).collect(),
+            votes_against.iter().map(weigh).collect(),
+            votes_abstain.iter().map(weigh).collect(),
+        ]
+    }
+
+    /// Sets the stake for, against and abstaining of an open proposal to its vote weights.
+    pub(crate) fn update_tallies(proposal: &mut Proposal<T>, weights: &VoteWeights<T::AccountId>) {
+        let [weights_for, weights_against, weights_abstain] = weights;
+        let sum = |weights: &Vec<(T::AccountId, u64)>| {
+            weights.iter().fold(0u64, |acc, (_, weight)| acc.saturating_add(*weight))
+        };
+
+        if let ProposalStatus::Open {
+            stake_for,
+            stake_against,
+            stake_abstain,
             ..
         } = &mut proposal.status
+        {
+            *stake_for = sum(weights_for);
+            *stake_against = sum(weights_against);
+            *stake_abstain = sum(weights_abstain);
+        }
+    }
+
+    /// Returns the tally the key's vote counts towards, if it voted on the open proposal.
+    fn vote_tally<'a>(
+        status: &'a mut ProposalStatus<T>,
+        key: &T::AccountId,
+    ) -> Option<&'a mut u64> {
+        let ProposalStatus::Open {
+            votes_for,
+            votes_against,
+            votes_abstain,
+            stake_for,
+            stake_against,
+            stake_abstain,
+        } = status
         else {
-            return Err(Error::<T>::ProposalClosed.into());
+            return None;
         };
 
-        let removed = votes_for.remove(&key) || votes_against.remove(&key);
+        if votes_for.contains(key) {
+            Some(stake_for)
+        } else if votes_against.contains(key) {
+            Some(stake_against)
+        } else if votes_abstain.contains(key) {
+            Some(stake_abstain)
+        } else {
+            None
+        }
+    }
 
-        // Check if the voter has actually voted on the proposal
-        ensure!(removed, Error::<T>::NotVoted);
+    /// Takes the power the key delegated on the proposal back from its delegate when the key
+    /// votes, or gives it back when the key removes its vote.
+    fn reclaim_delegated_power(
+        proposal: &mut Proposal<T>,
+        key: &T::AccountId,
+        power: u64,
+        reclaim: bool,
+    ) {
+        let Some(delegate) = Self::get_proposal_delegate(proposal.id, key, proposal.subnet_id())
+        else {
+            return;
+        };
 
-        // Update the proposal in storage
-        Proposals::<T>::insert(proposal.id, proposal);
-        Self::deposit_event(Event::<T>::ProposalVoteUnregistered(proposal_id, key));
+        ReclaimedVotingPower::<T>::mutate_exists(proposal.id, &delegate, |reclaimed| {
+            let value = reclaimed.unwrap_or_default();
+            let value = if reclaim {
+                value.saturating_add(power)
+            } else {
+                value.saturating_sub(power)
+            };
+            *reclaimed = (value > 0).then_some(value);
+        });
+
+        if let Some(tally) = Self::vote_tally(&mut proposal.status, &delegate) {
+            *tally = if reclaim {
+                tally.saturating_sub(power)
+            } else {
+                tally.saturating_add(power)
+            };
+        }
+    }
+
+    /// Delegates the voting power of the caller on the given scope to another account, replacing
+    /// the previous delegation on that scope. The delegate votes with the power unless the caller
+    /// votes on the proposal itself. Delegated power doesn't flow further to the delegate's own
+    /// delegates.
+    pub fn do_delegate_voting_power(
+        origin: T::RuntimeOrigin,
+        delegate: T::AccountId,
+        scope: DelegationScope,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(delegate != key, Error::<T>::DelegationCycle);
+        ensure!(
+            VotingPowerDelegations::<T>::contains_key(&key, scope)
+                || VotingPowerDelegations::<T>::iter_key_prefix(&key).count()
+                    < MAX_DELEGATION_SCOPES as usize,
+            Error::<T>::TooManyDelegations
+        );
+
+        Self::set_delegation(&key, scope, Some(delegate.clone()));
+
+        Self::deposit_event(Event::<T>::VotingPowerDelegated(key, delegate, scope));
         Ok(())
     }
+
+    /// Stops delegating the voting power of the caller on the given scope.
+    pub fn do_undelegate_voting_power(
+        origin: T::RuntimeOrigin,
+        scope: DelegationScope,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(
+            VotingPowerDelegations::<T>::contains_key(&key, scope),
+            Error::<T>::NotDelegating
+        );
+        Self::set_delegation(&key, scope, None);
+
+        Self::deposit_event(Event::<T>::VotingPowerUndelegated(key, scope));
+        Ok(())
+    }
+
+    /// Replaces the delegation of the key on the scope, moving its voting power to the new
+    /// delegate. The previous delegation is recorded for the open proposals.
+    pub(crate) fn set_delegation(
+        key: &T::AccountId,
+        scope: DelegationScope,
+        delegate: Option<T::AccountId>,
+    ) {
+        let open_proposals = OpenProposals::<T>::get();
+        let power = Self::get_voting_power(key);
+
+        // Every delegation of the key may stop taking precedence, or start to
+        Self::update_delegated_power(&open_proposals, key, power, false);
+
+        let previous = VotingPowerDelegations::<T>::get(key, scope);
+        let mut checkpoints = VotingPowerDelegationCheckpoints::<T>::get(key, scope);
+        if record_checkpoint(&mut checkpoints, &open_proposals, || previous.clone()) {
+            if checkpoints.is_empty() {
+                VotingPowerDelegationCheckpoints::<T>::remove(key, scope);
+            } else {
+                VotingPowerDelegationCheckpoints::<T>::insert(key, scope, checkpoints);
+            }
+        }
+
+        if let Some(previous) = previous {
+            VotingPowerDelegators::<T>::remove(previous, (key.clone(), scope));
+        }
+        match delegate {
+            Some(delegate) => {
+                VotingPowerDelegations::<T>::insert(key, scope, &delegate);
+                VotingPowerDelegators::<T>::insert(&delegate, (key.clone(), scope), ());
+            }
+            None => VotingPowerDelegations::<T>::remove(key, scope),
+        }
+
+        Self::update_delegated_power(&open_proposals, key, power, true);
+    }
+
+    /// Returns the extra vote weight the voter gets on the proposal from its conviction lock.
+    /// The locked stake is already counted once by the regular tally.
+    pub fn get_conviction_bonus(voter: &T::AccountId, proposal_id: ProposalId) -> u64 {
+        VoteLocks::<T>::get(voter)
+            .iter()
+            .find(|lock| lock.proposal_id == proposal_id)
+            .map(|lock| lock.amount.saturating_mul(u64::from(lock.conviction.saturating_sub(1))))
+            .unwrap_or_default()
+    }
+}
+
+/// Drops the conviction locks expiring at the block. Returns the weight consumed.
+pub fn release_expired_vote_locks<T: Config>(block_number: u64) -> Weight {
+    let keys: Vec<T::AccountId> = VoteLockExpirations::<T>::drain_prefix(block_number)
+        .map(|(key, ())| key)
+        .collect();
+    let count = keys.len() as u64;
+    for key in keys {
+        VoteLocks::<T>::mutate_exists(&key, |locks| {
+            if let Some(current) = locks {
+                current.retain(|lock| lock.unlock_block > block_number);
+                if current.is_empty() {
+                    *locks = None;
+                }
+            }
+        });
+    }
+
+    T::DbWeight::get().reads_writes(
+        count.saturating_mul(2).saturating_add(1),
+        count.saturating_mul(2),
+    )
 }
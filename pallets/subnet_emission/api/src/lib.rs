@@ -1,5 +1,6 @@
 #![no_std]
 
+use frame_support::{traits::ConstU32, BoundedVec};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
@@ -15,6 +16,42 @@ pub enum SubnetConsensus {
     Root,
 }
 
+/// Maximum amount of subnets listed in a fixed allocation table.
+pub const MAX_FIXED_PRICING_ENTRIES: u32 = 256;
+
+/// The mechanism used to split the block emission between subnets.
+#[derive(Clone, Debug, Default, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub enum SubnetPricingMechanism {
+    /// Subnets are priced by the weights of the rootnet validators.
+    #[default]
+    Root,
+    /// Every subnet receives the same emission.
+    Equal,
+    /// Subnets receive emission proportional to the stake delegated to their modules.
+    Stake,
+    /// Subnets receive emission proportional to a fixed `(netuid, share)` table. Subnets missing
+    /// from the table receive nothing.
+    Fixed(BoundedVec<(u16, u32), ConstU32<MAX_FIXED_PRICING_ENTRIES>>),
+}
+
+impl SubnetPricingMechanism {
+    /// A fixed allocation table must list each subnet once and have a positive total share.
+    pub fn is_valid(&self) -> bool {
+        let Self::Fixed(table) = self else {
+            return true;
+        };
+
+        let mut netuids: BoundedVec<u16, ConstU32<MAX_FIXED_PRICING_ENTRIES>> = BoundedVec::new();
+        for (netuid, _) in table.iter() {
+            if netuids.contains(netuid) || netuids.try_push(*netuid).is_err() {
+                return false;
+            }
+        }
+
+        table.iter().any(|(_, share)| *share > 0)
+    }
+}
+
 pub trait SubnetEmissionApi {
     fn get_unit_emission() -> u64;
 
@@ -47,4 +84,9 @@ pub trait SubnetEmissionApi {
     fn get_subnet_consensus_type(netuid: u16) -> Option<SubnetConsensus>;
 
     fn set_subnet_consensus_type(netuid: u16, subnet_consensus: Option<SubnetConsensus>);
+
+    fn get_subnet_pricing_mechanism() -> SubnetPricingMechanism;
+
+    /// Schedules the switch to a new pricing mechanism, returning the block it takes effect at.
+    fn schedule_subnet_pricing_mechanism(mechanism: SubnetPricingMechanism) -> u64;
 }
@@ -0,0 +1,95 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{Pallet as SubnetEmissionMod, *};
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use pallet_subnet_emission_api::SubnetConsensus;
+use pallet_subspace::{Pallet as SubspaceMod, SubnetBurn};
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+fn register_mock<T: Config>(
+    key: T::AccountId,
+    module_key: T::AccountId,
+    name: Vec<u8>,
+) -> Result<(), &'static str> {
+    let address = "test".as_bytes().to_vec();
+    let network = "testnet".as_bytes().to_vec();
+
+    let enough_stake = 10000000000000u64;
+    SubspaceMod::<T>::add_balance_to_account(
+        &key,
+        SubspaceMod::<T>::u64_to_balance(SubnetBurn::<T>::get() + enough_stake).unwrap(),
+    );
+    let network_metadata = Some("networkmetadata".as_bytes().to_vec());
+    let metadata = Some("metadata".as_bytes().to_vec());
+    SubspaceMod::<T>::register(
+        RawOrigin::Signed(key.clone()).into(),
+        network,
+        name,
+        address,
+        module_key.clone(),
+        network_metadata,
+        metadata,
+    )?;
+    SubspaceMod::<T>::increase_stake(&key, &module_key, enough_stake);
+    Ok(())
+}
+
+benchmarks! {
+    // ---------------------------------
+    // Hooks
+    // ---------------------------------
+
+    process_emission_distribution {
+        let s in 1 .. 256;
+        let m in 1 .. 420;
+
+        pallet_subspace::MaxRegistrationsPerBlock::<T>::set(u16::MAX);
+
+        // The subnet running the epoch, with `m` modules
+        let founder: T::AccountId = account("Founder", 0, 1);
+        register_mock::<T>(founder.clone(), founder, "founder".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
+        pallet_subspace::MaxRegistrationsPerInterval::<T>::insert(netuid, u16::MAX);
+        SubnetConsensusType::<T>::insert(netuid, SubnetConsensus::Yuma);
+
+        for i in 1..m {
+            let key: T::AccountId = account("ModuleKey", i, 2);
+            let mut name = "module".as_bytes().to_vec();
+            name.extend(i.to_le_bytes());
+            register_mock::<T>(key.clone(), key, name)?;
+        }
+
+        // Runs an epoch on every block
+        pallet_subspace::Tempo::<T>::insert(netuid, 1);
+        PendingEmission::<T>::insert(netuid, 1_000_000_000);
+
+        // The remaining subnets only queue their emission
+        for i in 1..s {
+            let other = netuid.saturating_add(i as u16);
+            pallet_subspace::N::<T>::insert(other, 0);
+            pallet_subspace::Tempo::<T>::insert(other, u16::MAX);
+        }
+
+        let emission_per_block = SubnetEmissionMod::<T>::get_total_emission_per_block();
+    }: {
+        SubnetEmissionMod::<T>::process_emission_distribution(1, emission_per_block)
+    }
+
+    record_epoch_emission {
+        let e in 1 .. MAX_EPOCH_EMISSION_ENTRIES;
+
+        let netuid = 0;
+        pallet_subspace::RecordEpochEmission::<T>::insert(netuid, true);
+
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        let breakdown: BTreeMap<_, _> = (0..e)
+            .map(|i| {
+                let staker: T::AccountId = account("Staker", i, 3);
+                ((module_key.clone(), staker), (u64::from(i), 0))
+            })
+            .collect();
+    }: {
+        SubnetEmissionMod::<T>::record_epoch_emission(netuid, 1_000_000_000, breakdown)
+    }
+}
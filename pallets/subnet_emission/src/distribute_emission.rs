@@ -1,9 +1,14 @@
 use super::*;
-use crate::subnet_consensus::{linear::LinearEpoch, treasury::TreasuryEpoch, yuma::YumaEpoch};
+use crate::subnet_consensus::{
+    linear::LinearEpoch,
+    treasury::TreasuryEpoch,
+    yuma::{EmissionMap, YumaEpoch, YumaProgress},
+};
 
-use frame_support::storage::with_storage_layer;
+use frame_support::{storage::with_storage_layer, weights::Weight, BoundedVec};
 use pallet_subnet_emission_api::SubnetConsensus;
 use pallet_subspace::N;
+use sp_std::vec::Vec;
 
 /// Processes subnets by updating pending emissions and running epochs when due.
 ///
@@ -12,21 +17,98 @@ use pallet_subspace::N;
 /// * `block_number` - The current block number.
 /// * `subnets_emission_distribution` - A map of subnet IDs to their emission values.
 ///
-/// This function iterates through all subnets, updates their pending emissions,
-/// and runs an epoch if it's time for that subnet.
-fn process_subnets<T: Config>(block_number: u64, subnets_emission_distribution: PricedSubnets) {
+/// # Returns
+///
+/// The amount of subnets processed, the amount of modules of the subnets that ran an epoch, how
+/// many of those subnets record their epoch emission, the largest size the multi-block epoch
+/// progress read on the block can have and how many rewards the epochs paid.
+///
+/// This function iterates through all subnets and updates their pending emissions. Subnets
+/// whose epoch is due are appended to the `EpochQueue`, and at most `MaxEpochsPerBlock` queued
+/// epochs are run. The remaining ones are deferred to the next blocks, their pending emission
+/// still growing, so the deferred epochs drain exactly what their subnets accrued. Stages of
+/// multi-block epochs in progress come first and take a slot each, the ones past the budget
+/// waiting for the next block.
+fn process_subnets<T: Config>(
+    block_number: u64,
+    subnets_emission_distribution: PricedSubnets,
+) -> (u32, u32, u32, u64, u32) {
+    let mut subnets: u32 = 0;
+    let mut queue = EpochQueue::<T>::get();
+    let mut newly_queued = Vec::new();
+    let mut in_progress = Vec::new();
+
     for netuid in N::<T>::iter_keys() {
+        subnets = subnets.saturating_add(1);
         update_pending_emission::<T>(
             netuid,
             subnets_emission_distribution.get(&netuid).unwrap_or(&0),
         );
 
+        // Epochs falling due while a multi-block one is computed are skipped, the next one
+        // drains their emission
+        if YumaEpochProgress::<T>::contains_key(netuid) {
+            in_progress.push(netuid);
+            continue;
+        }
+
         if pallet_subspace::Pallet::<T>::blocks_until_next_epoch(netuid, block_number) > 0 {
             continue;
         }
 
-        run_epoch::<T>(netuid);
+        // An epoch still queued already drains everything the subnet accrued
+        if !queue.contains(&netuid) {
+            queue.push(netuid);
+            newly_queued.push(netuid);
+        }
+    }
+
+    // Every stage of a multi-block epoch takes a slot of the block budget
+    let mut budget = usize::from(MaxEpochsPerBlock::<T>::get().max(1));
+    let mut epoch_modules: u32 = 0;
+    let mut recorded_epochs: u32 = 0;
+    let mut count_epoch = |netuid: u16| {
+        epoch_modules = epoch_modules.saturating_add(u32::from(N::<T>::get(netuid)));
+        if pallet_subspace::RecordEpochEmission::<T>::get(netuid) {
+            recorded_epochs = recorded_epochs.saturating_add(1);
+        }
+    };
+    let mut progress_size: u64 = 0;
+    let mut rewards: u32 = 0;
+    // Multi-block epochs past the budget wait for the next block, keeping their progress
+    for netuid in in_progress.into_iter().take(budget) {
+        count_epoch(netuid);
+        budget = budget.saturating_sub(1);
+        progress_size = progress_size
+            .saturating_add(YumaProgress::<T>::max_encoded_size(N::<T>::get(netuid)).into());
+        rewards = rewards.saturating_add(advance_multi_block_epoch::<T>(netuid));
     }
+
+    let deferred = queue.split_off(budget.min(queue.len()));
+    for netuid in queue {
+        // The subnet might have been removed while its epoch was queued
+        if !N::<T>::contains_key(netuid) {
+            continue;
+        }
+
+        count_epoch(netuid);
+        rewards = rewards.saturating_add(run_epoch::<T>(netuid));
+    }
+
+    for netuid in newly_queued.into_iter().filter(|netuid| deferred.contains(netuid)) {
+        log::debug!("epoch of subnet {netuid} deferred, the block epoch budget was spent");
+        Pallet::<T>::deposit_event(Event::<T>::EpochDeferred(netuid));
+    }
+
+    EpochQueue::<T>::set(deferred);
+
+    (
+        subnets,
+        epoch_modules,
+        recorded_epochs,
+        progress_size,
+        rewards,
+    )
 }
 
 /// Updates the pending emission for a given subnet.
@@ -52,27 +134,108 @@ fn update_pending_emission<T: Config>(netuid: u16, new_queued_emission: &u64) {
 ///
 /// * `netuid` - The ID of the subnet.
 ///
-/// This function clears the set weight rate limiter, retrieves the pending emission,
-/// and if there's emission to distribute, runs the consensus algorithm. If successful,
-/// it finalizes the epoch. If an error occurs during consensus, it logs the error
-fn run_epoch<T: Config>(netuid: u16) {
+/// This function retrieves the pending emission, and if there's emission to distribute, runs
+/// the consensus algorithm. If successful, it finalizes the epoch. If an error occurs during
+/// consensus, it logs the error. Yuma subnets with at least `MultiBlockEpochThreshold` modules
+/// start a multi-block epoch instead. Returns how many rewards the epoch paid.
+fn run_epoch<T: Config>(netuid: u16) -> u32 {
     log::trace!("running epoch for subnet {netuid}");
 
     let emission_to_drain = PendingEmission::<T>::get(netuid);
-    if emission_to_drain > 0 {
-        match run_consensus_algorithm::<T>(netuid, emission_to_drain) {
-            Ok(()) => finalize_epoch::<T>(netuid),
-            Err(e) => {
-                log::error!(
-                    "Error running consensus algorithm for subnet {}: {:?}",
-                    netuid,
-                    e
-                );
-            }
+    if emission_to_drain == 0 {
+        return 0;
+    }
+
+    let module_count = N::<T>::get(netuid);
+    if SubnetConsensusType::<T>::get(netuid) == Some(SubnetConsensus::Yuma)
+        && module_count >= MultiBlockEpochThreshold::<T>::get()
+    {
+        if YumaProgress::<T>::max_encoded_size(module_count) <= MAX_YUMA_PROGRESS_SIZE {
+            YumaEpochProgress::<T>::insert(
+                netuid,
+                YumaProgress::<T>::new(netuid, emission_to_drain),
+            );
+            return advance_multi_block_epoch::<T>(netuid);
+        }
+
+        log::warn!(
+            "subnet {netuid} is too large to keep a multi-block epoch, running it in a single block"
+        );
+    }
+
+    match run_consensus_algorithm::<T>(netuid, emission_to_drain) {
+        Ok(rewards) => {
+            finalize_epoch::<T>(netuid, emission_to_drain);
+            rewards
+        }
+        Err(e) => {
+            log::error!(
+                "Error running consensus algorithm for subnet {}: {:?}",
+                netuid,
+                e
+            );
+            0
         }
     }
 }
 
+/// Computes the next stage of the multi-block epoch of a subnet.
+///
+/// # Arguments
+///
+/// * `netuid` - The ID of the subnet.
+///
+/// The stages compute from the subnet state read when the epoch started, the weights and bonds
+/// being copied aside until it ends. If the subnet stopped running Yuma in the meantime, or a
+/// stage fails, the epoch is dropped and its emission stays pending for the next one. The last
+/// stage commits the results and distributes the emission in a storage layer, draining only the
+/// emission pending when the epoch started. Returns how many rewards the stage paid.
+fn advance_multi_block_epoch<T: Config>(netuid: u16) -> u32 {
+    let Some(mut progress) = YumaEpochProgress::<T>::take(netuid) else {
+        return 0;
+    };
+
+    if !progress.is_current() {
+        log::warn!("subnet {netuid} no longer runs yuma, dropping its multi-block epoch");
+        Pallet::<T>::clear_yuma_snapshot(netuid, progress.module_count());
+        return 0;
+    }
+
+    let res = with_storage_layer(|| {
+        progress.step().map_err(|err| {
+            log::error!("Failed to run yuma consensus stage for subnet {netuid}: {err:?}");
+            "yuma failed"
+        })
+    });
+
+    match res {
+        Ok(Some(emissions)) => {
+            Pallet::<T>::clear_yuma_snapshot(netuid, progress.module_count());
+            finalize_epoch::<T>(netuid, progress.emission_to_drain());
+            reward_count::<T>(&emissions)
+        }
+        Ok(None) => {
+            YumaEpochProgress::<T>::insert(netuid, progress);
+            0
+        }
+        Err(_) => {
+            log::error!(
+                "multi-block epoch of subnet {netuid} failed, {} tokens will be emitted on the \
+                next epoch",
+                progress.emission_to_drain()
+            );
+            Pallet::<T>::clear_yuma_snapshot(netuid, progress.module_count());
+            0
+        }
+    }
+}
+
+/// Counts the rewards a Yuma epoch paid, one per module and staker.
+fn reward_count<T: Config>(emissions: &EmissionMap<T>) -> u32 {
+    let rewards = emissions.values().map(BTreeMap::len).fold(0usize, usize::saturating_add);
+    rewards.try_into().unwrap_or(u32::MAX)
+}
+
 // ---------------------------------
 // Consensus
 // ---------------------------------
@@ -86,22 +249,24 @@ fn run_epoch<T: Config>(netuid: u16) {
 ///
 /// # Returns
 ///
-/// A Result indicating success or failure of the consensus algorithm.
+/// How many rewards the consensus algorithm paid, or its failure.
 ///
 /// This function selects and runs either the linear or Yuma consensus algorithm
 /// based on the subnet ID.
 fn run_consensus_algorithm<T: Config>(
     netuid: u16,
     emission_to_drain: u64,
-) -> Result<(), &'static str> {
+) -> Result<u32, &'static str> {
     with_storage_layer(|| {
         let Some(consensus_type) = SubnetConsensusType::<T>::get(netuid) else {
-            return Ok(());
+            return Ok(0);
         };
 
         match consensus_type {
-            SubnetConsensus::Root => Ok(()),
-            SubnetConsensus::Treasury => run_treasury_consensus::<T>(netuid, emission_to_drain),
+            SubnetConsensus::Root => Ok(0),
+            SubnetConsensus::Treasury => {
+                run_treasury_consensus::<T>(netuid, emission_to_drain).map(|()| 0)
+            }
             SubnetConsensus::Linear => run_linear_consensus::<T>(netuid, emission_to_drain),
             SubnetConsensus::Yuma => run_yuma_consensus::<T>(netuid, emission_to_drain),
         }
@@ -116,23 +281,20 @@ fn run_consensus_algorithm<T: Config>(
 ///
 /// # Returns
 ///
-/// A Result indicating success or failure of the linear consensus algorithm.
+/// How many rewards the linear consensus algorithm paid, or its failure.
 ///
 /// This function creates and runs a new LinearEpoch, logging any errors that occur.
 fn run_linear_consensus<T: Config>(
     netuid: u16,
     emission_to_drain: u64,
-) -> Result<(), &'static str> {
-    LinearEpoch::<T>::new(netuid, emission_to_drain)
-        .run()
-        .map(|_| ())
-        .map_err(|err| {
-            log::error!(
-                "Failed to run linear consensus algorithm: {err:?}, skipping this block. \
+) -> Result<u32, &'static str> {
+    LinearEpoch::<T>::new(netuid, emission_to_drain).run().map_err(|err| {
+        log::error!(
+            "Failed to run linear consensus algorithm: {err:?}, skipping this block. \
             {emission_to_drain} tokens will be emitted on the next epoch."
-            );
-            "linear failed"
-        })
+        );
+        "linear failed"
+    })
 }
 
 /// Runs the Yuma consensus algorithm for subnets other than 0.
@@ -144,17 +306,20 @@ fn run_linear_consensus<T: Config>(
 ///
 /// # Returns
 ///
-/// A Result indicating success or failure of the Yuma consensus algorithm.
+/// How many rewards the Yuma consensus algorithm paid, or its failure.
 ///
 /// This function creates and runs a new YumaEpoch, logging any errors that occur.
-fn run_yuma_consensus<T: Config>(netuid: u16, emission_to_drain: u64) -> Result<(), &'static str> {
-    YumaEpoch::<T>::new(netuid, emission_to_drain).run().map(|_| ()).map_err(|err| {
-        log::error!(
-            "Failed to run yuma consensus algorithm: {err:?}, skipping this block. \
+fn run_yuma_consensus<T: Config>(netuid: u16, emission_to_drain: u64) -> Result<u32, &'static str> {
+    YumaEpoch::<T>::new(netuid, emission_to_drain)
+        .run()
+        .map(|emissions| reward_count::<T>(&emissions))
+        .map_err(|err| {
+            log::error!(
+                "Failed to run yuma consensus algorithm: {err:?}, skipping this block. \
             {emission_to_drain} tokens will be emitted on the next epoch."
-        );
-        "yuma failed"
-    })
+            );
+            "yuma failed"
+        })
 }
 
 /// Runs the treasury consensus algorithm for a given network and emission amount.
@@ -195,11 +360,12 @@ fn run_treasury_consensus<T: Config>(
 /// # Arguments
 ///
 /// * `netuid` - The ID of the subnet.
+/// * `drained` - The pending emission distributed by the epoch.
 ///
-/// This function resets the pending emission for the subnet to 0 and
+/// This function removes the drained emission from the pending emission of the subnet and
 /// emits an EpochFinished event.
-fn finalize_epoch<T: Config>(netuid: u16) {
-    PendingEmission::<T>::insert(netuid, 0);
+fn finalize_epoch<T: Config>(netuid: u16, drained: u64) {
+    PendingEmission::<T>::mutate(netuid, |pending| *pending = pending.saturating_sub(drained));
     Pallet::<T>::deposit_event(Event::<T>::EpochFinished(netuid));
 }
 
@@ -212,12 +378,67 @@ impl<T: Config> Pallet<T> {
     /// * `emission_per_block` - The total emission to be distributed per block.
     ///
     /// This function calculates the emission distribution across subnets and
-    /// processes each subnet accordingly.
-    pub fn process_emission_distribution(block_number: u64, emission_per_block: u64) {
+    /// processes each subnet accordingly, returning the weight consumed.
+    pub fn process_emission_distribution(block_number: u64, emission_per_block: u64) -> Weight {
         log::debug!("stepping block {block_number:?}");
 
         let subnets_emission_distribution = Self::get_subnet_pricing(emission_per_block);
-        process_subnets::<T>(block_number, subnets_emission_distribution);
+        let (subnets, epoch_modules, recorded_epochs, progress_size, rewards) =
+            process_subnets::<T>(block_number, subnets_emission_distribution);
+
+        // Charged as if every recorded epoch filled its record
+        let record_weight =
+            <T as Config>::WeightInfo::record_epoch_emission(MAX_EPOCH_EMISSION_ENTRIES)
+                .saturating_mul(recorded_epochs.into());
+
+        <T as Config>::WeightInfo::process_emission_distribution(subnets, epoch_modules)
+            .saturating_add(record_weight)
+            .saturating_add(Weight::from_parts(0, progress_size))
+            // Compounded rewards change the voting power of the staker
+            .saturating_add(T::voting_power_change_weight().saturating_mul(rewards.into()))
+    }
+
+    /// Keeps the emission breakdown of the epoch that just ran, if the subnet opted into it
+    /// through the `record_epoch_emission` parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `netuid` - The ID of the subnet.
+    /// * `founder_emission` - The founder cut of the epoch.
+    /// * `breakdown` - The amount and delegation fee of every `(module_key, staker)` pair.
+    pub(crate) fn record_epoch_emission(
+        netuid: u16,
+        founder_emission: u64,
+        breakdown: BTreeMap<(T::AccountId, T::AccountId), (u64, u64)>,
+    ) {
+        if !pallet_subspace::RecordEpochEmission::<T>::get(netuid) {
+            LastEpochEmission::<T>::remove(netuid);
+            return;
+        }
+
+        let mut entries: Vec<_> = breakdown
+            .into_iter()
+            .map(|((module_key, staker), (amount, fee))| ModuleEmission {
+                module_key,
+                staker,
+                amount,
+                fee,
+            })
+            .collect();
+        entries.sort_by(|a, b| b.amount.cmp(&a.amount));
+
+        let truncated = entries.len() > MAX_EPOCH_EMISSION_ENTRIES as usize;
+        let entries = BoundedVec::truncate_from(entries);
+
+        LastEpochEmission::<T>::insert(
+            netuid,
+            EpochEmission {
+                block: pallet_subspace::Pallet::<T>::get_current_block_number(),
+                founder_emission,
+                entries,
+                truncated,
+            },
+        );
     }
 
     // ---------------------------------
@@ -252,6 +473,11 @@ impl<T: Config> Pallet<T> {
     /// * `netuid` - The ID of the subnet to remove from storage.
     pub fn remove_subnet_emission_storage(netuid: u16) {
         SubnetEmission::<T>::remove(netuid);
+        LastEpochEmission::<T>::remove(netuid);
+        EpochQueue::<T>::mutate(|queue| queue.retain(|queued| *queued != netuid));
+        if let Some(progress) = YumaEpochProgress::<T>::take(netuid) {
+            Self::clear_yuma_snapshot(netuid, progress.module_count());
+        }
     }
 
     /// Sets the emission storage for a given subnet.
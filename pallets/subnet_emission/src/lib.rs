@@ -8,11 +8,16 @@ use sp_std::collections::btree_map::BTreeMap;
 // Pallet Imports
 // ==============
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 pub mod distribute_emission;
 pub mod migrations;
 pub mod subnet_pricing {
     pub mod demo;
+    pub mod fixed;
     pub mod root;
+    pub mod stake;
 }
 
 pub mod subnet_consensus {
@@ -21,22 +26,27 @@ pub mod subnet_consensus {
     pub mod yuma;
 }
 
+pub mod weights; // Weight benchmarks
+
 // TODO:
 // move some import outside of the macro
 #[frame_support::pallet]
 pub mod pallet {
+    pub use crate::weights::WeightInfo;
     use crate::*;
     use frame_support::{
         pallet_prelude::*,
         sp_runtime::SaturatedConversion,
         storage::with_storage_layer,
-        traits::{ConstU64, Currency},
+        traits::{ConstU16, ConstU64, Currency},
     };
     use frame_system::pallet_prelude::BlockNumberFor;
-    use pallet_subnet_emission_api::SubnetConsensus;
-    use pallet_subspace::TotalStake;
+    use pallet_subnet_emission_api::{SubnetConsensus, SubnetPricingMechanism};
+    use pallet_subspace::{TotalStake, TotalUnbonding};
 
-    use subnet_pricing::root::RootPricing;
+    use subnet_pricing::{
+        demo::DemoPricing, fixed::FixedPricing, root::RootPricing, stake::StakePricing,
+    };
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
 
@@ -68,6 +78,9 @@ pub mod pallet {
         /// The maximum token supply.
         #[pallet::constant]
         type MaxSupply: Get<u64>;
+
+        /// The weight information of this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     // Storage
@@ -85,6 +98,80 @@ pub mod pallet {
     #[pallet::storage]
     pub type SubnetConsensusType<T> = StorageMap<_, Identity, u16, SubnetConsensus>;
 
+    /// Maximum amount of subnet epochs run on a single block. Epochs due past it are queued and
+    /// run on the following blocks, their subnets accruing emission in the meantime.
+    #[pallet::storage]
+    pub type MaxEpochsPerBlock<T> = StorageValue<_, u16, ValueQuery, ConstU16<8>>;
+
+    #[pallet::storage] // --- ITEM ( netuids )
+    pub type EpochQueue<T> = StorageValue<_, Vec<u16>, ValueQuery>;
+
+    /// Amount of modules from which the Yuma epochs of a subnet are computed a stage per block,
+    /// instead of all at once.
+    #[pallet::storage]
+    pub type MultiBlockEpochThreshold<T> = StorageValue<_, u16, ValueQuery, ConstU16<256>>;
+
+    /// Largest size, in bytes, the progress of a multi-block epoch can take. It is read and
+    /// rewritten on every stage, so larger subnets run their epochs in a single block instead.
+    pub const MAX_YUMA_PROGRESS_SIZE: u32 = 128 * 1024;
+
+    #[pallet::storage] // --- MAP ( netuid ) --> yuma_progress
+    pub type YumaEpochProgress<T: Config> =
+        StorageMap<_, Identity, u16, subnet_consensus::yuma::YumaProgress<T>>;
+
+    /// Weights rows of the modules as they were when the multi-block epoch of the subnet started.
+    #[pallet::storage] // --- DMAP ( netuid, uid ) --> weights
+    pub type YumaEpochWeights<T> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, Vec<(u16, u16)>, ValueQuery>;
+
+    /// Bonds rows of the modules as they were when the multi-block epoch of the subnet started.
+    #[pallet::storage] // --- DMAP ( netuid, uid ) --> bonds
+    pub type YumaEpochBonds<T> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, Vec<(u16, u16)>, ValueQuery>;
+
+    /// Amount of blocks between a pricing mechanism switch being scheduled and taking effect.
+    pub const SUBNET_PRICING_ACTIVATION_DELAY: u64 = 7_200;
+
+    #[pallet::storage]
+    pub type SubnetPricing<T> = StorageValue<_, SubnetPricingMechanism, ValueQuery>;
+
+    #[pallet::storage] // --- ITEM ( activation_block, mechanism )
+    pub type PendingSubnetPricing<T> = StorageValue<_, (u64, SubnetPricingMechanism)>;
+
+    /// Amount of blocks the subnet stakes used by the stake pricing mechanism are kept for
+    /// before being computed again.
+    pub const STAKE_PRICING_INTERVAL: u64 = 100;
+
+    #[pallet::storage] // --- ITEM ( computed_at_block, subnet_stakes )
+    pub type SubnetStakes<T> = StorageValue<_, (u64, PricedSubnets)>;
+
+    /// Maximum amount of entries kept in the emission record of an epoch. Keeps the record of a
+    /// subnet around 20 KB, as it is rewritten on every epoch.
+    pub const MAX_EPOCH_EMISSION_ENTRIES: u32 = 256;
+
+    /// Emission paid to a staker through a module on an epoch.
+    #[derive(Clone, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+    pub struct ModuleEmission<AccountId> {
+        pub module_key: AccountId,
+        pub staker: AccountId,
+        pub amount: u64,
+        pub fee: u64, // delegation fee kept by the module out of the staker's share
+    }
+
+    #[derive(Clone, PartialEq, Eq, TypeInfo, Decode, Encode, frame_support::DebugNoBound)]
+    #[scale_info(skip_type_params(T))]
+    pub struct EpochEmission<T: Config> {
+        pub block: u64,
+        pub founder_emission: u64,
+        /// Largest emissions first. Smaller ones are left out past
+        /// `MAX_EPOCH_EMISSION_ENTRIES`, in which case `truncated` is set.
+        pub entries: BoundedVec<ModuleEmission<T::AccountId>, ConstU32<MAX_EPOCH_EMISSION_ENTRIES>>,
+        pub truncated: bool,
+    }
+
+    #[pallet::storage] // --- MAP ( netuid ) --> epoch_emission
+    pub type LastEpochEmission<T: Config> = StorageMap<_, Identity, u16, EpochEmission<T>>;
+
     type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -100,17 +187,24 @@ pub mod pallet {
             let block_number: u64 =
                 block_number.try_into().ok().expect("blockchain won't pass 2 ^ 64 blocks");
 
+            // The total issuance, stake and unit emission, and the pending pricing switch
+            let mut weight = T::DbWeight::get().reads_writes(5, 2);
+
             let emission_per_block = Self::get_total_emission_per_block();
             // Make sure to use storage layer,
             // so runtime can never panic in initialization hook
             let res: Result<(), DispatchError> = with_storage_layer(|| {
-                Self::process_emission_distribution(block_number, emission_per_block);
+                Self::activate_pending_subnet_pricing(block_number);
+                weight = weight.saturating_add(Self::process_emission_distribution(
+                    block_number,
+                    emission_per_block,
+                ));
                 Ok(())
             });
             if let Err(err) = res {
                 log::error!("Error in on_initialize emission: {err:?}, skipping...");
             }
-            Weight::zero()
+            weight
         }
     }
 
@@ -119,6 +213,13 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// Subnets tempo has finished
         EpochFinished(u16),
+        /// The subnet epoch is due but was queued to a later block, as the epoch budget of the
+        /// block was spent
+        EpochDeferred(u16),
+        /// A new subnet pricing mechanism was scheduled to take effect at the given block
+        SubnetPricingScheduled(SubnetPricingMechanism, u64),
+        /// The subnet pricing mechanism was switched
+        SubnetPricingChanged(SubnetPricingMechanism),
     }
 
     #[derive(Debug)]
@@ -151,10 +252,12 @@ pub mod pallet {
     {
             let total_free_balance = Self::get_total_free_balance();
             let total_staked_balance = TotalStake::<T>::get();
+            let total_unbonding_balance = TotalUnbonding::<T>::get();
             total_free_balance
                 .try_into()
                 .unwrap_or_default()
                 .saturating_add(total_staked_balance)
+                .saturating_add(total_unbonding_balance)
         }
 
         // Halving Logic / Emission distributed per block
@@ -208,21 +311,22 @@ pub mod pallet {
         // Emission Distribution per Subnet
         // =================================
 
-        // Returns emisison for every network
-        // TODO
-        // later
-        // change this to also have the governacne processes, of picking the right subnet pricing
+        // Returns emisison for every network, using the pricing mechanism picked by governance.
+        // When the mechanism fails, e.g. a fixed table pricing no existing subnet, the emission
+        // is split equally instead, so the priced subnets always sum to `token_emission` as long
+        // as a subnet exists.
         #[must_use]
         pub fn get_subnet_pricing(token_emission: u64) -> PricedSubnets {
-            let rootnet_id = Self::get_consensus_netuid(SubnetConsensus::Root).unwrap_or(0);
-            let pricing = RootPricing::<T>::new(rootnet_id, token_emission);
-            let priced_subnets = match pricing.run() {
-                Ok(priced_subnets) => priced_subnets,
-                Err(err) => {
+            let priced_subnets = Self::run_subnet_pricing(token_emission)
+                .or_else(|err| {
+                    log::warn!("could not get priced subnets, splitting equally: {err:?}");
+                    DemoPricing::<T>::new(token_emission).run()
+                })
+                .map(|priced_subnets| Self::split_emission(token_emission, &priced_subnets))
+                .unwrap_or_else(|err| {
                     log::debug!("could not get priced subnets: {err:?}");
                     PricedSubnets::default()
-                }
-            };
+                });
 
             for (netuid, emission) in priced_subnets.iter() {
                 SubnetEmission::<T>::insert(netuid, emission);
@@ -230,5 +334,82 @@ pub mod pallet {
 
             priced_subnets
         }
+
+        fn run_subnet_pricing(token_emission: u64) -> Result<PricedSubnets, DispatchError> {
+            match SubnetPricing::<T>::get() {
+                SubnetPricingMechanism::Root => {
+                    let rootnet_id = Self::get_consensus_netuid(SubnetConsensus::Root).unwrap_or(0);
+                    RootPricing::<T>::new(rootnet_id, token_emission).run()
+                }
+                SubnetPricingMechanism::Equal => DemoPricing::<T>::new(token_emission).run(),
+                SubnetPricingMechanism::Stake => StakePricing::<T>::new(token_emission).run(),
+                SubnetPricingMechanism::Fixed(table) => {
+                    FixedPricing::<T>::new(table.into_inner(), token_emission).run()
+                }
+            }
+        }
+
+        /// Splits `to_be_emitted` between the subnets proportionally to their shares. Flooring
+        /// loses less than one token per subnet, which is handed out to the subnets with a
+        /// share in netuid order, so the result always sums to `to_be_emitted` unless no
+        /// subnet has a share.
+        #[must_use]
+        pub fn split_emission(to_be_emitted: u64, shares: &PricedSubnets) -> PricedSubnets {
+            let total_shares: u128 = shares.values().map(|share| u128::from(*share)).sum();
+
+            let mut priced_subnets: PricedSubnets = shares
+                .iter()
+                .map(|(netuid, share)| {
+                    let emission = u128::from(*share)
+                        .saturating_mul(u128::from(to_be_emitted))
+                        .checked_div(total_shares)
+                        .unwrap_or_default();
+                    (*netuid, u64::try_from(emission).unwrap_or_default())
+                })
+                .collect();
+
+            if total_shares == 0 {
+                return priced_subnets;
+            }
+
+            let emitted = priced_subnets.values().fold(0u64, |acc, e| acc.saturating_add(*e));
+            let mut remainder = to_be_emitted.saturating_sub(emitted);
+            for (netuid, emission) in priced_subnets.iter_mut() {
+                if remainder == 0 {
+                    break;
+                }
+                if shares.get(netuid).is_some_and(|share| *share > 0) {
+                    *emission = emission.saturating_add(1);
+                    remainder = remainder.saturating_sub(1);
+                }
+            }
+
+            priced_subnets
+        }
+
+        /// Schedules the switch to `mechanism`, replacing any switch that is still pending.
+        pub fn schedule_subnet_pricing_mechanism(mechanism: SubnetPricingMechanism) -> u64 {
+            let activation_block = pallet_subspace::Pallet::<T>::get_current_block_number()
+                .saturating_add(SUBNET_PRICING_ACTIVATION_DELAY);
+
+            PendingSubnetPricing::<T>::set(Some((activation_block, mechanism.clone())));
+            Self::deposit_event(Event::SubnetPricingScheduled(mechanism, activation_block));
+
+            activation_block
+        }
+
+        fn activate_pending_subnet_pricing(block_number: u64) {
+            let Some((activation_block, mechanism)) = PendingSubnetPricing::<T>::get() else {
+                return;
+            };
+
+            if block_number < activation_block {
+                return;
+            }
+
+            PendingSubnetPricing::<T>::kill();
+            SubnetPricing::<T>::set(mechanism.clone());
+            Self::deposit_event(Event::SubnetPricingChanged(mechanism));
+        }
     }
 }
@@ -8,7 +8,8 @@ use pallet_subspace::{
     LastUpdate, Pallet as PalletSubspace, SubnetParams, Trust, TrustRatio, Vec, Weights, N,
 };
 // use sp_core::Get;
-use sp_std::vec;
+use sp_arithmetic::per_things::Percent;
+use sp_std::{collections::btree_map::BTreeMap, vec};
 use substrate_fixed::types::{I32F32, I64F64};
 
 // struct WeightCounter<T: Config> {
@@ -80,10 +81,10 @@ impl<T: Config + pallet::Config> LinearEpoch<T> {
 
     /// This function acts as the main function of the entire blockchain reward distribution.
     /// It calculates the dividends, the incentive, the weights, the bonds,
-    /// the trust and the emission for the epoch.
-    pub fn run(self) -> Result<(), EmissionError> {
+    /// the trust and the emission for the epoch. Returns how many rewards were paid.
+    pub fn run(self) -> Result<u32, EmissionError> {
         if self.module_count == 0 {
-            return Ok(());
+            return Ok(0);
         }
 
         // STAKE
@@ -176,9 +177,7 @@ impl<T: Config + pallet::Config> LinearEpoch<T> {
             &self.founder_key,
             &uid_key_tuples,
             self.linear_netuid,
-        )?;
-
-        Ok(())
+        )
     }
 
     fn calculate_emission_ratios(
@@ -229,7 +228,7 @@ impl<T: Config + pallet::Config> LinearEpoch<T> {
         founder_key: &T::AccountId,
         uid_key_tuples: &[(u16, T::AccountId)],
         linear_netuid: u16,
-    ) -> Vec<u64> {
+    ) -> (Vec<u64>, u32) {
         let n = incentive_emission_float.len();
         let mut incentive_emission: Vec<u64> =
             incentive_emission_float.iter().map(|e| e.to_num::<u64>()).collect();
@@ -247,9 +246,26 @@ impl<T: Config + pallet::Config> LinearEpoch<T> {
 
         let mut emission: Vec<u64> = vec![0; n];
         let mut emitted = 0u64;
+        let mut payouts: BTreeMap<(T::AccountId, T::AccountId), u64> = BTreeMap::new();
+        let mut breakdown: BTreeMap<(T::AccountId, T::AccountId), (u64, u64)> = BTreeMap::new();
+        let mut rewards = 0u32;
+        let mut pay_reward = |staker: &T::AccountId,
+                              module_key: &T::AccountId,
+                              amount: u64,
+                              fee: u64| {
+            rewards = rewards.saturating_add(1);
+            if let Some(destination) = PalletSubspace::<T>::pay_reward(staker, module_key, amount) {
+                let paid = payouts.entry((staker.clone(), destination)).or_default();
+                *paid = paid.saturating_add(amount);
+            }
+
+            let (paid, kept) = breakdown.entry((module_key.clone(), staker.clone())).or_default();
+            *paid = paid.saturating_add(amount);
+            *kept = kept.saturating_add(fee);
+        };
 
         for (module_uid, module_key) in uid_key_tuples.iter() {
-            let owner_emission_incentive: u64 =
+            let mut owner_emission_incentive: u64 =
                 *incentive_emission.get(*module_uid as usize).unwrap_or(&0);
             let mut owner_dividends_emission: u64 =
                 *dividends_emission.get(*module_uid as usize).unwrap_or(&0);
@@ -276,20 +292,48 @@ impl<T: Config + pallet::Config> LinearEpoch<T> {
                             .unwrap_or_default();
                     let to_module: u64 = delegation_fee.mul_floor(dividends_from_delegate);
                     let to_delegate: u64 = dividends_from_delegate.saturating_sub(to_module);
-                    PalletSubspace::<T>::increase_stake(delegate_key, module_key, to_delegate);
+                    pay_reward(delegate_key, module_key, to_delegate, to_module);
                     emitted = emitted.saturating_add(to_delegate);
                     owner_dividends_emission = owner_dividends_emission.saturating_sub(to_delegate);
                 }
             }
 
+            // The whole incentive goes to the module unless it shares it with its stakers
+            let miner_delegation_fee = PalletSubspace::<T>::get_miner_delegation_fee(module_key);
+            if owner_emission_incentive > 0 && miner_delegation_fee < Percent::from_percent(100) {
+                let ownership_vector: Vec<(T::AccountId, I64F64)> =
+                    PalletSubspace::<T>::get_ownership_ratios(netuid, module_key);
+
+                let total_owner_emission_incentive: u64 = owner_emission_incentive;
+                for (delegate_key, delegate_ratio) in ownership_vector.iter() {
+                    if delegate_key == module_key {
+                        continue;
+                    }
+
+                    let incentive_from_delegate: u64 =
+                        I64F64::from_num(total_owner_emission_incentive)
+                            .checked_mul(*delegate_ratio)
+                            .map(|result| result.to_num::<u64>())
+                            .unwrap_or_default();
+                    let to_module: u64 = miner_delegation_fee.mul_floor(incentive_from_delegate);
+                    let to_delegate: u64 = incentive_from_delegate.saturating_sub(to_module);
+                    pay_reward(delegate_key, module_key, to_delegate, to_module);
+                    emitted = emitted.saturating_add(to_delegate);
+                    owner_emission_incentive = owner_emission_incentive.saturating_sub(to_delegate);
+                }
+            }
+
             let owner_emission: u64 =
                 owner_emission_incentive.saturating_add(owner_dividends_emission);
             if owner_emission > 0 {
-                PalletSubspace::<T>::increase_stake(module_key, module_key, owner_emission);
+                pay_reward(module_key, module_key, owner_emission, 0);
                 emitted = emitted.saturating_add(owner_emission);
             }
         }
 
+        PalletSubspace::<T>::deposit_reward_payouts(netuid, payouts);
+        Pallet::<T>::record_epoch_emission(netuid, founder_emission, breakdown);
+
         if netuid == linear_netuid && founder_emission > 0 {
             // Update global treasure
             PalletSubspace::<T>::add_balance_to_account(
@@ -298,7 +342,7 @@ impl<T: Config + pallet::Config> LinearEpoch<T> {
             );
         }
 
-        emission
+        (emission, rewards)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -311,11 +355,11 @@ impl<T: Config + pallet::Config> LinearEpoch<T> {
         founder_key: &T::AccountId,
         uid_key_tuples: &[(u16, T::AccountId)],
         linear_netuid: u16,
-    ) -> Result<(), EmissionError> {
+    ) -> Result<u32, EmissionError> {
         let (incentive_emission_float, dividends_emission_float) =
             Self::calculate_emission_ratios(incentive, dividends, to_be_emitted, netuid);
 
-        let emission = Self::calculate_emissions(
+        let (emission, rewards) = Self::calculate_emissions(
             &incentive_emission_float,
             &dividends_emission_float,
             founder_emission,
@@ -335,7 +379,7 @@ impl<T: Config + pallet::Config> LinearEpoch<T> {
         }
 
         Emission::<T>::insert(netuid, emission);
-        Ok(())
+        Ok(rewards)
     }
 
     fn compute_dividends(
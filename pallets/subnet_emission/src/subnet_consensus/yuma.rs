@@ -1,5 +1,4 @@
-use crate::{EmissionError, Pallet};
-use core::marker::PhantomData;
+use crate::{pallet, EmissionError, Pallet, YumaEpochBonds, YumaEpochWeights};
 use frame_support::{ensure, DebugNoBound};
 use pallet_subspace::{
     math::*, Active, Bonds, BondsMovingAverage, Config, Consensus, Dividends, Emission, Founder,
@@ -7,6 +6,9 @@ use pallet_subspace::{
     Pallet as PalletSubspace, PruningScores, Rank, Trust, Uids, ValidatorPermits, ValidatorTrust,
     Vec, Weights, N,
 };
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_arithmetic::per_things::Percent;
 use sp_std::vec;
 use substrate_fixed::types::{I32F32, I64F64, I96F32};
 
@@ -14,7 +16,8 @@ use sp_std::{borrow::Cow, collections::btree_map::BTreeMap};
 
 pub type EmissionMap<T> = BTreeMap<ModuleKey<T>, BTreeMap<AccountKey<T>, u64>>;
 
-#[derive(DebugNoBound)]
+#[derive(DebugNoBound, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
 pub struct YumaEpoch<T: Config> {
     /// The amount of modules on the subnet
     module_count: u16,
@@ -23,7 +26,7 @@ pub struct YumaEpoch<T: Config> {
     /// Consensus majority ratio, e.g. 51%.
     kappa: I32F32,
 
-    founder_key: AccountKey<T>,
+    founder_key: T::AccountId,
     founder_emission: u64,
     to_be_emitted: u64,
 
@@ -35,11 +38,145 @@ pub struct YumaEpoch<T: Config> {
     validator_permits: Vec<bool>,
     validator_forbids: Vec<bool>,
     max_allowed_validators: Option<u16>,
+    bonds_moving_average: u64,
 
-    _pd: PhantomData<T>,
+    /// The module keys by uid, in storage order
+    keys: Vec<(u16, T::AccountId)>,
+    /// Whether the weights and bonds are read from the copy taken when a multi-block epoch
+    /// started, instead of from the subnet storage
+    snapshot: bool,
 }
 
-impl<T: Config> YumaEpoch<T> {
+/// The stages a Yuma epoch is computed in, in order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum YumaStage {
+    /// Computes the stake and validator permits
+    Prepare,
+    /// Computes the consensus, validator trust and preranks
+    Consensus,
+    /// Computes the ranks, trust and incentives
+    Incentives,
+    /// Computes the bonds and dividends, writes the results and distributes the emission
+    Commit,
+}
+
+impl YumaStage {
+    fn next(self) -> Self {
+        match self {
+            Self::Prepare => Self::Consensus,
+            Self::Consensus => Self::Incentives,
+            Self::Incentives | Self::Commit => Self::Commit,
+        }
+    }
+}
+
+/// Values computed by the stages of a Yuma epoch, kept between them. Only per-module vectors are
+/// kept, the weights and bonds matrices are read from the copy taken when the epoch started.
+#[derive(Clone, Debug, Default, PartialEq, Encode, Decode, TypeInfo)]
+pub struct YumaValues {
+    active: Vec<bool>,
+    stake: Vec<I32F32>,
+    new_permits: Vec<bool>,
+
+    consensus: Vec<I32F32>,
+    validator_trust: Vec<u16>,
+    /// Only kept until the incentives are computed
+    preranks: Vec<I32F32>,
+
+    incentives: Vec<I32F32>,
+    ranks: Vec<u16>,
+    trust: Vec<u16>,
+}
+
+/// Most bytes a module adds to the progress, its key aside: two flags, the stake, consensus and
+/// incentives as `I32F32`, and the validator trust, rank and trust as `u16` for the values kept
+/// between stages, plus its last update, registration block, permit, forbid flag and uid read when
+/// the epoch started. The preranks are dropped by the stage computing the incentives.
+const PROGRESS_BYTES_PER_MODULE: u32 = 52;
+
+/// Bytes of the progress not depending on the amount of modules, founder key aside, vector lengths
+/// included.
+const PROGRESS_FIXED_BYTES: u32 = 192;
+
+/// A Yuma epoch computed a stage per block, for subnets too large to run it in a single one.
+#[derive(DebugNoBound, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct YumaProgress<T: Config> {
+    /// The subnet state the epoch is computed from, read when it started
+    epoch: YumaEpoch<T>,
+    /// The pending emission the epoch drains once committed
+    emission_to_drain: u64,
+    /// The stage computed on the next step
+    stage: YumaStage,
+    values: YumaValues,
+}
+
+impl<T: Config + pallet::Config> YumaProgress<T> {
+    /// Starts an epoch on the subnet as it is now, copying the weights and bonds its stages read.
+    pub fn new(netuid: u16, emission_to_drain: u64) -> Self {
+        let mut epoch = YumaEpoch::new(netuid, emission_to_drain);
+        epoch.snapshot = true;
+        Pallet::<T>::snapshot_yuma_matrices(netuid, epoch.module_count);
+
+        Self {
+            epoch,
+            emission_to_drain,
+            stage: YumaStage::Prepare,
+            values: YumaValues::default(),
+        }
+    }
+
+    /// Largest encoded size the progress of an epoch over `module_count` modules reaches.
+    pub fn max_encoded_size(module_count: u16) -> u32 {
+        let key_size = T::AccountId::max_encoded_len().try_into().unwrap_or(u32::MAX);
+        PROGRESS_BYTES_PER_MODULE
+            .saturating_add(key_size)
+            .saturating_mul(module_count.into())
+            .saturating_add(PROGRESS_FIXED_BYTES)
+            .saturating_add(key_size)
+    }
+
+    /// The amount of modules on the subnet when the epoch started.
+    pub fn module_count(&self) -> u16 {
+        self.epoch.module_count
+    }
+
+    pub fn emission_to_drain(&self) -> u64 {
+        self.emission_to_drain
+    }
+
+    pub fn stage(&self) -> YumaStage {
+        self.stage
+    }
+
+    /// Whether the subnet still runs Yuma consensus. Modules registered or deregistered while the
+    /// epoch is computed don't invalidate it, its results follow the module keys when committed.
+    pub fn is_current(&self) -> bool {
+        pallet::SubnetConsensusType::<T>::get(self.epoch.netuid)
+            == Some(pallet_subnet_emission_api::SubnetConsensus::Yuma)
+    }
+
+    /// Computes the next stage of the epoch. Returns the emissions distributed once the last
+    /// stage commits the results.
+    ///
+    /// The stages compute from the subnet state read when the epoch started, so the results are
+    /// the ones the epoch would have had if computed at once. The emission of modules deregistered
+    /// in the meantime is not distributed and no longer drained.
+    pub fn step(&mut self) -> Result<Option<EmissionMap<T>>, EmissionError> {
+        if self.stage == YumaStage::Commit {
+            let (emissions, undistributed) =
+                self.epoch.commit(core::mem::take(&mut self.values))?;
+            self.emission_to_drain = self.emission_to_drain.saturating_sub(undistributed);
+            return Ok(Some(emissions));
+        }
+
+        self.epoch.compute_stage(self.stage, &mut self.values)?;
+        self.stage = self.stage.next();
+        Ok(None)
+    }
+}
+
+impl<T: Config + pallet::Config> YumaEpoch<T> {
     pub fn new(netuid: u16, to_be_emitted: u64) -> Self {
         let validator_permits = ValidatorPermits::<T>::get(netuid);
         let validator_forbids = validator_permits.iter().map(|&b| !b).collect();
@@ -53,7 +190,7 @@ impl<T: Config> YumaEpoch<T> {
             netuid,
             kappa: Pallet::<T>::get_float_kappa(),
 
-            founder_key: AccountKey(founder_key),
+            founder_key,
             founder_emission,
             to_be_emitted,
 
@@ -65,8 +202,10 @@ impl<T: Config> YumaEpoch<T> {
             validator_forbids,
             validator_permits,
             max_allowed_validators: MaxAllowedValidators::<T>::get(netuid),
+            bonds_moving_average: BondsMovingAverage::<T>::get(netuid),
 
-            _pd: Default::default(),
+            keys: Keys::<T>::iter_prefix(netuid).collect(),
+            snapshot: false,
         }
     }
 
@@ -81,21 +220,97 @@ impl<T: Config> YumaEpoch<T> {
         );
         log::trace!("yuma for netuid {} parameters: {self:?}", self.netuid);
 
-        let (inactive, active): (Vec<_>, Vec<_>) = self
+        let mut values = YumaValues::default();
+        let mut stage = YumaStage::Prepare;
+        while stage != YumaStage::Commit {
+            self.compute_stage(stage, &mut values)?;
+            stage = stage.next();
+        }
+
+        self.commit(values).map(|(emissions, _)| emissions)
+    }
+
+    /// Computes a single stage of the epoch, reading its inputs from and writing its outputs to
+    /// `values`.
+    fn compute_stage(
+        &self,
+        stage: YumaStage,
+        values: &mut YumaValues,
+    ) -> Result<(), EmissionError> {
+        match stage {
+            YumaStage::Prepare => self.prepare(values)?,
+            YumaStage::Consensus => {
+                let mut weights = self
+                    .compute_weights()
+                    .ok_or(EmissionError::Other("weights storage is broken"))?;
+                let active_stake = self.values_active_stake(values);
+
+                let ConsensusAndTrust {
+                    consensus,
+                    validator_trust,
+                    preranks,
+                } = self.compute_consensus_and_trust(&mut weights, &active_stake);
+
+                values.consensus = consensus.into_inner();
+                values.validator_trust =
+                    validator_trust.into_inner().into_iter().map(fixed_proportion_to_u16).collect();
+                values.preranks = preranks.into_inner();
+            }
+            YumaStage::Incentives => {
+                let weights = self.compute_consensus_weights(&values.consensus)?;
+                let active_stake = self.values_active_stake(values);
+                let preranks =
+                    Preranks::unchecked_from_inner(core::mem::take(&mut values.preranks));
+
+                let IncentivesAndTrust {
+                    incentives,
+                    ranks,
+                    trust,
+                } = self.compute_incentive_and_trust(&weights, &active_stake, &preranks);
+
+                values.incentives = incentives.into_inner();
+                values.ranks =
+                    ranks.into_inner().into_iter().map(fixed_proportion_to_u16).collect();
+                values.trust =
+                    trust.into_inner().into_iter().map(fixed_proportion_to_u16).collect();
+            }
+            YumaStage::Commit => {}
+        }
+
+        Ok(())
+    }
+
+    /// The stake of the active validators, derived from the stake and activity of `values`.
+    fn values_active_stake(&self, values: &YumaValues) -> ActiveStake {
+        let inactive: Vec<bool> = values.active.iter().map(|active| !active).collect();
+        self.compute_active_stake(
+            &inactive,
+            &StakeVal::unchecked_from_inner(values.stake.clone()),
+        )
+    }
+
+    /// The weights clipped at the majority consensus, as the consensus stage leaves them.
+    fn compute_consensus_weights(&self, consensus: &[I32F32]) -> Result<WeightsVal, EmissionError> {
+        let weights = self
+            .compute_weights()
+            .ok_or(EmissionError::Other("weights storage is broken"))?;
+        Ok(WeightsVal::unchecked_from_inner(col_clip_sparse(
+            weights.as_ref(),
+            consensus,
+        )))
+    }
+
+    /// Computes the activity, the stake and the new validator permits.
+    fn prepare(&self, values: &mut YumaValues) -> Result<(), EmissionError> {
+        let active: Vec<_> = self
             .last_update
             .iter()
             .zip(&self.block_at_registration)
             .map(|(updated, block_at_registration)| {
-                let is_inactive = *updated <= *block_at_registration
-                    || updated.saturating_add(self.activity_cutoff) < self.current_block;
-                (is_inactive, !is_inactive)
+                *updated > *block_at_registration
+                    && updated.saturating_add(self.activity_cutoff) >= self.current_block
             })
-            .unzip();
-
-        let mut weights = self
-            .compute_weights()
-            .ok_or(EmissionError::Other("weights storage is broken"))?;
-        log::trace!("final weights: {weights:?}");
+            .collect();
 
         let stake = self.compute_stake()?;
         log::trace!("final stake: {stake:?}");
@@ -117,7 +332,6 @@ impl<T: Config> YumaEpoch<T> {
         sorted_indexed_stake.sort_by_key(|(_idx, stake)| *stake);
         sorted_indexed_stake.reverse();
 
-        let current_block = PalletSubspace::<T>::get_current_block_number();
         let min_stake = pallet_subspace::MinValidatorStake::<T>::get(self.netuid);
         let mut validator_count = 0;
         for (idx, stake) in sorted_indexed_stake {
@@ -131,7 +345,7 @@ impl<T: Config> YumaEpoch<T> {
 
             match pallet_subspace::WeightSetAt::<T>::get(self.netuid, idx) {
                 Some(weight_block) => {
-                    if current_block.saturating_sub(weight_block) > 7200 {
+                    if self.current_block.saturating_sub(weight_block) > 7200 {
                         continue;
                     }
                 }
@@ -145,20 +359,35 @@ impl<T: Config> YumaEpoch<T> {
         }
 
         log::trace!("new permis: {new_permits:?}");
-        let active_stake = self.compute_active_stake(&inactive, &stake);
-        log::trace!("final active stake: {active_stake:?}");
 
-        let ConsensusAndTrust {
+        values.active = active;
+        values.stake = stake.into_inner();
+        values.new_permits = new_permits;
+
+        Ok(())
+    }
+
+    /// Computes the bonds and dividends, writes the consensus values of the epoch and distributes
+    /// its emission. Returns the emissions distributed and the emission of the modules
+    /// deregistered since the epoch started, left undistributed.
+    fn commit(&self, values: YumaValues) -> Result<(EmissionMap<T>, u64), EmissionError> {
+        let weights = self.compute_consensus_weights(&values.consensus)?;
+        let active_stake = self.values_active_stake(&values);
+
+        let YumaValues {
+            active,
+            stake,
+            new_permits,
             consensus,
             validator_trust,
-            preranks,
-        } = self.compute_consensus_and_trust(&mut weights, &active_stake);
-
-        let IncentivesAndTrust {
             incentives,
             ranks,
             trust,
-        } = self.compute_incentive_and_trust(&weights, &active_stake, &preranks);
+            ..
+        } = values;
+
+        let stake = StakeVal::unchecked_from_inner(stake);
+        let incentives = IncentivesVal::unchecked_from_inner(incentives);
 
         let BondsAndDividends {
             ema_bonds,
@@ -174,28 +403,32 @@ impl<T: Config> YumaEpoch<T> {
             combined_emissions,
         } = self.compute_emissions(&stake, &active_stake, &incentives, &dividends);
 
-        let consensus: Vec<_> =
-            consensus.into_inner().into_iter().map(fixed_proportion_to_u16).collect();
+        let consensus: Vec<_> = consensus.into_iter().map(fixed_proportion_to_u16).collect();
         let incentives: Vec<_> =
             incentives.into_inner().into_iter().map(fixed_proportion_to_u16).collect();
         let dividends: Vec<_> =
             dividends.into_inner().into_iter().map(fixed_proportion_to_u16).collect();
-        let trust: Vec<_> = trust.into_inner().into_iter().map(fixed_proportion_to_u16).collect();
-        let ranks: Vec<_> = ranks.into_inner().into_iter().map(fixed_proportion_to_u16).collect();
         let pruning_scores = vec_max_upscale_to_u16(pruning_scores.as_ref());
-        let validator_trust: Vec<_> =
-            validator_trust.into_inner().into_iter().map(fixed_proportion_to_u16).collect();
-
-        Active::<T>::insert(self.netuid, active);
-        Consensus::<T>::insert(self.netuid, consensus);
-        Dividends::<T>::insert(self.netuid, dividends);
-        Emission::<T>::insert(self.netuid, combined_emissions);
-        Incentive::<T>::insert(self.netuid, incentives);
-        PruningScores::<T>::insert(self.netuid, pruning_scores);
-        Rank::<T>::insert(self.netuid, ranks);
-        Trust::<T>::insert(self.netuid, trust);
-        ValidatorPermits::<T>::insert(self.netuid, &new_permits);
-        ValidatorTrust::<T>::insert(self.netuid, validator_trust);
+
+        // Modules registered or deregistered while a multi-block epoch was computed moved the
+        // uids, the values follow the module keys onto the current ones
+        let layout = self.current_layout();
+        let new_uids: BTreeMap<u16, u16> = layout
+            .iter()
+            .enumerate()
+            .filter_map(|(uid, old_uid)| Some(((*old_uid)?, uid as u16)))
+            .collect();
+
+        Active::<T>::insert(self.netuid, remap(&layout, &active));
+        Consensus::<T>::insert(self.netuid, remap(&layout, &consensus));
+        Dividends::<T>::insert(self.netuid, remap(&layout, &dividends));
+        Emission::<T>::insert(self.netuid, remap(&layout, &combined_emissions));
+        Incentive::<T>::insert(self.netuid, remap(&layout, &incentives));
+        PruningScores::<T>::insert(self.netuid, remap(&layout, &pruning_scores));
+        Rank::<T>::insert(self.netuid, remap(&layout, &ranks));
+        Trust::<T>::insert(self.netuid, remap(&layout, &trust));
+        ValidatorPermits::<T>::insert(self.netuid, remap(&layout, &new_permits));
+        ValidatorTrust::<T>::insert(self.netuid, remap(&layout, &validator_trust));
 
         ensure!(
             new_permits.len() == self.module_count as usize,
@@ -210,7 +443,8 @@ impl<T: Config> YumaEpoch<T> {
             "unequal number of bonds and modules"
         );
 
-        for i in 0..self.module_count as usize {
+        for (&i, &uid) in &new_uids {
+            let i = i as usize;
             // Set bonds only if uid retains validator permit, otherwise clear bonds.
             if *new_permits.get(i).unwrap_or(&false) {
                 let new_bonds_row: Vec<(u16, u16)> = ema_bonds
@@ -218,28 +452,39 @@ impl<T: Config> YumaEpoch<T> {
                     .map(|bonds_row| {
                         bonds_row
                             .iter()
-                            .map(|(j, value)| (*j, fixed_proportion_to_u16(*value)))
+                            .filter_map(|(j, value)| {
+                                Some((*new_uids.get(j)?, fixed_proportion_to_u16(*value)))
+                            })
                             .collect()
                     })
                     .unwrap_or_default();
-                Bonds::<T>::insert(self.netuid, i as u16, new_bonds_row);
+                Bonds::<T>::insert(self.netuid, uid, new_bonds_row);
             } else if self.max_allowed_validators.is_none()
                 || *self.validator_permits.get(i).unwrap_or(&false)
             {
                 // Only overwrite the intersection.
                 let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
-                Bonds::<T>::insert(self.netuid, i as u16, new_empty_bonds_row);
+                Bonds::<T>::insert(self.netuid, uid, new_empty_bonds_row);
             }
         }
 
         // Emission tuples ( key, server_emission, validator_emission )
         let mut result: Vec<(ModuleKey<T>, u64, u64)> = vec![];
-        for (uid_i, module_key) in Keys::<T>::iter_prefix(self.netuid) {
-            result.push((
-                ModuleKey(module_key),
-                *server_emissions.get(uid_i as usize).unwrap_or(&0),
-                *validator_emissions.get(uid_i as usize).unwrap_or(&0),
-            ));
+        let mut undistributed: u64 = 0;
+        for (uid_i, module_key) in &self.keys {
+            let server_emission = *server_emissions.get(*uid_i as usize).unwrap_or(&0);
+            let validator_emission = *validator_emissions.get(*uid_i as usize).unwrap_or(&0);
+            if new_uids.contains_key(uid_i) {
+                result.push((
+                    ModuleKey(module_key.clone()),
+                    server_emission,
+                    validator_emission,
+                ));
+            } else {
+                undistributed = undistributed
+                    .saturating_add(server_emission)
+                    .saturating_add(validator_emission);
+            }
         }
 
         let distribute_emissions = self.distribute_emissions(result);
@@ -248,7 +493,18 @@ impl<T: Config> YumaEpoch<T> {
             self.netuid
         );
 
-        distribute_emissions
+        Ok((distribute_emissions?, undistributed))
+    }
+
+    /// The uid each module currently on the subnet had when the epoch started, by current uid.
+    /// Modules registered since then have none.
+    fn current_layout(&self) -> Vec<Option<u16>> {
+        let old_uids: BTreeMap<&T::AccountId, u16> =
+            self.keys.iter().map(|(uid, key)| (key, *uid)).collect();
+
+        (0..N::<T>::get(self.netuid))
+            .map(|uid| Keys::<T>::get(self.netuid, uid).and_then(|key| old_uids.get(&key).copied()))
+            .collect()
     }
 
     fn distribute_emissions(
@@ -257,20 +513,27 @@ impl<T: Config> YumaEpoch<T> {
     ) -> Result<EmissionMap<T>, EmissionError> {
         let mut emissions: EmissionMap<T> = Default::default();
         let mut emitted: u64 = 0;
+        let mut payouts: BTreeMap<(T::AccountId, T::AccountId), u64> = BTreeMap::new();
+        let mut fees: BTreeMap<(T::AccountId, T::AccountId), u64> = BTreeMap::new();
 
         if self.founder_emission > 0 {
             match PalletSubspace::<T>::u64_to_balance(self.founder_emission) {
                 Some(balance) => {
-                    PalletSubspace::<T>::add_balance_to_account(&self.founder_key.0, balance);
+                    PalletSubspace::<T>::add_balance_to_account(&self.founder_key, balance);
                 }
                 None => return Err(EmissionError::BalanceConversionFailed),
             }
             emitted = emitted.saturating_add(self.founder_emission);
         }
 
-        for (module_key, server_emission, mut validator_emission) in result {
-            let mut increase_stake = |account_key: &AccountKey<T>, amount: u64| {
-                PalletSubspace::<T>::increase_stake(&account_key.0, &module_key.0, amount);
+        for (module_key, mut server_emission, mut validator_emission) in result {
+            let mut pay_reward = |account_key: &AccountKey<T>, amount: u64| {
+                if let Some(destination) =
+                    PalletSubspace::<T>::pay_reward(&account_key.0, &module_key.0, amount)
+                {
+                    let paid = payouts.entry((account_key.0.clone(), destination)).or_default();
+                    *paid = paid.saturating_add(amount);
+                }
 
                 let stake = emissions
                     .entry(module_key.clone())
@@ -301,7 +564,10 @@ impl<T: Config> YumaEpoch<T> {
                     let to_module: u64 = delegation_fee.mul_floor(dividends_from_delegate);
                     let to_delegate: u64 = dividends_from_delegate.saturating_sub(to_module);
 
-                    increase_stake(&AccountKey(delegate_key), to_delegate);
+                    let fee = fees.entry((module_key.0.clone(), delegate_key.clone())).or_default();
+                    *fee = fee.saturating_add(to_module);
+
+                    pay_reward(&AccountKey(delegate_key), to_delegate);
 
                     validator_emission = validator_emission
                         .checked_sub(to_delegate)
@@ -309,9 +575,40 @@ impl<T: Config> YumaEpoch<T> {
                 }
             }
 
+            // The whole incentive goes to the module unless it shares it with its stakers
+            let miner_delegation_fee = PalletSubspace::<T>::get_miner_delegation_fee(&module_key.0);
+            if server_emission > 0 && miner_delegation_fee < Percent::from_percent(100) {
+                let ownership_vector =
+                    PalletSubspace::<T>::get_ownership_ratios(self.netuid, &module_key.0);
+
+                let total_server_emission = I64F64::from_num(server_emission);
+                for (delegate_key, delegate_ratio) in ownership_vector {
+                    if delegate_key == module_key.0 {
+                        continue;
+                    }
+
+                    let incentive_from_delegate: u64 = total_server_emission
+                        .checked_mul(delegate_ratio)
+                        .unwrap_or_default()
+                        .to_num::<u64>();
+
+                    let to_module: u64 = miner_delegation_fee.mul_floor(incentive_from_delegate);
+                    let to_delegate: u64 = incentive_from_delegate.saturating_sub(to_module);
+
+                    let fee = fees.entry((module_key.0.clone(), delegate_key.clone())).or_default();
+                    *fee = fee.saturating_add(to_module);
+
+                    pay_reward(&AccountKey(delegate_key), to_delegate);
+
+                    server_emission = server_emission
+                        .checked_sub(to_delegate)
+                        .ok_or("more server emissions were done than expected")?;
+                }
+            }
+
             let remaining_emission = server_emission.saturating_add(validator_emission);
             if remaining_emission > 0 {
-                increase_stake(&AccountKey(module_key.0.clone()), remaining_emission);
+                pay_reward(&AccountKey(module_key.0.clone()), remaining_emission);
             }
         }
 
@@ -325,12 +622,35 @@ impl<T: Config> YumaEpoch<T> {
 
         log::trace!("emitted {emitted} tokens in total");
 
+        PalletSubspace::<T>::deposit_reward_payouts(self.netuid, payouts);
+
+        let breakdown = emissions
+            .iter()
+            .flat_map(|(module_key, stakers)| {
+                stakers.iter().map(move |(staker, amount)| {
+                    ((module_key.0.clone(), staker.0.clone()), *amount)
+                })
+            })
+            .map(|(pair, amount)| {
+                let fee = fees.get(&pair).copied().unwrap_or_default();
+                (pair, (amount, fee))
+            })
+            .collect();
+        Pallet::<T>::record_epoch_emission(self.netuid, self.founder_emission, breakdown);
+
         Ok(emissions)
     }
 
     fn compute_weights(&self) -> Option<WeightsVal> {
         // Access network weights row unnormalized.
-        let mut weights = Pallet::<T>::get_weights_sparse(self.netuid)?;
+        let mut weights = if self.snapshot {
+            Pallet::<T>::get_sparse_rows(
+                self.module_count,
+                YumaEpochWeights::<T>::iter_prefix(self.netuid),
+            )?
+        } else {
+            Pallet::<T>::get_sparse_rows(self.module_count, Weights::<T>::iter_prefix(self.netuid))?
+        };
         log::trace!("  original weights: {weights:?}");
 
         if self.max_allowed_validators.is_some() {
@@ -466,7 +786,14 @@ impl<T: Config> YumaEpoch<T> {
         incentives: &IncentivesVal,
     ) -> Option<BondsAndDividends> {
         // Access network bonds.
-        let mut bonds = Pallet::<T>::get_bonds_sparse(self.netuid)?;
+        let mut bonds = if self.snapshot {
+            Pallet::<T>::get_sparse_rows(
+                self.module_count,
+                YumaEpochBonds::<T>::iter_prefix(self.netuid),
+            )?
+        } else {
+            Pallet::<T>::get_sparse_rows(self.module_count, Bonds::<T>::iter_prefix(self.netuid))?
+        };
         log::trace!("  original bonds: {bonds:?}");
 
         // Remove bonds referring to deregistered modules.
@@ -491,7 +818,7 @@ impl<T: Config> YumaEpoch<T> {
         log::trace!("  normalized bonds delta: {bonds_delta:?}");
 
         // Compute bonds moving average.
-        let bonds_moving_average = I64F64::from_num(BondsMovingAverage::<T>::get(self.netuid))
+        let bonds_moving_average = I64F64::from_num(self.bonds_moving_average)
             .checked_div(I64F64::from_num(1_000_000))
             .unwrap_or_default();
         log::trace!("  bonds moving average: {bonds_moving_average}");
@@ -690,34 +1017,53 @@ struct Emissions {
     combined_emissions: Vec<u64>,
 }
 
-impl<T: Config> Pallet<T> {
+impl<T: Config + pallet::Config> Pallet<T> {
     pub fn get_float_kappa() -> I32F32 {
         I32F32::from_num(Kappa::<T>::get())
             .checked_div(I32F32::from_num(u16::MAX))
             .unwrap_or_default()
     }
 
-    fn get_weights_sparse(netuid: u16) -> Option<Vec<Vec<(u16, I32F32)>>> {
-        let n = N::<T>::get(netuid) as usize;
-        let mut weights: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
-        for (uid_i, weights_i) in
-            Weights::<T>::iter_prefix(netuid).filter(|(uid, _)| *uid < n as u16)
-        {
-            for (uid_j, weight_ij) in weights_i.into_iter().filter(|(uid, _)| *uid < n as u16) {
-                weights.get_mut(uid_i as usize)?.push((uid_j, I32F32::from_num(weight_ij)));
+    /// Reads the weights or bonds rows of a subnet with `n` modules as a sparse matrix.
+    fn get_sparse_rows(
+        n: u16,
+        rows: impl Iterator<Item = (u16, Vec<(u16, u16)>)>,
+    ) -> Option<Vec<Vec<(u16, I32F32)>>> {
+        let mut matrix: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n as usize];
+        for (uid_i, row_i) in rows.filter(|(uid, _)| *uid < n) {
+            for (uid_j, value_ij) in row_i.into_iter().filter(|(uid, _)| *uid < n) {
+                matrix.get_mut(uid_i as usize)?.push((uid_j, I32F32::from_num(value_ij)));
             }
         }
-        Some(weights)
+        Some(matrix)
     }
 
-    fn get_bonds_sparse(netuid: u16) -> Option<Vec<Vec<(u16, I32F32)>>> {
-        let n: usize = N::<T>::get(netuid) as usize;
-        let mut bonds: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
-        for (uid_i, bonds_i) in Bonds::<T>::iter_prefix(netuid).filter(|(uid, _)| *uid < n as u16) {
-            for (uid_j, bonds_ij) in bonds_i.into_iter().filter(|(uid, _)| *uid < n as u16) {
-                bonds.get_mut(uid_i as usize)?.push((uid_j, I32F32::from_num(bonds_ij)));
-            }
+    /// Copies the weights and bonds rows a multi-block epoch over `module_count` modules reads.
+    fn snapshot_yuma_matrices(netuid: u16, module_count: u16) {
+        for (uid, row) in Weights::<T>::iter_prefix(netuid).filter(|(uid, _)| *uid < module_count) {
+            YumaEpochWeights::<T>::insert(netuid, uid, row);
         }
-        Some(bonds)
+        for (uid, row) in Bonds::<T>::iter_prefix(netuid).filter(|(uid, _)| *uid < module_count) {
+            YumaEpochBonds::<T>::insert(netuid, uid, row);
+        }
+    }
+
+    /// Removes the weights and bonds copied for the multi-block epoch of a subnet.
+    pub(crate) fn clear_yuma_snapshot(netuid: u16, module_count: u16) {
+        let _ = YumaEpochWeights::<T>::clear_prefix(netuid, module_count.into(), None);
+        let _ = YumaEpochBonds::<T>::clear_prefix(netuid, module_count.into(), None);
     }
 }
+
+/// Lays out per-module values computed for the uids the epoch started with onto the current uids.
+fn remap<V: Clone + Default>(layout: &[Option<u16>], values: &[V]) -> Vec<V> {
+    layout
+        .iter()
+        .map(|old_uid| {
+            old_uid
+                .and_then(|old_uid| values.get(old_uid as usize))
+                .cloned()
+                .unwrap_or_default()
+        })
+        .collect()
+}
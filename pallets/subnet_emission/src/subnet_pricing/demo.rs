@@ -1,5 +1,5 @@
 // This file acts as a "proof of concept" for the onchain subnet-pricing mechanism.
-// It is not meant to be used as one of the production pricing mechanisms.
+// It backs the `Equal` pricing mechanism, which splits the emission evenly between subnets.
 
 // SUBNET PRICING MECHANISM
 // -------------------------
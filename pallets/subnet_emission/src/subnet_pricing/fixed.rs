@@ -0,0 +1,40 @@
+use crate::{Config, Pallet, PricedSubnets};
+use core::marker::PhantomData;
+
+use sp_std::vec::Vec;
+
+/// Prices subnets following a fixed `(netuid, share)` table decided by governance. Subnets
+/// missing from the table are priced at zero, and entries for subnets that do not exist are
+/// ignored.
+pub struct FixedPricing<T: Config> {
+    table: Vec<(u16, u32)>,
+    to_be_emitted: u64,
+    _pd: PhantomData<T>,
+}
+
+impl<T: Config> FixedPricing<T> {
+    pub fn new(table: Vec<(u16, u32)>, to_be_emitted: u64) -> Self {
+        Self {
+            table,
+            to_be_emitted,
+            _pd: PhantomData,
+        }
+    }
+
+    pub fn run(self) -> Result<PricedSubnets, sp_runtime::DispatchError> {
+        let mut shares: PricedSubnets =
+            pallet_subspace::N::<T>::iter_keys().map(|netuid| (netuid, 0)).collect();
+
+        for (netuid, share) in self.table {
+            if let Some(subnet_share) = shares.get_mut(&netuid) {
+                *subnet_share = u64::from(share);
+            }
+        }
+
+        if shares.values().all(|share| *share == 0) {
+            return Err("No subnet in the fixed allocation table exists.".into());
+        }
+
+        Ok(Pallet::<T>::split_emission(self.to_be_emitted, &shares))
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Config, Pallet, PricedSubnets, SubnetStakes, STAKE_PRICING_INTERVAL};
+use core::marker::PhantomData;
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+/// Prices every subnet proportionally to the stake delegated to the modules registered on it.
+pub struct StakePricing<T: Config> {
+    to_be_emitted: u64,
+    _pd: PhantomData<T>,
+}
+
+impl<T: Config> StakePricing<T> {
+    pub fn new(to_be_emitted: u64) -> Self {
+        Self {
+            to_be_emitted,
+            _pd: PhantomData,
+        }
+    }
+
+    pub fn run(self) -> Result<PricedSubnets, sp_runtime::DispatchError> {
+        let block = pallet_subspace::Pallet::<T>::get_current_block_number();
+        let subnet_stakes = match SubnetStakes::<T>::get() {
+            Some((computed_at, mut subnet_stakes))
+                if block.saturating_sub(computed_at) < STAKE_PRICING_INTERVAL =>
+            {
+                // Subnets removed since then must not be priced
+                subnet_stakes.retain(|netuid, _| pallet_subspace::N::<T>::contains_key(netuid));
+                subnet_stakes
+            }
+            _ => {
+                let subnet_stakes = Self::compute_subnet_stakes();
+                SubnetStakes::<T>::put((block, subnet_stakes.clone()));
+                subnet_stakes
+            }
+        };
+
+        if subnet_stakes.values().all(|stake| *stake == 0) {
+            return Err("No stake on any subnet.".into());
+        }
+
+        Ok(Pallet::<T>::split_emission(
+            self.to_be_emitted,
+            &subnet_stakes,
+        ))
+    }
+
+    /// Sums the stake delegated to the modules of every subnet. A key registered on several
+    /// subnets is counted once, its stake being split evenly between them.
+    fn compute_subnet_stakes() -> PricedSubnets {
+        let mut key_subnets: BTreeMap<T::AccountId, Vec<u16>> = BTreeMap::new();
+        for (netuid, _, key) in pallet_subspace::Keys::<T>::iter() {
+            key_subnets.entry(key).or_default().push(netuid);
+        }
+
+        let mut subnet_stakes: PricedSubnets =
+            pallet_subspace::N::<T>::iter_keys().map(|netuid| (netuid, 0)).collect();
+        for (key, netuids) in key_subnets {
+            let stake = pallet_subspace::Pallet::<T>::get_delegated_stake(&key)
+                .checked_div(netuids.len() as u64)
+                .unwrap_or_default();
+            for netuid in netuids {
+                if let Some(subnet_stake) = subnet_stakes.get_mut(&netuid) {
+                    *subnet_stake = subnet_stake.saturating_add(stake);
+                }
+            }
+        }
+
+        subnet_stakes
+    }
+}
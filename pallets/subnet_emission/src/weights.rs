@@ -0,0 +1,173 @@
+//! Weights for `pallet_subnet_emission`
+//!
+//! These weights were estimated by hand and have not been benchmarked yet. They have to be
+//! generated with the command below before a release.
+
+// Command:
+// ./target/release/node-subspace
+// benchmark
+// pallet
+// --chain
+// specs/benchmarks.json
+// --pallet
+// pallet_subnet_emission
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// pallets/subnet_emission/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_subnet_emission`.
+pub trait WeightInfo {
+	fn process_emission_distribution(s: u32, m: u32, ) -> Weight;
+	fn record_epoch_emission(e: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_subnet_emission` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `SubnetEmissionModule::SubnetPricing` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetPricing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::EpochQueue` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::EpochQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::MaxEpochsPerBlock` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::MaxEpochsPerBlock` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:257 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TotalStake` (r:1 w:0)
+	/// Proof: `SubspaceModule::TotalStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetEmission` (r:256 w:256)
+	/// Proof: `SubnetEmissionModule::SubnetEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:256 w:256)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Tempo` (r:256 w:0)
+	/// Proof: `SubspaceModule::Tempo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:256 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:420 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Weights` (r:420 w:0)
+	/// Proof: `SubspaceModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:420 w:420)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeTo` (r:420 w:420)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Incentive` (r:256 w:256)
+	/// Proof: `SubspaceModule::Incentive` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Dividends` (r:256 w:256)
+	/// Proof: `SubspaceModule::Dividends` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Emission` (r:256 w:256)
+	/// Proof: `SubspaceModule::Emission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochWeights` (r:420 w:420)
+	/// Proof: `SubnetEmissionModule::YumaEpochWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochBonds` (r:420 w:420)
+	/// Proof: `SubnetEmissionModule::YumaEpochBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 256]`.
+	/// The range of component `m` is `[1, 420]`.
+	fn process_emission_distribution(s: u32, m: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(22_000_000, 3977)
+			.saturating_add(Weight::from_parts(9_741_062, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(1_186_524, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().reads((11_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2631).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 4471).saturating_mul(m.into()))
+	}
+	/// Storage: `SubspaceModule::RecordEpochEmission` (r:1 w:0)
+	/// Proof: `SubspaceModule::RecordEpochEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::LastEpochEmission` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::LastEpochEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `e` is `[1, 256]`.
+	fn record_epoch_emission(e: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(9_000_000, 3517)
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `SubnetEmissionModule::SubnetPricing` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetPricing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::EpochQueue` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::EpochQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::MaxEpochsPerBlock` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::MaxEpochsPerBlock` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:257 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TotalStake` (r:1 w:0)
+	/// Proof: `SubspaceModule::TotalStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetEmission` (r:256 w:256)
+	/// Proof: `SubnetEmissionModule::SubnetEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:256 w:256)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Tempo` (r:256 w:0)
+	/// Proof: `SubspaceModule::Tempo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:256 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:420 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Weights` (r:420 w:0)
+	/// Proof: `SubspaceModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:420 w:420)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeTo` (r:420 w:420)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Incentive` (r:256 w:256)
+	/// Proof: `SubspaceModule::Incentive` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Dividends` (r:256 w:256)
+	/// Proof: `SubspaceModule::Dividends` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Emission` (r:256 w:256)
+	/// Proof: `SubspaceModule::Emission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochWeights` (r:420 w:420)
+	/// Proof: `SubnetEmissionModule::YumaEpochWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochBonds` (r:420 w:420)
+	/// Proof: `SubnetEmissionModule::YumaEpochBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 256]`.
+	/// The range of component `m` is `[1, 420]`.
+	fn process_emission_distribution(s: u32, m: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(22_000_000, 3977)
+			.saturating_add(Weight::from_parts(9_741_062, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(1_186_524, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().reads((11_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2631).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 4471).saturating_mul(m.into()))
+	}
+	/// Storage: `SubspaceModule::RecordEpochEmission` (r:1 w:0)
+	/// Proof: `SubspaceModule::RecordEpochEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::LastEpochEmission` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::LastEpochEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `e` is `[1, 256]`.
+	fn record_epoch_emission(e: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(9_000_000, 3517)
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
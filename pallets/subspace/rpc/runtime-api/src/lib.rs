@@ -30,18 +30,174 @@ pub struct ModuleStats {
 pub struct ModuleParams {
     pub name: Vec<u8>,
     pub address: Vec<u8>,
-    pub delegation_fee: Percent, // delegate_fee
+    pub delegation_fee: Percent,       // taken from dividends
+    pub miner_delegation_fee: Percent, // taken from incentive
     pub controller: AccountId,
 }
 
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct DelegationFeeChange {
+    pub fee: Percent,
+    pub effective_block: u64,
+}
+
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
 pub struct ModuleInfo {
     pub params: ModuleParams,
     pub stats: ModuleStats,
+    /// Announced delegation fee increase that is not in effect yet.
+    pub pending_delegation_fee: Option<DelegationFeeChange>,
+    /// Announced miner delegation fee increase that is not in effect yet.
+    pub pending_miner_delegation_fee: Option<DelegationFeeChange>,
+}
+
+/// Module params as returned by version 1 of the API, before the miner delegation fee.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct ModuleParamsV1 {
+    pub name: Vec<u8>,
+    pub address: Vec<u8>,
+    pub delegation_fee: Percent,
+    pub controller: AccountId,
+}
+
+/// Module info as returned by version 1 of the API.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct ModuleInfoV1 {
+    pub params: ModuleParamsV1,
+    pub stats: ModuleStats,
+}
+
+impl From<ModuleInfoV1> for ModuleInfo {
+    /// The single fee of version 1 was taken from both dividends and incentive.
+    fn from(info: ModuleInfoV1) -> Self {
+        Self {
+            params: ModuleParams {
+                name: info.params.name,
+                address: info.params.address,
+                delegation_fee: info.params.delegation_fee,
+                miner_delegation_fee: info.params.delegation_fee,
+                controller: info.params.controller,
+            },
+            stats: info.stats,
+            pending_delegation_fee: None,
+            pending_miner_delegation_fee: None,
+        }
+    }
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct PendingUnlock {
+    pub module_key: AccountId,
+    pub amount: u64,
+    pub unlock_block: u64,
+    /// Module the amount is staked on once unlocked, if it was transferred.
+    pub restake_to: Option<AccountId>,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct MetagraphModule {
+    pub uid: u16,
+    pub key: AccountId,
+    pub name: Vec<u8>,
+    pub address: Vec<u8>,
+    /// Total stake delegated to the module, including its own.
+    pub stake: u64,
+    pub incentive: u16,
+    pub dividends: u16,
+    pub trust: u16,
+    pub rank: u16,
+    pub consensus: u16,
+    pub validator_permit: bool,
+    pub last_update: u64,
+    pub emission: u64,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct SubnetMetagraph {
+    pub netuid: u16,
+    pub modules: Vec<MetagraphModule>, // ordered by uid
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct ModuleEmission {
+    pub module_key: AccountId,
+    pub staker: AccountId,
+    pub amount: u64,
+    /// Delegation fee kept by the module out of the staker's share.
+    pub fee: u64,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct EpochEmission {
+    pub netuid: u16,
+    pub block: u64,
+    pub founder_emission: u64,
+    pub entries: Vec<ModuleEmission>, // largest emissions first
+    /// Whether smaller emissions were left out of the record.
+    pub truncated: bool,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, TypeInfo, Serialize, Deserialize)]
+pub enum VoteMode {
+    Authority,
+    Vote,
+}
+
+/// The subnet parameters changed by a scheduled update. Missing ones are left as they are.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct SubnetParamsChanges {
+    pub founder: Option<AccountId>,
+    pub founder_share: Option<u16>,
+    pub immunity_period: Option<u16>,
+    pub incentive_ratio: Option<u16>,
+    pub max_allowed_uids: Option<u16>,
+    pub max_allowed_weights: Option<u16>,
+    pub min_allowed_weights: Option<u16>,
+    pub max_weight_age: Option<u64>,
+    pub name: Option<Vec<u8>>,
+    pub metadata: Option<Option<Vec<u8>>>,
+    pub tempo: Option<u16>,
+    pub trust_ratio: Option<u16>,
+    pub maximum_set_weight_calls_per_epoch: Option<u16>,
+    pub vote_mode: Option<VoteMode>,
+    pub bonds_ma: Option<u64>,
+    pub target_registrations_interval: Option<u16>,
+    pub target_registrations_per_interval: Option<u16>,
+    pub max_registrations_per_interval: Option<u16>,
+    pub adjustment_alpha: Option<u64>,
+    pub min_validator_stake: Option<u64>,
+    pub unbonding_period: Option<Option<u64>>,
+    pub commit_reveal_weights: Option<bool>,
+    pub record_epoch_emission: Option<bool>,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct ScheduledSubnetUpdate {
+    pub netuid: u16,
+    pub at_block: u64,
+    pub changes: SubnetParamsChanges,
 }
 
 sp_api::decl_runtime_apis! {
+    /// Version 2 changed the `ModuleInfo` layout and added every method after
+    /// `get_module_info`. Clients have to check the version the runtime implements before calling
+    /// them.
     pub trait SubspaceRuntimeApi {
+        #[changed_in(2)]
+        fn get_module_info(key: AccountId, netuid: u16) -> ModuleInfoV1;
+
         fn get_module_info(key: AccountId, netuid: u16) -> ModuleInfo;
+
+        #[api_version(2)]
+        fn get_pending_unlocks(key: AccountId) -> Vec<PendingUnlock>;
+
+        #[api_version(2)]
+        fn get_subnet_metagraph(netuid: u16) -> SubnetMetagraph;
+
+        #[api_version(2)]
+        fn get_last_epoch_emission(netuid: u16) -> Option<EpochEmission>;
+
+        #[api_version(2)]
+        fn get_scheduled_subnet_update(netuid: u16) -> Option<ScheduledSubnetUpdate>;
     }
 }
@@ -1,17 +1,19 @@
 use jsonrpsee::{
-    core::{ClientError as JsonRpseeError, RpcResult},
+    core::RpcResult,
     proc_macros::rpc,
-    types::error::ErrorObject,
+    types::error::{ErrorObject, ErrorObjectOwned},
 };
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
     traits::{Block as BlockT, IdentifyAccount, Verify},
     MultiSignature,
 };
 use std::sync::Arc;
-use subspace_runtime_api::ModuleInfo;
 pub use subspace_runtime_api::SubspaceRuntimeApi;
+use subspace_runtime_api::{
+    EpochEmission, ModuleInfo, PendingUnlock, ScheduledSubnetUpdate, SubnetMetagraph,
+};
 
 type Signature = MultiSignature;
 type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
@@ -30,6 +32,34 @@ pub trait SubspaceApi<BlockHash> {
         netuid: u16,
         at: Option<BlockHash>,
     ) -> RpcResult<ModuleInfo>;
+
+    #[method(name = "subspace_getPendingUnlocks")]
+    fn get_pending_unlocks(
+        &self,
+        key: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<PendingUnlock>>;
+
+    #[method(name = "subspace_getSubnetMetagraph")]
+    fn get_subnet_metagraph(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<SubnetMetagraph>;
+
+    #[method(name = "subspace_getLastEpochEmission")]
+    fn get_last_epoch_emission(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<EpochEmission>>;
+
+    #[method(name = "subspace_getScheduledSubnetUpdate")]
+    fn get_scheduled_subnet_update(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ScheduledSubnetUpdate>>;
 }
 
 pub struct SubspacePallet<C, Block> {
@@ -46,6 +76,32 @@ impl<C, Block> SubspacePallet<C, Block> {
     }
 }
 
+impl<C, Block> SubspacePallet<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+    C::Api: SubspaceRuntimeApi<Block>,
+{
+    /// Returns the version of the runtime API implemented at the block.
+    fn api_version(&self, at: <Block as BlockT>::Hash) -> RpcResult<u32> {
+        let version = self
+            .client
+            .runtime_api()
+            .api_version::<dyn SubspaceRuntimeApi<Block>>(at)
+            .map_err(runtime_error_into_rpc_err)?;
+        version.ok_or_else(|| unsupported_method_error(0))
+    }
+
+    /// Fails when the runtime at the block does not implement the version a method was added in.
+    fn ensure_api_version(&self, at: <Block as BlockT>::Hash, required: u32) -> RpcResult<()> {
+        let version = self.api_version(at)?;
+        if version < required {
+            return Err(unsupported_method_error(version));
+        }
+        Ok(())
+    }
+}
+
 impl<C, Block> SubspaceApiServer<<Block as BlockT>::Hash> for SubspacePallet<C, Block>
 where
     Block: BlockT,
@@ -61,18 +117,83 @@ where
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
 
-        let value = api.get_module_info(at, key, netuid).map_err(runtime_error_into_rpc_err);
-        Ok(value.unwrap())
+        #[allow(deprecated)]
+        let value = if self.api_version(at)? < 2 {
+            api.get_module_info_before_version_2(at, key, netuid).map(ModuleInfo::from)
+        } else {
+            api.get_module_info(at, key, netuid)
+        };
+        value.map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_pending_unlocks(
+        &self,
+        key: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<PendingUnlock>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.ensure_api_version(at, 2)?;
+
+        api.get_pending_unlocks(at, key).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_subnet_metagraph(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<SubnetMetagraph> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.ensure_api_version(at, 2)?;
+
+        api.get_subnet_metagraph(at, netuid).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_last_epoch_emission(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<EpochEmission>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.ensure_api_version(at, 2)?;
+
+        api.get_last_epoch_emission(at, netuid).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_scheduled_subnet_update(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ScheduledSubnetUpdate>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.ensure_api_version(at, 2)?;
+
+        api.get_scheduled_subnet_update(at, netuid).map_err(runtime_error_into_rpc_err)
     }
 }
 
 const RUNTIME_ERROR: i32 = 1;
+const UNSUPPORTED_METHOD_ERROR: i32 = 2;
 
 /// Converts a runtime trap into an RPC error.
-fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
-    JsonRpseeError::Call(ErrorObject::owned(
-        RUNTIME_ERROR,
-        "Runtime error",
-        Some(format!("{:?}", err)),
-    ))
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Runtime error", Some(format!("{:?}", err)))
+}
+
+/// The runtime at the requested block is older than the method.
+fn unsupported_method_error(api_version: u32) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        UNSUPPORTED_METHOD_ERROR,
+        "Method not supported by the runtime at this block",
+        Some(format!(
+            "runtime implements version {api_version} of the API"
+        )),
+    )
 }
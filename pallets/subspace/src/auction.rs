@@ -0,0 +1,140 @@
+use super::*;
+use frame_support::pallet_prelude::MaxEncodedLen;
+use sp_runtime::{traits::Zero, BoundedVec, DispatchError};
+use sp_std::vec::Vec;
+
+// ---------------------------------
+// Subnet Slot Auctions
+// ---------------------------------
+
+/// The highest bid of the subnet slot auction. The bid amount is reserved from the bidder until
+/// it is outbid or the auction closes.
+#[derive(
+    Clone, TypeInfo, Decode, Encode, PartialEq, Eq, frame_support::DebugNoBound, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct SubnetSlotBid<T: Config> {
+    pub bidder: T::AccountId,
+    pub amount: u64,
+    /// name of the subnet created if the bid wins
+    pub name: BoundedVec<u8, ConstU32<256>>,
+    pub metadata: Option<BoundedVec<u8, ConstU32<59>>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Places a bid on the subnet slot auction, opening one if there is none. Bids are only
+    /// accepted when auctions are enabled and `MaxAllowedSubnets` is reached.
+    pub fn do_bid_subnet_slot(
+        origin: T::RuntimeOrigin,
+        name: Vec<u8>,
+        metadata: Option<Vec<u8>>,
+        amount: u64,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let config = SubnetAuctionConfig::<T>::get();
+        ensure!(config.enabled, Error::<T>::SubnetAuctionDisabled);
+        ensure!(
+            Self::get_total_subnets() >= MaxAllowedSubnets::<T>::get(),
+            Error::<T>::SubnetSlotsAvailable
+        );
+        ensure!(amount >= config.min_bid, Error::<T>::SubnetBidTooLow);
+
+        let name: BoundedVec<u8, ConstU32<256>> =
+            name.try_into().map_err(|_| Error::<T>::SubnetNameTooLong)?;
+        let metadata: Option<BoundedVec<u8, ConstU32<59>>> = match metadata {
+            Some(metadata) => {
+                Some(metadata.try_into().map_err(|_| Error::<T>::InvalidSubnetMetadata)?)
+            }
+            None => None,
+        };
+        SubnetChangeset::<T>::new(SubnetParams {
+            name: name.clone(),
+            metadata: metadata.clone(),
+            founder: key.clone(),
+            ..DefaultSubnetParams::<T>::get()
+        })?;
+
+        let current_block = Self::get_current_block_number();
+        let end_block = match SubnetSlotAuction::<T>::get() {
+            Some((end_block, highest_bid)) => {
+                ensure!(amount > highest_bid.amount, Error::<T>::SubnetBidTooLow);
+                T::Currency::unreserve(
+                    &highest_bid.bidder,
+                    Self::u64_to_balance(highest_bid.amount)
+                        .ok_or(Error::<T>::CouldNotConvertToBalance)?,
+                );
+                end_block
+            }
+            None => {
+                let end_block = current_block.saturating_add(config.duration);
+                Self::deposit_event(Event::SubnetSlotAuctionStarted(end_block));
+                end_block
+            }
+        };
+
+        T::Currency::reserve(
+            &key,
+            Self::u64_to_balance(amount).ok_or(Error::<T>::CouldNotConvertToBalance)?,
+        )
+        .map_err(|_| Error::<T>::NotEnoughBalanceToBid)?;
+
+        let bid = SubnetSlotBid {
+            bidder: key.clone(),
+            amount,
+            name,
+            metadata,
+        };
+        SubnetSlotAuction::<T>::set(Some((end_block, bid)));
+
+        Self::deposit_event(Event::SubnetSlotBidPlaced(key, amount));
+        Ok(())
+    }
+
+    /// Closes the subnet slot auction once its end block is reached. The highest bid is burned
+    /// and its subnet takes the place of the lowest emission subnet. If the subnet can't be
+    /// created anymore, the bid is refunded. Returns the weight consumed.
+    pub(crate) fn close_subnet_slot_auction(block_number: u64) -> Weight {
+        let Some((end_block, bid)) = SubnetSlotAuction::<T>::get() else {
+            return Weight::zero();
+        };
+        if end_block > block_number {
+            return Weight::zero();
+        }
+
+        SubnetSlotAuction::<T>::kill();
+
+        let result = frame_support::storage::with_storage_layer(|| {
+            let params = SubnetParams {
+                name: bid.name.clone(),
+                metadata: bid.metadata.clone(),
+                founder: bid.bidder.clone(),
+                ..DefaultSubnetParams::<T>::get()
+            };
+            let changeset = SubnetChangeset::new(params)?;
+            let netuid = Self::add_subnet_from_registration(changeset)?;
+
+            let amount =
+                Self::u64_to_balance(bid.amount).ok_or(Error::<T>::CouldNotConvertToBalance)?;
+            let (_, not_slashed) = T::Currency::slash_reserved(&bid.bidder, amount);
+            ensure!(not_slashed.is_zero(), Error::<T>::NotEnoughBalanceToBid);
+
+            Ok::<_, DispatchError>(netuid)
+        });
+
+        match result {
+            Ok(netuid) => {
+                Self::deposit_event(Event::SubnetSlotAuctionWon(netuid, bid.bidder, bid.amount))
+            }
+            Err(err) => {
+                log::warn!("subnet slot auction could not be settled: {err:?}");
+                if let Some(amount) = Self::u64_to_balance(bid.amount) {
+                    T::Currency::unreserve(&bid.bidder, amount);
+                }
+                Self::deposit_event(Event::SubnetSlotAuctionFailed(bid.bidder, bid.amount));
+            }
+        }
+
+        T::WeightInfo::close_subnet_slot_auction()
+    }
+}
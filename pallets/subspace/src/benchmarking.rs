@@ -178,7 +178,7 @@ benchmarks! {
         let caller: T::AccountId = account("Alice", 0, 1);
         register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
         let netuid = SubspaceMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
-    }: deregister(RawOrigin::Signed(caller), netuid)
+    }: deregister(RawOrigin::Signed(caller.clone()), netuid, caller)
 
     // ---------------------------------
     // Updating
@@ -192,9 +192,10 @@ benchmarks! {
         let name = "updated_name".as_bytes().to_vec();
         let address = "updated_address".as_bytes().to_vec();
         let delegation_fee = Some(Percent::from_percent(5));
+        let miner_delegation_fee = Some(Percent::from_percent(50));
         let metadata = Some("updated_metadata".as_bytes().to_vec());
         let netuid = SubspaceMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
-    }: update_module(RawOrigin::Signed(caller), netuid, name, address, delegation_fee, metadata)
+    }: update_module(RawOrigin::Signed(caller.clone()), netuid, caller, name, address, delegation_fee, miner_delegation_fee, metadata)
 
 
    // 10
@@ -225,7 +226,10 @@ benchmarks! {
         params.target_registrations_per_interval,
         params.max_registrations_per_interval,
         params.adjustment_alpha,
-        params.min_immunity_stake
+        params.min_immunity_stake,
+        params.unbonding_period,
+        params.commit_reveal_weights,
+        params.record_epoch_emission
     )
     // 11
     delegate_rootnet_control {
@@ -238,4 +242,257 @@ benchmarks! {
         let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
         T::set_subnet_consensus_type(netuid, Some(SubnetConsensus::Root));
     }: delegate_rootnet_control(RawOrigin::Signed(module_key), module_key2)
+
+    // 12
+    withdraw_unbonded {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        register_mock::<T>(module_key.clone(), module_key.clone(), "test".as_bytes().to_vec())?;
+
+        // The worst case withdraws a full queue, half of it transferred stake to restake
+        let amount = 1000000000u64;
+        let unlocks: Vec<_> = (0..MAX_PENDING_UNLOCKS)
+            .map(|i| PendingUnlock::<T> {
+                module_key: module_key.clone(),
+                amount,
+                unlock_block: 0,
+                restake_to: (i % 2 == 0).then(|| module_key.clone()),
+            })
+            .collect();
+        PendingUnlocks::<T>::insert(&caller, unlocks);
+        TotalUnbonding::<T>::set(amount * MAX_PENDING_UNLOCKS as u64);
+    }: withdraw_unbonded(RawOrigin::Signed(caller))
+
+    // 13
+    commit_weights {
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        let module_key2: T::AccountId = account("ModuleKey2", 0, 3);
+
+        register_mock::<T>(module_key.clone(), module_key.clone(), "test".as_bytes().to_vec())?;
+        register_mock::<T>(module_key2.clone(), module_key2.clone(), "test1".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        CommitRevealWeights::<T>::insert(netuid, true);
+        let commit_hash = SubspaceMod::<T>::hash_weights(&module_key2, netuid, &[0], &[10], b"salt");
+    }: commit_weights(RawOrigin::Signed(module_key2), netuid, commit_hash)
+
+    // 14
+    reveal_weights {
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        let module_key2: T::AccountId = account("ModuleKey2", 0, 3);
+
+        register_mock::<T>(module_key.clone(), module_key.clone(), "test".as_bytes().to_vec())?;
+        register_mock::<T>(module_key2.clone(), module_key2.clone(), "test1".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        CommitRevealWeights::<T>::insert(netuid, true);
+        let commit_hash = SubspaceMod::<T>::hash_weights(&module_key2, netuid, &[0], &[10], b"salt");
+        SubspaceMod::<T>::commit_weights(
+            RawOrigin::Signed(module_key2.clone()).into(),
+            netuid,
+            commit_hash,
+        )?;
+
+        // Commits are revealed on the next epoch
+        let next_epoch_block = SubspaceMod::<T>::get_current_block_number()
+            + u64::from(Tempo::<T>::get(netuid));
+        frame_system::Pallet::<T>::set_block_number((next_epoch_block as u32).into());
+    }: reveal_weights(RawOrigin::Signed(module_key2), netuid, vec![0], vec![10], b"salt".to_vec())
+
+    // 15
+    set_payout_preference {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let destination: T::AccountId = account("Bob", 0, 2);
+    }: set_payout_preference(RawOrigin::Signed(caller.clone()), caller, PayoutPreference::PayToAccount(destination))
+
+    // 16
+    rotate_module_key {
+        let s in 1 .. 256;
+        let t in 2 .. 256;
+        let d in 0 .. 256;
+
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        let new_key: T::AccountId = account("NewKey", 0, 3);
+
+        register_mock::<T>(module_key.clone(), module_key.clone(), "test".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
+
+        // Every subnet is looked up for the module
+        for i in 1..s {
+            N::<T>::insert(netuid.saturating_add(i as u16), 0);
+        }
+
+        // The module stakes to itself, which takes two of the stake entries
+        for i in 2..t {
+            let staker: T::AccountId = account("Staker", i, 1);
+            SubspaceMod::<T>::increase_stake(&staker, &module_key, 100000000000000u64);
+        }
+
+        for i in 0..d {
+            let delegator: T::AccountId = account("Delegator", i, 0);
+            RootnetControlDelegation::<T>::insert(&delegator, &module_key);
+            RootnetControlDelegators::<T>::insert(&module_key, &delegator, ());
+        }
+
+        let witness = KeyRotationWitness {
+            subnets: N::<T>::iter_keys().count() as u32,
+            stakes: t,
+            rootnet_delegators: d,
+            governance_entries: T::module_key_rotation_entries(&module_key, u32::MAX),
+        };
+    }: rotate_module_key(RawOrigin::Signed(module_key.clone()), netuid, module_key, new_key, witness)
+
+    // 17
+    set_module_controller {
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        let controller: T::AccountId = account("Controller", 0, 3);
+
+        register_mock::<T>(module_key.clone(), module_key.clone(), "test".as_bytes().to_vec())?;
+    }: set_module_controller(RawOrigin::Signed(module_key.clone()), module_key, Some(controller))
+
+    // 18
+    propose_subnet_ownership {
+        let founder: T::AccountId = account("Alice", 0, 1);
+        let new_founder: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(founder.clone(), founder.clone(), "test".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
+    }: propose_subnet_ownership(RawOrigin::Signed(founder), netuid, new_founder)
+
+    // 19
+    accept_subnet_ownership {
+        let founder: T::AccountId = account("Alice", 0, 1);
+        let new_founder: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(founder.clone(), founder.clone(), "test".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
+        SubspaceMod::<T>::propose_subnet_ownership(
+            RawOrigin::Signed(founder).into(),
+            netuid,
+            new_founder.clone(),
+        )?;
+    }: accept_subnet_ownership(RawOrigin::Signed(new_founder), netuid)
+
+    // 21
+    schedule_subnet_update {
+        let founder: T::AccountId = account("Alice", 0, 1);
+        register_mock::<T>(founder.clone(), founder.clone(), "test".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
+        let patch = SubnetParamsPatch {
+            tempo: Some(200),
+            ..Default::default()
+        };
+    }: schedule_subnet_update(RawOrigin::Signed(founder), netuid, patch, 100)
+
+    // 22
+    cancel_scheduled_subnet_update {
+        let founder: T::AccountId = account("Alice", 0, 1);
+        register_mock::<T>(founder.clone(), founder.clone(), "test".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
+        SubspaceMod::<T>::schedule_subnet_update(
+            RawOrigin::Signed(founder.clone()).into(),
+            netuid,
+            SubnetParamsPatch::default(),
+            100,
+        )?;
+    }: cancel_scheduled_subnet_update(RawOrigin::Signed(founder), netuid)
+
+    // 23
+    bid_subnet_slot {
+        let founder: T::AccountId = account("Alice", 0, 1);
+        let bidder: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(founder.clone(), founder.clone(), "test".as_bytes().to_vec())?;
+        MaxAllowedSubnets::<T>::set(SubspaceMod::<T>::get_total_subnets());
+        SubnetAuctionConfig::<T>::mutate(|config| config.enabled = true);
+
+        let amount = SubnetAuctionConfig::<T>::get().min_bid;
+        SubspaceMod::<T>::add_balance_to_account(
+            &bidder,
+            SubspaceMod::<T>::u64_to_balance(amount.saturating_mul(2)).unwrap(),
+        );
+    }: bid_subnet_slot(RawOrigin::Signed(bidder), b"auctioned".to_vec(), None, amount)
+
+    // 24
+    cancel_subnet_ownership_offer {
+        let founder: T::AccountId = account("Alice", 0, 1);
+        let new_founder: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(founder.clone(), founder.clone(), "test".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
+        SubspaceMod::<T>::propose_subnet_ownership(
+            RawOrigin::Signed(founder.clone()).into(),
+            netuid,
+            new_founder,
+        )?;
+    }: cancel_subnet_ownership_offer(RawOrigin::Signed(founder), netuid)
+
+    // ---------------------------------
+    // Hooks
+    // ---------------------------------
+
+    adjust_registration_parameters {
+        let s in 1 .. 256;
+
+        for netuid in 0..s {
+            Tempo::<T>::insert(netuid as u16, 100);
+        }
+    }: {
+        // Every burn interval is reached on block 0
+        SubspaceMod::<T>::adjust_registration_parameters(0)
+    }
+
+    copy_delegated_weights {
+        use pallet_subnet_emission_api::SubnetConsensus;
+        let d in 0 .. 256;
+        let w in 1 .. 256;
+
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        register_mock::<T>(module_key.clone(), module_key.clone(), "test".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
+        T::set_subnet_consensus_type(netuid, Some(SubnetConsensus::Root));
+        Weights::<T>::insert(netuid, 0, (0..w as u16).map(|uid| (uid, 1)).collect::<Vec<_>>());
+
+        for i in 0..d {
+            let delegator: T::AccountId = account("Delegator", i, 0);
+            Uids::<T>::insert(netuid, &delegator, (i as u16).saturating_add(1));
+            RootnetControlDelegation::<T>::insert(&delegator, &module_key);
+        }
+    }: {
+        SubspaceMod::<T>::copy_delegated_weights(0)
+    }
+
+    apply_scheduled_subnet_update {
+        let founder: T::AccountId = account("Alice", 0, 1);
+        register_mock::<T>(founder.clone(), founder.clone(), "test".as_bytes().to_vec())?;
+        let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").unwrap();
+        SubspaceMod::<T>::schedule_subnet_update(
+            RawOrigin::Signed(founder).into(),
+            netuid,
+            SubnetParamsPatch {
+                tempo: Some(200),
+                ..Default::default()
+            },
+            100,
+        )?;
+    }: {
+        SubspaceMod::<T>::apply_scheduled_subnet_update(netuid, 100)
+    }
+
+    close_subnet_slot_auction {
+        let founder: T::AccountId = account("Alice", 0, 1);
+        let bidder: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(founder.clone(), founder.clone(), "test".as_bytes().to_vec())?;
+        MaxAllowedSubnets::<T>::set(SubspaceMod::<T>::get_total_subnets());
+        SubnetAuctionConfig::<T>::mutate(|config| config.enabled = true);
+
+        let amount = SubnetAuctionConfig::<T>::get().min_bid;
+        SubspaceMod::<T>::add_balance_to_account(
+            &bidder,
+            SubspaceMod::<T>::u64_to_balance(amount.saturating_mul(2)).unwrap(),
+        );
+        SubspaceMod::<T>::bid_subnet_slot(
+            RawOrigin::Signed(bidder).into(),
+            b"auctioned".to_vec(),
+            None,
+            amount,
+        )?;
+        let end_block = SubnetSlotAuction::<T>::get().unwrap().0;
+    }: {
+        SubspaceMod::<T>::close_subnet_slot_auction(end_block)
+    }
 }
@@ -1,5 +1,7 @@
 use super::*;
 use frame_support::pallet_prelude::{DispatchResult, MaxEncodedLen};
+use pallet_governance_api::GovernanceConfigurationPatch;
+use sp_arithmetic::per_things::Percent;
 use sp_core::Get;
 use sp_runtime::DispatchError;
 
@@ -40,6 +42,32 @@ pub struct SubnetBurnConfiguration<T> {
     pub _pd: PhantomData<T>,
 }
 
+#[derive(
+    Clone, TypeInfo, Decode, Encode, PartialEq, Eq, frame_support::DebugNoBound, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct SubnetAuctionConfiguration<T> {
+    /// whether subnet slots are auctioned once `MaxAllowedSubnets` is reached, instead of
+    /// evicting the lowest emission subnet on registration
+    pub enabled: bool,
+    /// number of blocks the auction accepts bids for, counted from the first bid
+    pub duration: u64,
+    /// the lowest bid accepted
+    pub min_bid: u64,
+    pub _pd: PhantomData<T>,
+}
+
+impl<T: Config> Default for SubnetAuctionConfiguration<T> {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration: 7_200,
+            min_bid: 2_000_000_000_000,
+            _pd: PhantomData,
+        }
+    }
+}
+
 impl<T: Config> Default for BurnConfiguration<T> {
     fn default() -> Self {
         Self {
@@ -77,6 +105,157 @@ impl<T: Config> BurnConfiguration<T> {
     }
 }
 
+/// A partial update of the burn configuration. Fields left as `None` keep their current value.
+#[derive(
+    Clone,
+    TypeInfo,
+    Decode,
+    Encode,
+    PartialEq,
+    Eq,
+    frame_support::DebugNoBound,
+    frame_support::DefaultNoBound,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct BurnConfigurationPatch<T> {
+    pub min_burn: Option<u64>,
+    pub max_burn: Option<u64>,
+    pub _pd: PhantomData<T>,
+}
+
+impl<T: Config> BurnConfigurationPatch<T> {
+    /// Returns the configuration with the fields set in the patch replaced.
+    #[must_use]
+    pub fn merge(self, mut config: BurnConfiguration<T>) -> BurnConfiguration<T> {
+        if let Some(min_burn) = self.min_burn {
+            config.min_burn = min_burn;
+        }
+        if let Some(max_burn) = self.max_burn {
+            config.max_burn = max_burn;
+        }
+
+        config
+    }
+}
+
+/// A partial update of the subnet auction configuration. Fields left as `None` keep their
+/// current value.
+#[derive(
+    Clone,
+    TypeInfo,
+    Decode,
+    Encode,
+    PartialEq,
+    Eq,
+    frame_support::DebugNoBound,
+    frame_support::DefaultNoBound,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct SubnetAuctionConfigurationPatch<T> {
+    pub enabled: Option<bool>,
+    pub duration: Option<u64>,
+    pub min_bid: Option<u64>,
+    pub _pd: PhantomData<T>,
+}
+
+impl<T: Config> SubnetAuctionConfigurationPatch<T> {
+    /// Returns the configuration with the fields set in the patch replaced.
+    #[must_use]
+    pub fn merge(self, mut config: SubnetAuctionConfiguration<T>) -> SubnetAuctionConfiguration<T> {
+        if let Some(enabled) = self.enabled {
+            config.enabled = enabled;
+        }
+        if let Some(duration) = self.duration {
+            config.duration = duration;
+        }
+        if let Some(min_bid) = self.min_bid {
+            config.min_bid = min_bid;
+        }
+
+        config
+    }
+}
+
+/// A partial update of the global params. Fields left as `None` keep their current value.
+#[derive(
+    Clone,
+    TypeInfo,
+    Decode,
+    Encode,
+    PartialEq,
+    Eq,
+    frame_support::DebugNoBound,
+    frame_support::DefaultNoBound,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct GlobalParamsPatch<T: Config> {
+    pub max_name_length: Option<u16>,
+    pub min_name_length: Option<u16>,
+    pub max_allowed_subnets: Option<u16>,
+    pub max_allowed_modules: Option<u16>,
+    pub max_registrations_per_block: Option<u16>,
+    pub max_allowed_weights: Option<u16>,
+    pub max_burn: Option<u64>,
+    pub min_burn: Option<u64>,
+    pub floor_delegation_fee: Option<Percent>,
+    pub floor_founder_share: Option<u8>,
+    pub min_weight_stake: Option<u64>,
+    pub curator: Option<T::AccountId>,
+    pub general_subnet_application_cost: Option<u64>,
+    pub kappa: Option<u16>,
+    pub rho: Option<u16>,
+    pub subnet_immunity_period: Option<u64>,
+    pub unbonding_period: Option<u64>,
+    pub delegation_fee_notice_period: Option<u64>,
+    pub burn_config: BurnConfigurationPatch<T>,
+    pub subnet_auction_config: SubnetAuctionConfigurationPatch<T>,
+    pub governance_config: GovernanceConfigurationPatch,
+}
+
+impl<T: Config> GlobalParamsPatch<T> {
+    /// Returns the params with the fields set in the patch replaced.
+    #[must_use]
+    pub fn merge(self, mut params: GlobalParams<T>) -> GlobalParams<T> {
+        macro_rules! merge {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if let Some(value) = self.$field {
+                        params.$field = value;
+                    }
+                )*
+            };
+        }
+
+        merge!(
+            max_name_length,
+            min_name_length,
+            max_allowed_subnets,
+            max_allowed_modules,
+            max_registrations_per_block,
+            max_allowed_weights,
+            floor_delegation_fee,
+            floor_founder_share,
+            min_weight_stake,
+            curator,
+            general_subnet_application_cost,
+            kappa,
+            rho,
+            subnet_immunity_period,
+            unbonding_period,
+            delegation_fee_notice_period,
+        );
+        params.burn_config = self.burn_config.merge(params.burn_config);
+        params.subnet_auction_config =
+            self.subnet_auction_config.merge(params.subnet_auction_config);
+        params.governance_config = self.governance_config.merge(params.governance_config);
+
+        params
+    }
+}
+
 impl<T: Config> Pallet<T> {
     pub fn global_params() -> GlobalParams<T> {
         GlobalParams {
@@ -91,6 +270,7 @@ impl<T: Config> Pallet<T> {
             // burn & registrations
             max_registrations_per_block: MaxRegistrationsPerBlock::<T>::get(),
             burn_config: BurnConfig::<T>::get(),
+            subnet_auction_config: SubnetAuctionConfig::<T>::get(),
             // weights
             max_allowed_weights: MaxAllowedWeightsGlobal::<T>::get(),
             min_weight_stake: MinWeightStake::<T>::get(),
@@ -101,6 +281,10 @@ impl<T: Config> Pallet<T> {
             kappa: Kappa::<T>::get(),
             rho: Rho::<T>::get(),
 
+            // staking
+            unbonding_period: UnbondingPeriod::<T>::get(),
+            delegation_fee_notice_period: DelegationFeeNoticePeriod::<T>::get(),
+
             governance_config: T::get_global_governance_configuration(),
         }
     }
@@ -135,11 +319,18 @@ impl<T: Config> Pallet<T> {
         // burn
         params.burn_config.apply()?;
 
+        // subnet slot auctions, an open auction keeps its end block
+        SubnetAuctionConfig::<T>::set(params.subnet_auction_config);
+
         // Update the general subnet application cost
         T::set_general_subnet_application_cost(params.general_subnet_application_cost);
         Kappa::<T>::set(params.kappa);
         Rho::<T>::set(params.rho);
 
+        // staking
+        UnbondingPeriod::<T>::set(params.unbonding_period);
+        DelegationFeeNoticePeriod::<T>::set(params.delegation_fee_notice_period);
+
         Ok(())
     }
 
@@ -194,6 +385,21 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InvalidProposalExpiration
         );
 
+        ensure!(
+            params.unbonding_period <= MAX_UNBONDING_PERIOD,
+            Error::<T>::InvalidUnbondingPeriod
+        );
+
+        ensure!(
+            params.delegation_fee_notice_period <= MAX_DELEGATION_FEE_NOTICE_PERIOD,
+            Error::<T>::InvalidDelegationFeeNoticePeriod
+        );
+
+        ensure!(
+            params.subnet_auction_config.duration > 0 && params.subnet_auction_config.min_bid > 0,
+            Error::<T>::InvalidSubnetAuctionConfig
+        );
+
         Ok(())
     }
 }
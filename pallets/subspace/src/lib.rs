@@ -2,7 +2,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "512"]
 
-use crate::subnet::SubnetChangeset;
+use crate::subnet::{SubnetChangeset, SubnetParamsPatch};
 use frame_system::{self as system, ensure_signed};
 pub use pallet::*;
 use scale_info::TypeInfo;
@@ -15,6 +15,7 @@ use frame_support::{
     ensure,
     traits::{
         tokens::WithdrawReasons, ConstU16, ConstU32, Currency, ExistenceRequirement, IsSubType,
+        ReservableCurrency,
     },
     PalletId,
 };
@@ -38,6 +39,7 @@ mod benchmarking;
 // Pallet Imports
 // ---------------------------------
 
+pub mod auction;
 pub mod global;
 pub mod math;
 pub mod module;
@@ -59,20 +61,21 @@ pub mod pallet {
 
     use super::*;
     pub use crate::weights::WeightInfo;
+    use auction::SubnetSlotBid;
     use frame_support::{
         pallet_prelude::{ValueQuery, *},
         traits::Currency,
         Identity,
     };
     use frame_system::pallet_prelude::*;
-    use global::{BurnConfiguration, SubnetBurnConfiguration};
+    use global::{BurnConfiguration, SubnetAuctionConfiguration, SubnetBurnConfiguration};
     use module::ModuleChangeset;
     use pallet_governance_api::{GovernanceConfiguration, VoteMode};
     use sp_arithmetic::per_things::Percent;
     use sp_core::{ConstU64, ConstU8};
     pub use sp_std::{vec, vec::Vec};
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(13);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(14);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -95,7 +98,7 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// Currency type that will be used to place deposits on modules.
-        type Currency: Currency<Self::AccountId> + Send + Sync;
+        type Currency: ReservableCurrency<Self::AccountId> + Send + Sync;
 
         /// The default number of modules that can be registered per interval.
         type DefaultMaxRegistrationsPerInterval: Get<u16>;
@@ -229,7 +232,10 @@ pub mod pallet {
 
         // Other
         pub subnet_immunity_period: u64,
+        pub unbonding_period: u64, // blocks removed stake stays locked
+        pub delegation_fee_notice_period: u64, // blocks a delegation fee increase waits
         pub burn_config: BurnConfiguration<T>,
+        pub subnet_auction_config: SubnetAuctionConfiguration<T>,
         pub governance_config: GovernanceConfiguration,
 
         pub kappa: u16,
@@ -275,6 +281,49 @@ pub mod pallet {
     #[pallet::storage] // --- ITEM  ( total_stake )
     pub type TotalStake<T> = StorageValue<_, u64, ValueQuery>;
 
+    #[derive(Decode, Encode, PartialEq, Eq, Clone, TypeInfo, frame_support::DebugNoBound)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PendingUnlock<T: Config> {
+        pub module_key: T::AccountId, // module the stake was removed from
+        pub amount: u64,
+        pub unlock_block: u64, // block from which the amount can be withdrawn
+        pub restake_to: Option<T::AccountId>, // module transferred stake is staked on once unlocked
+    }
+
+    /// Upper bound for the unbonding period, roughly 30 days of blocks.
+    pub const MAX_UNBONDING_PERIOD: u64 = 324_000;
+    /// Maximum number of unlock chunks a staker can have pending at once.
+    pub const MAX_PENDING_UNLOCKS: usize = 32;
+
+    #[pallet::storage] // --- ITEM ( unbonding_period )
+    pub type UnbondingPeriod<T> = StorageValue<_, u64, ValueQuery, ConstU64<0>>;
+
+    #[pallet::storage] // --- MAP ( netuid ) --> unbonding_period
+    pub type SubnetUnbondingPeriod<T> = StorageMap<_, Identity, u16, u64>;
+
+    #[pallet::storage] // --- MAP ( staker ) --> pending_unlocks
+    pub type PendingUnlocks<T: Config> =
+        StorageMap<_, Identity, T::AccountId, Vec<PendingUnlock<T>>, ValueQuery>;
+
+    #[pallet::storage] // --- ITEM ( total_unbonding )
+    pub type TotalUnbonding<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// Where a staker's emission rewards go at the end of each epoch.
+    #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, Default, TypeInfo, MaxEncodedLen)]
+    pub enum PayoutPreference<AccountId> {
+        /// Rewards are added to the stake they were earned on.
+        #[default]
+        Compound,
+        /// Rewards are paid as free balance to the staker.
+        PayToBalance,
+        /// Rewards are paid as free balance to the given account.
+        PayToAccount(AccountId),
+    }
+
+    #[pallet::storage] // --- MAP ( staker ) --> payout_preference
+    pub type PayoutPreferences<T: Config> =
+        StorageMap<_, Identity, T::AccountId, PayoutPreference<T::AccountId>, ValueQuery>;
+
     // ---------------------------------
     // Subnets
     // ---------------------------------
@@ -298,6 +347,16 @@ pub mod pallet {
     pub type Founder<T: Config> =
         StorageMap<_, Identity, u16, T::AccountId, ValueQuery, DefaultKey<T>>;
 
+    /// Number of blocks a subnet ownership offer can be accepted for, roughly 7 days of blocks.
+    pub const SUBNET_OWNERSHIP_OFFER_PERIOD: u64 = 75_600;
+
+    #[pallet::storage] // --- MAP ( netuid ) --> (new_founder, expiration_block)
+    pub type PendingSubnetOwnership<T: Config> = StorageMap<_, Identity, u16, (T::AccountId, u64)>;
+
+    #[pallet::storage] // --- MAP ( netuid ) --> (at_block, patch)
+    pub type ScheduledSubnetUpdates<T: Config> =
+        StorageMap<_, Identity, u16, (u64, SubnetParamsPatch<T>)>;
+
     #[pallet::storage] // --- DMAP ( key, netuid ) --> bool
     pub type IncentiveRatio<T: Config> =
         StorageMap<_, Identity, u16, u16, ValueQuery, ConstU16<50>>;
@@ -312,6 +371,13 @@ pub mod pallet {
     #[pallet::storage] // ITEM ( subnet_max_registrations_per_interval )
     pub type SubnetRegistrationsThisInterval<T: Config> = StorageValue<_, u16, ValueQuery>;
 
+    #[pallet::storage] // ITEM ( subnet_auction_config )
+    pub type SubnetAuctionConfig<T: Config> =
+        StorageValue<_, SubnetAuctionConfiguration<T>, ValueQuery>;
+
+    #[pallet::storage] // ITEM ( (end_block, highest_bid) )
+    pub type SubnetSlotAuction<T: Config> = StorageValue<_, (u64, SubnetSlotBid<T>)>;
+
     #[pallet::type_value]
     pub fn DefaultSubnetBurn<T: Config>() -> u64 {
         SubnetBurnConfig::<T>::get().min_burn
@@ -367,6 +433,9 @@ pub mod pallet {
                     ..Default::default()
                 },
                 metadata: None,
+                unbonding_period: None,
+                commit_reveal_weights: false,
+                record_epoch_emission: false,
             }
         }
     }
@@ -401,6 +470,12 @@ pub mod pallet {
         pub adjustment_alpha: u64,
         pub min_validator_stake: u64,
         pub governance_config: GovernanceConfiguration,
+        // staking
+        pub unbonding_period: Option<u64>, // overrides the global unbonding period
+        // weights
+        pub commit_reveal_weights: bool, // weights are committed and revealed an epoch later
+        // emission
+        pub record_epoch_emission: bool, // keeps the breakdown of the last epoch on-chain
     }
 
     #[pallet::storage] // --- MAP ( netuid ) --> max_allowed_uids
@@ -450,10 +525,25 @@ pub mod pallet {
         pub name: Vec<u8>,
         pub address: Vec<u8>,
         pub delegation_fee: Percent,
+        pub miner_delegation_fee: Percent,
         pub metadata: Option<Vec<u8>>,
         pub controller: T::AccountId,
     }
 
+    /// Upper bounds of what a module key rotation moves, declared by the caller so the rotation
+    /// is charged for them up front. The rotation fails if the key holds more.
+    #[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, Default, TypeInfo)]
+    pub struct KeyRotationWitness {
+        /// Subnets registered on the chain.
+        pub subnets: u32,
+        /// Stake entries of the key, staked to it and by it.
+        pub stakes: u32,
+        /// Keys delegating their rootnet control to the key.
+        pub rootnet_delegators: u32,
+        /// Governance entries of the key, counted by the governance pallet.
+        pub governance_entries: u32,
+    }
+
     #[pallet::storage] // --- DMAP ( netuid, module_key ) --> uid
     pub type Uids<T: Config> =
         StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, u16>;
@@ -509,6 +599,31 @@ pub mod pallet {
     #[pallet::storage]
     pub type WeightSetAt<T: Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, u64>;
 
+    #[pallet::storage] // --- MAP ( netuid ) --> commit_reveal_weights
+    pub type CommitRevealWeights<T> = StorageMap<_, Identity, u16, bool, ValueQuery>;
+
+    /// Maximum number of uids whose plaintext weights are cleared per subnet and block.
+    pub const PLAINTEXT_WEIGHTS_CLEARED_PER_BLOCK: u16 = 128;
+
+    /// Subnets that enabled commit-reveal while holding weights set in plaintext, with the block
+    /// it was enabled at and the uid the clearing continues below.
+    #[pallet::storage] // --- MAP ( netuid ) --> (enabled_at, next_uid)
+    pub type PlaintextWeightsClearing<T> = StorageMap<_, Identity, u16, (u64, u16)>;
+
+    #[pallet::storage] // --- MAP ( netuid ) --> record_epoch_emission
+    pub type RecordEpochEmission<T> = StorageMap<_, Identity, u16, bool, ValueQuery>;
+
+    #[derive(Decode, Encode, PartialEq, Eq, Clone, TypeInfo, frame_support::DebugNoBound)]
+    #[scale_info(skip_type_params(T))]
+    pub struct WeightCommit<T: Config> {
+        pub hash: T::Hash,
+        pub epoch: u64, // epoch the commit was made in, revealed on the next one
+    }
+
+    #[pallet::storage] // --- DMAP ( netuid, key ) --> weight_commit
+    pub type WeightCommits<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, T::AccountId, WeightCommit<T>>;
+
     #[pallet::type_value]
     pub fn DefaultDelegationFee<T: Config>() -> Percent {
         Percent::from_percent(5u8)
@@ -518,10 +633,46 @@ pub mod pallet {
     pub type DelegationFee<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Percent, ValueQuery, DefaultDelegationFee<T>>;
 
+    #[pallet::type_value]
+    pub fn DefaultMinerDelegationFee<T: Config>() -> Percent {
+        Percent::from_percent(100u8)
+    }
+
+    #[pallet::storage] // --- MAP ( module_key ) --> miner_delegation_fee
+    pub type MinerDelegationFee<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Percent,
+        ValueQuery,
+        DefaultMinerDelegationFee<T>,
+    >;
+
+    /// Upper bound for the delegation fee notice period, roughly 30 days of blocks.
+    pub const MAX_DELEGATION_FEE_NOTICE_PERIOD: u64 = 324_000;
+
+    #[pallet::storage] // --- ITEM ( delegation_fee_notice_period )
+    pub type DelegationFeeNoticePeriod<T> = StorageValue<_, u64, ValueQuery, ConstU64<10_800>>;
+
+    #[pallet::storage] // --- MAP ( module_key ) --> (delegation_fee, effective_block)
+    pub type PendingDelegationFee<T: Config> =
+        StorageMap<_, Identity, T::AccountId, (Percent, u64)>;
+
+    #[pallet::storage] // --- MAP ( module_key ) --> (miner_delegation_fee, effective_block)
+    pub type PendingMinerDelegationFee<T: Config> =
+        StorageMap<_, Identity, T::AccountId, (Percent, u64)>;
+
+    #[pallet::storage] // --- MAP ( module_key ) --> controller
+    pub type ModuleController<T: Config> = StorageMap<_, Identity, T::AccountId, T::AccountId>;
+
     #[pallet::storage] // MAP (netuid, module_key) -> control_delegation
     pub type RootnetControlDelegation<T: Config> =
         StorageMap<_, Identity, T::AccountId, T::AccountId>;
 
+    #[pallet::storage] // --- DMAP ( target, delegator ) --> ()
+    pub type RootnetControlDelegators<T: Config> =
+        StorageDoubleMap<_, Identity, T::AccountId, Identity, T::AccountId, ()>;
+
     // ---------------------------------
     // Event Variables
     // ---------------------------------
@@ -539,19 +690,72 @@ pub mod pallet {
         /// Event created when stake has been removed from the key staking account onto the coldkey
         /// account
         StakeRemoved(T::AccountId, T::AccountId, u64),
+        /// Event created when removed stake enters the unbonding queue
+        /// (staker, module_key, amount, unlock_block)
+        StakeUnbonding(T::AccountId, T::AccountId, u64, u64),
+        /// Event created when unbonded stake is withdrawn back to the staker's balance
+        UnbondedWithdrawn(T::AccountId, u64),
+        /// Event created when a staker changes where its rewards are paid
+        PayoutPreferenceSet(T::AccountId, PayoutPreference<T::AccountId>),
+        /// Event created at the end of an epoch for the rewards paid out as balance
+        /// (netuid, staker, destination, amount)
+        RewardsPaidOut(u16, T::AccountId, T::AccountId, u64),
         /// Event created when a caller successfully sets their weights on a subnetwork
         WeightsSet(u16, u16),
+        /// Event created when a caller commits to the weights it will reveal on the next epoch
+        WeightsCommitted(u16, u16),
         /// Event created when a new module account has been registered to the chain
         ModuleRegistered(u16, u16, T::AccountId),
         /// Event created when a module account has been deregistered from the chain
         ModuleDeregistered(u16, u16, T::AccountId),
         /// Event created when the module's updated information is added to the network
         ModuleUpdated(u16, T::AccountId),
+        /// Event created when a module sets or clears the controller managing it
+        /// (module_key, controller)
+        ModuleControllerSet(T::AccountId, Option<T::AccountId>),
+        /// Event created when a module is moved to a new key on every subnet
+        /// (old_key, new_key)
+        ModuleKeyRotated(T::AccountId, T::AccountId),
+        /// Event created when a module announces a delegation fee increase
+        /// (module_key, delegation_fee, effective_block)
+        DelegationFeeScheduled(T::AccountId, Percent, u64),
+        /// Event created when a module announces a miner delegation fee increase
+        /// (module_key, miner_delegation_fee, effective_block)
+        MinerDelegationFeeScheduled(T::AccountId, Percent, u64),
         // Parameter Updates
         /// Event created when global parameters are updated
         GlobalParamsUpdated(GlobalParams<T>),
         /// Event created when subnet parameters are updated
         SubnetParamsUpdated(u16),
+        /// Event created when a founder offers the subnet to a new founder
+        /// (netuid, new_founder, expiration_block)
+        SubnetOwnershipProposed(u16, T::AccountId, u64),
+        /// Event created when the new founder accepts the subnet
+        /// (netuid, old_founder, new_founder)
+        SubnetOwnershipTransferred(u16, T::AccountId, T::AccountId),
+        /// Event created when the founder withdraws the subnet ownership offer, or when it expires
+        /// (netuid)
+        SubnetOwnershipOfferCancelled(u16),
+        /// Event created when a founder schedules a subnet parameters update
+        /// (netuid, at_block)
+        SubnetUpdateScheduled(u16, u64),
+        /// Event created when a scheduled subnet parameters update is cancelled, or dropped
+        /// because it was no longer valid at its block
+        /// (netuid)
+        ScheduledSubnetUpdateCancelled(u16),
+        /// Event created when the first bid opens a subnet slot auction
+        /// (end_block)
+        SubnetSlotAuctionStarted(u64),
+        /// Event created when a bid becomes the highest of the subnet slot auction
+        /// (bidder, amount)
+        SubnetSlotBidPlaced(T::AccountId, u64),
+        /// Event created when the highest bid wins the subnet slot
+        /// (netuid, bidder, amount)
+        SubnetSlotAuctionWon(u16, T::AccountId, u64),
+        /// Event created when the subnet of the highest bid can't be created, and the bid is
+        /// refunded
+        /// (bidder, amount)
+        SubnetSlotAuctionFailed(T::AccountId, u64),
     }
 
     // ---------------------------------
@@ -571,6 +775,8 @@ pub mod pallet {
         NotEnoughBalanceToRegisterSubnet,
         /// Insufficient stake to withdraw the requested amount.
         NotEnoughStakeToWithdraw,
+        /// The stake is locked by a conviction vote and can't be withdrawn yet.
+        StakeIsLocked,
         /// Insufficient balance in the cold key account to stake the requested amount.
         NotEnoughBalanceToStake,
         /// The weight vectors for keys and values have different sizes.
@@ -729,6 +935,59 @@ pub mod pallet {
         RootnetSubnetNotFound,
         /// MinValidatorStake must be lower than 250k
         InvalidMinValidatorStake,
+        /// The unbonding period is invalid.
+        InvalidUnbondingPeriod,
+        /// The delegation fee notice period is invalid.
+        InvalidDelegationFeeNoticePeriod,
+        /// The staker has too many pending unlocks, withdraw the unbonded ones first.
+        TooManyPendingUnlocks,
+        /// There is no unbonded stake ready to be withdrawn.
+        NoUnbondedStake,
+        /// The subnet requires weights to be committed and revealed.
+        CommitRevealWeightsEnabled,
+        /// The subnet does not use commit-reveal weights.
+        CommitRevealWeightsDisabled,
+        /// There is no weight commit for the caller on this subnet.
+        WeightCommitNotFound,
+        /// Weights can only be revealed on the epoch after they were committed.
+        RevealTooEarly,
+        /// The reveal window for the weight commit has passed.
+        WeightCommitExpired,
+        /// The revealed weights do not match the commit hash.
+        InvalidWeightReveal,
+        /// The new module key is already registered, holds or receives stake, or has governance
+        /// state.
+        NewKeyInUse,
+        /// The caller is not the controller of the module. Modules without a controller are
+        /// managed by their own key.
+        NotModuleController,
+        /// The founder can't be changed by `update_subnet`, it's offered with
+        /// `propose_subnet_ownership` instead.
+        FounderChangeNotAllowed,
+        /// The account is already the founder of the subnet.
+        AlreadySubnetFounder,
+        /// The subnet has not been offered to the caller.
+        NoSubnetOwnershipOffer,
+        /// The subnet ownership offer has expired.
+        SubnetOwnershipOfferExpired,
+        /// Subnet updates can only be scheduled for a future block.
+        InvalidScheduledBlock,
+        /// The subnet has no scheduled update.
+        NoScheduledSubnetUpdate,
+        /// Subnet slots are not auctioned.
+        SubnetAuctionDisabled,
+        /// Subnets can still be registered without an auction.
+        SubnetSlotsAvailable,
+        /// All subnet slots are taken, new subnets must win the subnet slot auction.
+        SubnetSlotAuctionRequired,
+        /// The bid is below the minimum bid or the highest bid.
+        SubnetBidTooLow,
+        /// Not enough balance to place the bid.
+        NotEnoughBalanceToBid,
+        /// The module key holds more than the key rotation witness declares.
+        InvalidKeyRotationWitness,
+        /// The subnet auction duration and minimum bid must be non-zero.
+        InvalidSubnetAuctionConfig,
     }
 
     // ---------------------------------
@@ -811,25 +1070,44 @@ pub mod pallet {
             let block_number: u64 =
                 block_number.try_into().ok().expect("blockchain won't pass 2 ^ 64 blocks");
 
+            let db_weight = T::DbWeight::get();
+
             // Adjust costs to reflect the demand
-            Self::adjust_registration_parameters(block_number);
+            let mut weight = Self::adjust_registration_parameters(block_number);
 
             // Clears the root net weights daily quota
             Self::clear_rootnet_daily_weight_calls(block_number);
+            weight = weight.saturating_add(db_weight.writes(1));
+
+            weight = weight.saturating_add(Self::copy_delegated_weights(block_number));
 
-            Self::copy_delegated_weights(block_number);
+            // `SubnetSlotAuction`
+            weight = weight.saturating_add(db_weight.reads(1));
+            weight = weight.saturating_add(Self::close_subnet_slot_auction(block_number));
 
             for netuid in N::<T>::iter_keys() {
+                // `N`, the scheduled update, the ownership offer, the plaintext weights clearing
+                // and the epoch schedule of the subnet
+                weight = weight.saturating_add(db_weight.reads(5));
+                weight = weight
+                    .saturating_add(Self::apply_scheduled_subnet_update(netuid, block_number));
+                weight = weight.saturating_add(Self::clear_plaintext_weights(netuid));
+                weight = weight.saturating_add(Self::clear_expired_subnet_ownership_offer(
+                    netuid,
+                    block_number,
+                ));
+
                 if Self::blocks_until_next_epoch(netuid, block_number) > 0 {
                     continue;
                 }
 
                 // Clear weights for normal subnets
                 Self::clear_set_weight_rate_limiter(netuid);
+                Self::clear_expired_weight_commits(netuid, block_number);
+                weight = weight.saturating_add(db_weight.reads_writes(2, 2));
             }
 
-            // TODO: fix later
-            Weight::default()
+            weight
         }
 
         fn on_idle(_n: BlockNumberFor<T>, _remaining: Weight) -> Weight {
@@ -869,7 +1147,11 @@ pub mod pallet {
         // ---------------------------------
 
         #[pallet::call_index(1)]
-        #[pallet::weight((T::WeightInfo::add_stake(), DispatchClass::Normal, Pays::No))]
+        #[pallet::weight((
+            T::WeightInfo::add_stake().saturating_add(T::voting_power_change_weight()),
+            DispatchClass::Normal,
+            Pays::No
+        ))]
         pub fn add_stake(
             origin: OriginFor<T>,
             module_key: T::AccountId,
@@ -879,7 +1161,11 @@ pub mod pallet {
         }
 
         #[pallet::call_index(2)]
-        #[pallet::weight((T::WeightInfo::remove_stake(), DispatchClass::Normal, Pays::No))]
+        #[pallet::weight((
+            T::WeightInfo::remove_stake().saturating_add(T::voting_power_change_weight()),
+            DispatchClass::Normal,
+            Pays::No
+        ))]
         pub fn remove_stake(
             origin: OriginFor<T>,
             module_key: T::AccountId,
@@ -893,7 +1179,13 @@ pub mod pallet {
         // ---------------------------------
 
         #[pallet::call_index(3)]
-        #[pallet::weight((T::WeightInfo::add_stake_multiple(), DispatchClass::Normal, Pays::No))]
+        #[pallet::weight((
+            T::WeightInfo::add_stake_multiple().saturating_add(
+                T::voting_power_change_weight().saturating_mul(module_keys.len() as u64)
+            ),
+            DispatchClass::Normal,
+            Pays::No
+        ))]
         pub fn add_stake_multiple(
             origin: OriginFor<T>,
             module_keys: Vec<T::AccountId>,
@@ -903,7 +1195,13 @@ pub mod pallet {
         }
 
         #[pallet::call_index(4)]
-        #[pallet::weight((T::WeightInfo::remove_stake_multiple(), DispatchClass::Normal, Pays::No))]
+        #[pallet::weight((
+            T::WeightInfo::remove_stake_multiple().saturating_add(
+                T::voting_power_change_weight().saturating_mul(module_keys.len() as u64)
+            ),
+            DispatchClass::Normal,
+            Pays::No
+        ))]
         pub fn remove_stake_multiple(
             origin: OriginFor<T>,
             module_keys: Vec<T::AccountId>,
@@ -917,7 +1215,12 @@ pub mod pallet {
         // ---------------------------------
 
         #[pallet::call_index(5)]
-        #[pallet::weight((T::WeightInfo::transfer_stake(), DispatchClass::Normal, Pays::No))]
+        #[pallet::weight((
+            T::WeightInfo::transfer_stake()
+                .saturating_add(T::voting_power_change_weight().saturating_mul(2)),
+            DispatchClass::Normal,
+            Pays::No
+        ))]
         pub fn transfer_stake(
             origin: OriginFor<T>,         // --- The account that is calling this function.
             module_key: T::AccountId,     // --- The module key.
@@ -942,7 +1245,11 @@ pub mod pallet {
         // ---------------------------------
 
         #[pallet::call_index(7)]
-        #[pallet::weight((T::WeightInfo::register(), DispatchClass::Normal, Pays::No))]
+        #[pallet::weight((
+            T::WeightInfo::register().saturating_add(T::voting_power_change_weight()),
+            DispatchClass::Normal,
+            Pays::No
+        ))]
         pub fn register(
             origin: OriginFor<T>,
             network_name: Vec<u8>,
@@ -965,8 +1272,12 @@ pub mod pallet {
 
         #[pallet::call_index(8)]
         #[pallet::weight((T::WeightInfo::deregister(), DispatchClass::Normal, Pays::No))]
-        pub fn deregister(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
-            Self::do_deregister(origin, netuid)
+        pub fn deregister(
+            origin: OriginFor<T>,
+            netuid: u16,
+            module_key: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_deregister(origin, netuid, module_key)
         }
 
         // ---------------------------------
@@ -978,22 +1289,29 @@ pub mod pallet {
         pub fn update_module(
             origin: OriginFor<T>,
             netuid: u16,
+            module_key: T::AccountId,
             name: Vec<u8>,
             address: Vec<u8>,
             delegation_fee: Option<Percent>,
+            miner_delegation_fee: Option<Percent>,
             metadata: Option<Vec<u8>>,
         ) -> DispatchResult {
-            let key = ensure_signed(origin.clone())?;
             ensure!(
-                Self::is_registered(Some(netuid), &key),
+                Self::is_registered(Some(netuid), &module_key),
                 Error::<T>::ModuleDoesNotExist
             );
 
-            let params = Self::module_params(netuid, &key);
+            let params = Self::module_params(netuid, &module_key);
 
-            let changeset =
-                ModuleChangeset::update(&params, name, address, delegation_fee, metadata);
-            Self::do_update_module(origin, netuid, changeset)
+            let changeset = ModuleChangeset::update(
+                &params,
+                name,
+                address,
+                delegation_fee,
+                miner_delegation_fee,
+                metadata,
+            );
+            Self::do_update_module(origin, netuid, module_key, changeset)
         }
 
         #[pallet::call_index(10)]
@@ -1021,6 +1339,9 @@ pub mod pallet {
             max_registrations_per_interval: u16,
             adjustment_alpha: u64,
             min_validator_stake: u64,
+            unbonding_period: Option<u64>,
+            commit_reveal_weights: bool,
+            record_epoch_emission: bool,
         ) -> DispatchResult {
             let params = SubnetParams {
                 founder,
@@ -1046,6 +1367,9 @@ pub mod pallet {
                     ..T::get_subnet_governance_configuration(netuid)
                 },
                 metadata,
+                unbonding_period,
+                commit_reveal_weights,
+                record_epoch_emission,
             };
 
             let changeset = SubnetChangeset::update(netuid, params)?;
@@ -1060,6 +1384,143 @@ pub mod pallet {
         ) -> DispatchResult {
             Self::do_delegate_rootnet_control(origin, target)
         }
+
+        #[pallet::call_index(12)]
+        #[pallet::weight((
+            T::WeightInfo::withdraw_unbonded().saturating_add(
+                T::voting_power_change_weight().saturating_mul(MAX_PENDING_UNLOCKS as u64)
+            ),
+            DispatchClass::Normal,
+            Pays::No
+        ))]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_withdraw_unbonded(origin)
+        }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight((T::WeightInfo::commit_weights(), DispatchClass::Normal, Pays::No))]
+        pub fn commit_weights(
+            origin: OriginFor<T>,
+            netuid: u16,
+            commit_hash: T::Hash,
+        ) -> DispatchResult {
+            Self::do_commit_weights(origin, netuid, commit_hash)
+        }
+
+        #[pallet::call_index(14)]
+        #[pallet::weight((T::WeightInfo::reveal_weights(), DispatchClass::Normal, Pays::No))]
+        pub fn reveal_weights(
+            origin: OriginFor<T>,
+            netuid: u16,
+            uids: Vec<u16>,
+            weights: Vec<u16>,
+            salt: Vec<u8>,
+        ) -> DispatchResult {
+            Self::do_reveal_weights(origin, netuid, uids, weights, salt)
+        }
+
+        #[pallet::call_index(15)]
+        #[pallet::weight((T::WeightInfo::set_payout_preference(), DispatchClass::Normal, Pays::No))]
+        pub fn set_payout_preference(
+            origin: OriginFor<T>,
+            key: T::AccountId,
+            preference: PayoutPreference<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_payout_preference(origin, key, preference)
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight((
+            T::WeightInfo::rotate_module_key(
+                witness.subnets,
+                witness.stakes,
+                witness.rootnet_delegators,
+            )
+            .saturating_add(T::module_key_rotation_weight(witness.governance_entries)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn rotate_module_key(
+            origin: OriginFor<T>,
+            netuid: u16,
+            module_key: T::AccountId,
+            new_key: T::AccountId,
+            witness: KeyRotationWitness,
+        ) -> DispatchResult {
+            Self::do_rotate_module_key(origin, netuid, module_key, new_key, witness)
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight((T::WeightInfo::set_module_controller(), DispatchClass::Normal, Pays::No))]
+        pub fn set_module_controller(
+            origin: OriginFor<T>,
+            module_key: T::AccountId,
+            controller: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_module_controller(origin, module_key, controller)
+        }
+
+        #[pallet::call_index(18)]
+        #[pallet::weight((T::WeightInfo::propose_subnet_ownership(), DispatchClass::Normal, Pays::No))]
+        pub fn propose_subnet_ownership(
+            origin: OriginFor<T>,
+            netuid: u16,
+            new_founder: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_propose_subnet_ownership(origin, netuid, new_founder)
+        }
+
+        #[pallet::call_index(19)]
+        #[pallet::weight((T::WeightInfo::accept_subnet_ownership(), DispatchClass::Normal, Pays::No))]
+        pub fn accept_subnet_ownership(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_accept_subnet_ownership(origin, netuid)
+        }
+
+        #[pallet::call_index(20)]
+        #[pallet::weight((T::WeightInfo::update_subnet(), DispatchClass::Normal, Pays::No))]
+        pub fn update_subnet_params(
+            origin: OriginFor<T>,
+            netuid: u16,
+            patch: SubnetParamsPatch<T>,
+        ) -> DispatchResult {
+            let params = patch.merge(Self::subnet_params(netuid));
+            let changeset = SubnetChangeset::update(netuid, params)?;
+            Self::do_update_subnet(origin, netuid, changeset)
+        }
+
+        #[pallet::call_index(21)]
+        #[pallet::weight((T::WeightInfo::schedule_subnet_update(), DispatchClass::Normal, Pays::No))]
+        pub fn schedule_subnet_update(
+            origin: OriginFor<T>,
+            netuid: u16,
+            patch: SubnetParamsPatch<T>,
+            at_block: u64,
+        ) -> DispatchResult {
+            Self::do_schedule_subnet_update(origin, netuid, patch, at_block)
+        }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight((T::WeightInfo::cancel_scheduled_subnet_update(), DispatchClass::Normal, Pays::No))]
+        pub fn cancel_scheduled_subnet_update(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_cancel_scheduled_subnet_update(origin, netuid)
+        }
+
+        #[pallet::call_index(23)]
+        #[pallet::weight((T::WeightInfo::bid_subnet_slot(), DispatchClass::Normal, Pays::No))]
+        pub fn bid_subnet_slot(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+            metadata: Option<Vec<u8>>,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_bid_subnet_slot(origin, name, metadata, amount)
+        }
+
+        #[pallet::call_index(24)]
+        #[pallet::weight((T::WeightInfo::cancel_subnet_ownership_offer(), DispatchClass::Normal, Pays::No))]
+        pub fn cancel_subnet_ownership_offer(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_cancel_subnet_ownership_offer(origin, netuid)
+        }
     }
 }
 
@@ -126,3 +126,33 @@ pub mod v13 {
         }
     }
 }
+
+pub mod v14 {
+    use super::*;
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    pub struct MigrateToV14<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV14<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != 13 {
+                log::info!("Storage v14 already updated");
+                return Weight::zero();
+            }
+            log::info!("Migrating storage to v14");
+
+            // Index the rootnet control delegations by target
+            let mut delegations = 0u64;
+            for (delegator, target) in RootnetControlDelegation::<T>::iter() {
+                RootnetControlDelegators::<T>::insert(target, delegator, ());
+                delegations = delegations.saturating_add(1);
+            }
+            log::info!("Indexed {delegations} rootnet control delegations");
+
+            StorageVersion::new(14).put::<Pallet<T>>();
+            T::DbWeight::get()
+                .reads_writes(delegations.saturating_add(1), delegations.saturating_add(1))
+        }
+    }
+}
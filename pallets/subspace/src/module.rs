@@ -9,6 +9,7 @@ pub struct ModuleChangeset {
     pub name: Option<Vec<u8>>,
     pub address: Option<Vec<u8>>,
     pub delegation_fee: Option<Percent>,
+    pub miner_delegation_fee: Option<Percent>,
     pub metadata: Option<Vec<u8>>,
 }
 
@@ -24,6 +25,7 @@ impl ModuleChangeset {
             name: Some(name),
             address: Some(address),
             delegation_fee: Some(delegation_fee),
+            miner_delegation_fee: None,
             metadata,
         }
     }
@@ -34,12 +36,14 @@ impl ModuleChangeset {
         name: Vec<u8>,
         address: Vec<u8>,
         delegation_fee: Option<Percent>,
+        miner_delegation_fee: Option<Percent>,
         metadata: Option<Vec<u8>>,
     ) -> Self {
         Self {
             name: (name != params.name).then_some(name),
             address: (address != params.address).then_some(address),
             delegation_fee,
+            miner_delegation_fee,
             metadata,
         }
     }
@@ -82,9 +86,18 @@ impl ModuleChangeset {
 
             if !DelegationFee::<T>::contains_key(&key) {
                 DelegationFee::<T>::insert(&key, fee);
+            } else {
+                Pallet::<T>::schedule_delegation_fee(&key, fee);
             }
         }
 
+        if let Some(fee) = self.miner_delegation_fee {
+            let floor = FloorDelegationFee::<T>::get();
+            ensure!(fee >= floor, Error::<T>::InvalidMinDelegationFee);
+
+            Pallet::<T>::schedule_miner_delegation_fee(&key, fee);
+        }
+
         if let Some(metadata) = self.metadata {
             ensure!(!metadata.is_empty(), Error::<T>::InvalidModuleMetadata);
             ensure!(metadata.len() <= 59, Error::<T>::ModuleMetadataTooLong);
@@ -102,15 +115,179 @@ impl<T: Config> Pallet<T> {
     pub fn do_update_module(
         origin: T::RuntimeOrigin,
         netuid: u16,
+        module_key: T::AccountId,
         changeset: ModuleChangeset,
     ) -> DispatchResult {
-        // 1. We check the callers (key) signature.
-        let key = ensure_signed(origin)?;
-        let uid: u16 = Self::get_uid_for_key(netuid, &key).ok_or(Error::<T>::ModuleDoesNotExist)?;
+        // 1. We check the caller manages the module.
+        Self::ensure_module_controller(origin, &module_key)?;
+        let uid: u16 =
+            Self::get_uid_for_key(netuid, &module_key).ok_or(Error::<T>::ModuleDoesNotExist)?;
 
         // 2. Apply the changeset
-        changeset.apply::<T>(netuid, key, uid)?;
+        changeset.apply::<T>(netuid, module_key, uid)?;
+
+        Ok(())
+    }
+
+    /// Returns the account managing the module: its controller, or the module key itself if it
+    /// has none.
+    pub fn get_module_controller(module_key: &T::AccountId) -> T::AccountId {
+        ModuleController::<T>::get(module_key).unwrap_or_else(|| module_key.clone())
+    }
+
+    /// Ensures the origin is signed by the account managing the module.
+    pub fn ensure_module_controller(
+        origin: T::RuntimeOrigin,
+        module_key: &T::AccountId,
+    ) -> DispatchResult {
+        let caller = ensure_signed(origin)?;
+        ensure!(
+            caller == Self::get_module_controller(module_key),
+            Error::<T>::NotModuleController
+        );
+        Ok(())
+    }
+
+    /// Ensures the origin is signed by a key managing itself, and returns it. It guards the stake
+    /// of a module key with a controller, and the transfers made through this pallet. The free
+    /// balance of the key can still be moved with `pallet_balances`, so such a module should keep
+    /// its funds staked or on the controller.
+    pub fn ensure_self_managed(
+        origin: T::RuntimeOrigin,
+    ) -> Result<T::AccountId, sp_runtime::DispatchError> {
+        let key = ensure_signed(origin.clone())?;
+        Self::ensure_module_controller(origin, &key)?;
+        Ok(key)
+    }
+
+    /// Sets the account that alone updates, deregisters and rotates the module, leaving the
+    /// module key to set weights only. Setting no controller, or the module key itself, hands
+    /// the module back to its key.
+    pub fn do_set_module_controller(
+        origin: T::RuntimeOrigin,
+        module_key: T::AccountId,
+        controller: Option<T::AccountId>,
+    ) -> DispatchResult {
+        Self::ensure_module_controller(origin, &module_key)?;
+        ensure!(
+            Self::is_key_registered_on_any_network(&module_key),
+            Error::<T>::ModuleDoesNotExist
+        );
+
+        let controller = controller.filter(|controller| *controller != module_key);
+        match &controller {
+            Some(controller) => ModuleController::<T>::insert(&module_key, controller),
+            None => ModuleController::<T>::remove(&module_key),
+        }
+
+        Self::deposit_event(Event::ModuleControllerSet(module_key, controller));
+        Ok(())
+    }
 
+    /// Moves the module to `new_key` on every subnet it is registered on. The uids are kept,
+    /// and with them the bonds, weights and registration blocks of the module, while everything
+    /// stored by key, the unbonding stake included, is moved to the new key.
+    pub fn do_rotate_module_key(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        key: T::AccountId,
+        new_key: T::AccountId,
+        witness: KeyRotationWitness,
+    ) -> DispatchResult {
+        // 1. We check the caller manages the module.
+        Self::ensure_module_controller(origin, &key)?;
+        ensure!(
+            Uids::<T>::contains_key(netuid, &key),
+            Error::<T>::ModuleDoesNotExist
+        );
+
+        // 2. The new key must be unused, so nothing it owns is merged into the module.
+        ensure!(
+            new_key != key
+                && !Self::is_key_registered_on_any_network(&new_key)
+                && StakeTo::<T>::iter_prefix(&new_key).next().is_none()
+                && StakeFrom::<T>::iter_prefix(&new_key).next().is_none()
+                && !PendingUnlocks::<T>::contains_key(&new_key)
+                && !T::has_governance_state(&new_key),
+            Error::<T>::NewKeyInUse
+        );
+
+        // 3. The key must not hold more than the caller paid for.
+        ensure!(
+            at_most(N::<T>::iter_keys(), witness.subnets)
+                && at_most(
+                    StakeFrom::<T>::iter_key_prefix(&key)
+                        .chain(StakeTo::<T>::iter_key_prefix(&key)),
+                    witness.stakes
+                )
+                && at_most(
+                    RootnetControlDelegators::<T>::iter_key_prefix(&key),
+                    witness.rootnet_delegators
+                )
+                && T::module_key_rotation_entries(&key, witness.governance_entries)
+                    <= witness.governance_entries,
+            Error::<T>::InvalidKeyRotationWitness
+        );
+
+        // 4. Move the module on every subnet, keeping its uid.
+        let netuids: Vec<_> = N::<T>::iter_keys()
+            .filter_map(|netuid| Uids::<T>::get(netuid, &key).map(|uid| (netuid, uid)))
+            .collect();
+        for (netuid, uid) in netuids {
+            Uids::<T>::remove(netuid, &key);
+            Uids::<T>::insert(netuid, &new_key, uid);
+            Keys::<T>::insert(netuid, uid, &new_key);
+
+            if let Some(metadata) = Metadata::<T>::take(netuid, &key) {
+                Metadata::<T>::insert(netuid, &new_key, metadata);
+            }
+            if let Some(commit) = WeightCommits::<T>::take(netuid, &key) {
+                WeightCommits::<T>::insert(netuid, &new_key, commit);
+            }
+        }
+
+        // 5. Move the controller, the fees, the stake and the payout preference.
+        ModuleController::<T>::swap(&key, &new_key);
+        DelegationFee::<T>::swap(&key, &new_key);
+        PendingDelegationFee::<T>::swap(&key, &new_key);
+        MinerDelegationFee::<T>::swap(&key, &new_key);
+        PendingMinerDelegationFee::<T>::swap(&key, &new_key);
+        PayoutPreferences::<T>::swap(&key, &new_key);
+        Self::move_stake(&key, &new_key);
+
+        // 6. Move the unbonding stake, so it is withdrawn by the new key. The new key has no
+        // pending unlocks, so none are merged.
+        let mut unlocks = PendingUnlocks::<T>::take(&key);
+        for unlock in &mut unlocks {
+            if unlock.module_key == key {
+                unlock.module_key = new_key.clone();
+            }
+            if unlock.restake_to.as_ref() == Some(&key) {
+                unlock.restake_to = Some(new_key.clone());
+            }
+        }
+        if !unlocks.is_empty() {
+            PendingUnlocks::<T>::insert(&new_key, unlocks);
+        }
+
+        // 7. Move the rootnet control delegations, from and to the key.
+        if let Some(target) = RootnetControlDelegation::<T>::take(&key) {
+            RootnetControlDelegators::<T>::remove(&target, &key);
+            RootnetControlDelegators::<T>::insert(&target, &new_key, ());
+            RootnetControlDelegation::<T>::insert(&new_key, target);
+        }
+        let delegators: Vec<_> = RootnetControlDelegators::<T>::drain_prefix(&key)
+            .map(|(delegator, ())| delegator)
+            .collect();
+        for delegator in delegators {
+            RootnetControlDelegation::<T>::insert(&delegator, &new_key);
+            RootnetControlDelegators::<T>::insert(&new_key, delegator, ());
+        }
+
+        // 8. Move the governance state of the key.
+        T::handle_module_key_rotation(&key, &new_key);
+
+        Self::deposit_event(Event::ModuleKeyRotated(key, new_key));
         Ok(())
     }
 
@@ -125,8 +302,9 @@ impl<T: Config> Pallet<T> {
             name: Name::<T>::get(netuid, uid),
             address: Address::<T>::get(netuid, uid),
             metadata: Metadata::<T>::get(netuid, key),
-            delegation_fee: DelegationFee::<T>::get(key),
-            controller: key.clone(),
+            delegation_fee: Self::get_active_delegation_fee(key),
+            miner_delegation_fee: Self::get_active_miner_delegation_fee(key),
+            controller: Self::get_module_controller(key),
         }
     }
 
@@ -301,6 +479,10 @@ impl<T: Config> Pallet<T> {
         // HANDLE THE DELEGATION FEE
         if Uids::<T>::iter().all(|(_, key, _)| key != module_key) {
             DelegationFee::<T>::remove(&module_key);
+            PendingDelegationFee::<T>::remove(&module_key);
+            MinerDelegationFee::<T>::remove(&module_key);
+            PendingMinerDelegationFee::<T>::remove(&module_key);
+            ModuleController::<T>::remove(&module_key);
             // Remove stake from old key and add to new key
             Self::remove_stake_from_storage(&module_key);
         }
@@ -326,18 +508,19 @@ impl<T: Config> Pallet<T> {
     fn handle_rootnet_module_deregistration(key: T::AccountId, netuid: u16) {
         if Self::is_rootnet(netuid) {
             // Remove the direct delegation for the key
-            RootnetControlDelegation::<T>::remove(&key);
+            if let Some(target) = RootnetControlDelegation::<T>::take(&key) {
+                RootnetControlDelegators::<T>::remove(target, &key);
+            }
 
             // Remove all delegations to the key
-            RootnetControlDelegation::<T>::translate(
-                |_, v: T::AccountId| {
-                    if v == key {
-                        None
-                    } else {
-                        Some(v)
-                    }
-                },
-            );
+            for (delegator, ()) in RootnetControlDelegators::<T>::drain_prefix(&key) {
+                RootnetControlDelegation::<T>::remove(delegator);
+            }
         }
     }
 }
+
+/// Whether the iterator yields at most `bound` items, going through no more than `bound + 1`.
+fn at_most(iter: impl Iterator, bound: u32) -> bool {
+    iter.take(bound.saturating_add(1) as usize).count() <= bound as usize
+}
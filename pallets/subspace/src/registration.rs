@@ -50,6 +50,7 @@ impl<T: Config> Pallet<T> {
     /// * The maximum number of registrations per interval has been reached.
     /// * The stake is insufficient for registration.
     /// * The module key is already registered.
+    /// * The module has a controller and the caller is not it.
     /// * The maximum number of modules per network has been reached.
     /// * The root network registration requirements are not met.
     ///
@@ -95,8 +96,9 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Arguments
     ///
-    /// * `origin` - The origin of the call, must be a signed extrinsic.
+    /// * `origin` - The origin of the call, must be signed by the module's controller.
     /// * `netuid` - The unique identifier of the subnet.
+    /// * `key` - The key of the module to deregister.
     ///
     /// # Returns
     ///
@@ -105,7 +107,7 @@ impl<T: Config> Pallet<T> {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// * The origin is not signed.
+    /// * The origin is not signed by the module's controller.
     /// * The module does not exist in the specified subnet.
     /// * The module cannot be removed from the subnet.
     /// * The key is still registered after removal attempt.
@@ -113,9 +115,13 @@ impl<T: Config> Pallet<T> {
     /// # Events
     ///
     /// Emits a `ModuleDeregistered` event when successful.
-    pub fn do_deregister(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
-        // --- 1. Check that the caller has signed the transaction.
-        let key = ensure_signed(origin)?;
+    pub fn do_deregister(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        key: T::AccountId,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller manages the module.
+        Self::ensure_module_controller(origin, &key)?;
         // --- 2. Check that the module exists in the subnet.
         let Some(uid) = Self::get_uid_for_key(netuid, &key) else {
             return Err(Error::<T>::ModuleDoesNotExist.into());
@@ -163,6 +169,14 @@ impl<T: Config> Pallet<T> {
             ..DefaultSubnetParams::<T>::get()
         };
         let changeset = SubnetChangeset::new(params)?;
+
+        // Once all slots are taken, new subnets must win the slot auction instead of evicting one
+        ensure!(
+            !SubnetAuctionConfig::<T>::get().enabled
+                || Self::get_total_subnets() < MaxAllowedSubnets::<T>::get(),
+            Error::<T>::SubnetSlotAuctionRequired
+        );
+
         let burn = SubnetBurn::<T>::get();
 
         Self::remove_balance_from_account(
@@ -194,6 +208,11 @@ impl<T: Config> Pallet<T> {
             Error::<T>::KeyAlreadyRegistered
         );
 
+        // A module with a controller is only registered on more subnets by it.
+        if let Some(controller) = ModuleController::<T>::get(module_key) {
+            ensure!(*key == controller, Error::<T>::NotModuleController);
+        }
+
         let rootnet_id = T::get_consensus_netuid(SubnetConsensus::Root).unwrap_or(Self::ROOTNET_ID);
         if netuid != rootnet_id {
             let burn =
@@ -429,8 +448,9 @@ impl<T: Config> Pallet<T> {
     // Registration Burn
     // --------------------------
 
-    // This code is running under the `on_initialize` hook
-    pub fn adjust_registration_parameters(block_number: u64) {
+    /// Adjusts the subnet and module burns to reflect the demand. Runs under the
+    /// `on_initialize` hook and returns the weight it consumed.
+    pub fn adjust_registration_parameters(block_number: u64) -> Weight {
         // For subnet prices
         let subnet_config = SubnetBurnConfig::<T>::get();
         let subnet_burn = SubnetBurn::<T>::get();
@@ -452,7 +472,10 @@ impl<T: Config> Pallet<T> {
         // For subnet modules
         RegistrationsPerBlock::<T>::mutate(|val| *val = 0);
 
+        let mut subnets: u32 = 0;
         for (netuid, _) in Tempo::<T>::iter() {
+            subnets = subnets.saturating_add(1);
+
             let module_config = BurnConfig::<T>::get();
             let module_burn = Burn::<T>::get(netuid);
             Self::adjust_burn_parameters(
@@ -470,6 +493,8 @@ impl<T: Config> Pallet<T> {
                 },
             );
         }
+
+        T::WeightInfo::adjust_registration_parameters(subnets)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -12,6 +12,23 @@ pub struct ModuleStats<T: Config> {
     pub weights: Vec<(u16, u16)>,
 }
 
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct MetagraphModule<T: Config> {
+    pub uid: u16,
+    pub key: T::AccountId,
+    pub name: Vec<u8>,
+    pub address: Vec<u8>,
+    pub stake: u64,
+    pub incentive: u16,
+    pub dividends: u16,
+    pub trust: u16,
+    pub rank: u16,
+    pub consensus: u16,
+    pub validator_permit: bool,
+    pub last_update: u64,
+    pub emission: u64,
+}
+
 impl<T: Config> Pallet<T> {
     pub fn get_module_stats(netuid: u16, key: &T::AccountId) -> ModuleStats<T> {
         let uid = Uids::<T>::get(netuid, key).unwrap_or(u16::MAX);
@@ -39,4 +56,39 @@ impl<T: Config> Pallet<T> {
             weights,
         }
     }
+
+    /// Returns a snapshot of every module registered on the subnet, ordered by uid.
+    pub fn get_subnet_metagraph(netuid: u16) -> Vec<MetagraphModule<T>> {
+        // Read every consensus vector once, instead of once per uid.
+        let incentive = Incentive::<T>::get(netuid);
+        let dividends = Dividends::<T>::get(netuid);
+        let trust = Trust::<T>::get(netuid);
+        let rank = Rank::<T>::get(netuid);
+        let consensus = Consensus::<T>::get(netuid);
+        let validator_permits = ValidatorPermits::<T>::get(netuid);
+        let last_update = LastUpdate::<T>::get(netuid);
+        let emission = Emission::<T>::get(netuid);
+
+        (0..N::<T>::get(netuid))
+            .filter_map(|uid| Keys::<T>::get(netuid, uid).map(|key| (uid, key)))
+            .map(|(uid, key)| {
+                let idx = uid as usize;
+                MetagraphModule {
+                    uid,
+                    name: Name::<T>::get(netuid, uid),
+                    address: Address::<T>::get(netuid, uid),
+                    stake: Self::get_delegated_stake(&key),
+                    incentive: incentive.get(idx).copied().unwrap_or_default(),
+                    dividends: dividends.get(idx).copied().unwrap_or_default(),
+                    trust: trust.get(idx).copied().unwrap_or_default(),
+                    rank: rank.get(idx).copied().unwrap_or_default(),
+                    consensus: consensus.get(idx).copied().unwrap_or_default(),
+                    validator_permit: validator_permits.get(idx).copied().unwrap_or_default(),
+                    last_update: last_update.get(idx).copied().unwrap_or_default(),
+                    emission: emission.get(idx).copied().unwrap_or_default(),
+                    key,
+                }
+            })
+            .collect()
+    }
 }
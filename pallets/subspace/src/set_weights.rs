@@ -1,6 +1,7 @@
 use super::*;
 use frame_support::pallet_prelude::DispatchResult;
 use pallet_subnet_emission_api::SubnetConsensus;
+use sp_runtime::{traits::Hash, DispatchError};
 
 impl<T: Config> Pallet<T> {
     /// Sets weights for a node in a specific subnet.   
@@ -27,6 +28,7 @@ impl<T: Config> Pallet<T> {
     /// * The caller attempts to set a weight for themselves (except in subnet 0).
     /// * The caller doesn't have enough stake to set the specified weights.
     /// * The caller has no stake.
+    /// * The subnet requires weights to be committed and revealed.
     ///
     /// # Effects
     ///
@@ -51,12 +53,11 @@ impl<T: Config> Pallet<T> {
         values: Vec<u16>,
     ) -> dispatch::DispatchResult {
         let key = ensure_signed(origin)?;
-        let Some(uid) = Self::get_uid_for_key(netuid, &key) else {
-            return Err(Error::<T>::ModuleDoesNotExist.into());
-        };
-        if Pallet::<T>::get_delegated_stake(&key) < pallet::MinValidatorStake::<T>::get(netuid) {
-            return Err(Error::<T>::NotEnoughStakeToSetWeights.into());
-        }
+        ensure!(
+            !CommitRevealWeights::<T>::get(netuid),
+            Error::<T>::CommitRevealWeightsEnabled
+        );
+        let uid = Self::get_validator_uid(netuid, &key)?;
         Self::validate_input(uid, &uids, &values, netuid)?;
         Self::handle_rate_limiting(uid, netuid, &key)?;
         Self::validate_stake(&key, uids.len())?;
@@ -65,6 +66,129 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Commits to the weights a node will reveal on the next epoch of a subnet using
+    /// commit-reveal weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, must be a signed account.
+    /// * `netuid` - The ID of the subnet.
+    /// * `commit_hash` - The hash of the weights, see [`Self::hash_weights`].
+    ///
+    /// # Notes
+    ///
+    /// - A new commit replaces the previous one, even if it was not revealed yet.
+    /// - Commits count towards the set weight calls per epoch limit.
+    pub fn do_commit_weights(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        commit_hash: T::Hash,
+    ) -> DispatchResult {
+        // --- 1. Check that the subnet uses commit-reveal weights.
+        let key = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::NetworkDoesNotExist
+        );
+        ensure!(
+            CommitRevealWeights::<T>::get(netuid),
+            Error::<T>::CommitRevealWeightsDisabled
+        );
+
+        // --- 2. Check that the caller is allowed to set weights.
+        let uid = Self::get_validator_uid(netuid, &key)?;
+        Self::handle_rate_limiting(uid, netuid, &key)?;
+
+        // --- 3. Store the commit for the current epoch.
+        let commit = WeightCommit {
+            hash: commit_hash,
+            epoch: Self::get_current_epoch(netuid),
+        };
+        WeightCommits::<T>::insert(netuid, &key, commit);
+
+        Self::deposit_event(Event::WeightsCommitted(netuid, uid));
+        Ok(())
+    }
+
+    /// Reveals the weights committed on the previous epoch, setting them for the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, must be a signed account.
+    /// * `netuid` - The ID of the subnet.
+    /// * `uids` - A vector of UIDs to set weights for.
+    /// * `values` - A vector of weight values corresponding to the UIDs.
+    /// * `salt` - The salt used when computing the commit hash.
+    ///
+    /// # Errors
+    ///
+    /// Besides the errors returned by [`Self::do_set_weights`], this function fails if the
+    /// caller has no commit, if the commit was made on the current epoch, if its reveal
+    /// window has passed or if the revealed values do not match its hash.
+    pub fn do_reveal_weights(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        salt: Vec<u8>,
+    ) -> DispatchResult {
+        // --- 1. Check that the subnet uses commit-reveal weights.
+        let key = ensure_signed(origin)?;
+        ensure!(
+            CommitRevealWeights::<T>::get(netuid),
+            Error::<T>::CommitRevealWeightsDisabled
+        );
+
+        // --- 2. Check that the commit can be revealed on this epoch.
+        let commit =
+            WeightCommits::<T>::get(netuid, &key).ok_or(Error::<T>::WeightCommitNotFound)?;
+        let current_epoch = Self::get_current_epoch(netuid);
+        ensure!(commit.epoch < current_epoch, Error::<T>::RevealTooEarly);
+        ensure!(
+            commit.epoch.saturating_add(1) == current_epoch,
+            Error::<T>::WeightCommitExpired
+        );
+
+        // --- 3. Check that the revealed weights match the commit.
+        ensure!(
+            Self::hash_weights(&key, netuid, &uids, &values, &salt) == commit.hash,
+            Error::<T>::InvalidWeightReveal
+        );
+
+        // --- 4. Set the weights.
+        let uid = Self::get_validator_uid(netuid, &key)?;
+        Self::validate_input(uid, &uids, &values, netuid)?;
+        Self::validate_stake(&key, uids.len())?;
+        WeightCommits::<T>::remove(netuid, &key);
+        Self::finalize_weights(netuid, uid, &uids, &values)?;
+        Self::remove_rootnet_delegation(netuid, key);
+        Ok(())
+    }
+
+    /// Computes the hash a node has to commit to before revealing its weights.
+    ///
+    /// The key is part of the preimage so a commit cannot be copied and later revealed with
+    /// the weights published by its original author.
+    pub fn hash_weights(
+        key: &T::AccountId,
+        netuid: u16,
+        uids: &[u16],
+        values: &[u16],
+        salt: &[u8],
+    ) -> T::Hash {
+        T::Hashing::hash_of(&(key, netuid, uids, values, salt))
+    }
+
+    fn get_validator_uid(netuid: u16, key: &T::AccountId) -> Result<u16, DispatchError> {
+        let Some(uid) = Self::get_uid_for_key(netuid, key) else {
+            return Err(Error::<T>::ModuleDoesNotExist.into());
+        };
+        if Pallet::<T>::get_delegated_stake(key) < pallet::MinValidatorStake::<T>::get(netuid) {
+            return Err(Error::<T>::NotEnoughStakeToSetWeights.into());
+        }
+        Ok(uid)
+    }
+
     fn validate_input(uid: u16, uids: &[u16], values: &[u16], netuid: u16) -> DispatchResult {
         ensure!(
             uids.len() == values.len(),
@@ -119,7 +243,9 @@ impl<T: Config> Pallet<T> {
 
     fn remove_rootnet_delegation(netuid: u16, key: T::AccountId) {
         if Self::is_rootnet(netuid) {
-            RootnetControlDelegation::<T>::remove(key);
+            if let Some(target) = RootnetControlDelegation::<T>::take(&key) {
+                RootnetControlDelegators::<T>::remove(target, key);
+            }
         }
     }
 
@@ -211,6 +337,21 @@ impl<T: Config> Pallet<T> {
         let _ = SetWeightCallsPerEpoch::<T>::clear_prefix(netuid, u32::MAX, None);
     }
 
+    /// Removes the weight commits of a subnet whose reveal window has passed.
+    pub fn clear_expired_weight_commits(netuid: u16, block_number: u64) {
+        let current_epoch = Self::get_epoch_at(netuid, block_number);
+        let expired: Vec<_> = WeightCommits::<T>::iter_prefix(netuid)
+            .filter(|(_, commit)| commit.epoch.saturating_add(1) < current_epoch)
+            .map(|(key, _)| key)
+            .collect();
+
+        for key in expired {
+            WeightCommits::<T>::remove(netuid, key);
+        }
+    }
+
+    /// Hands the rootnet weight setting of the caller over to `target`. Like setting weights,
+    /// it is left to the module key even when the module has a controller.
     pub fn do_delegate_rootnet_control(
         origin: T::RuntimeOrigin,
         target: T::AccountId,
@@ -234,30 +375,46 @@ impl<T: Config> Pallet<T> {
 
         Self::check_rootnet_daily_limit(rootnet_id, origin_uid)?;
 
+        if let Some(previous) = RootnetControlDelegation::<T>::get(&key) {
+            RootnetControlDelegators::<T>::remove(previous, &key);
+        }
+        RootnetControlDelegators::<T>::insert(&target, &key, ());
         RootnetControlDelegation::<T>::set(key, Some(target));
 
         Ok(())
     }
 
-    pub fn copy_delegated_weights(block: u64) {
+    /// Copies the rootnet weights of the delegation targets to their delegators every 5400
+    /// blocks. Returns the weight consumed.
+    pub fn copy_delegated_weights(block: u64) -> Weight {
         use core::ops::Rem;
-        if block.rem(5400) == 0 {
-            let Some(rootnet_id) = T::get_consensus_netuid(SubnetConsensus::Root) else {
-                return;
-            };
+        if block.rem(5400) != 0 {
+            return T::WeightInfo::copy_delegated_weights(0, 0);
+        }
 
-            for (origin, target) in RootnetControlDelegation::<T>::iter().collect::<Vec<_>>() {
-                let Some(target_uid) = Self::get_uid_for_key(rootnet_id, &target) else {
-                    continue;
-                };
+        let Some(rootnet_id) = T::get_consensus_netuid(SubnetConsensus::Root) else {
+            return T::WeightInfo::copy_delegated_weights(0, 0);
+        };
 
-                let Some(origin_uid) = Self::get_uid_for_key(rootnet_id, &origin) else {
-                    continue;
-                };
+        let delegations = RootnetControlDelegation::<T>::iter().collect::<Vec<_>>();
+        let count = delegations.len() as u32;
+        // The longest weight vector copied, as copying is charged per weight
+        let mut max_weights: u32 = 0;
 
-                let weights = Weights::<T>::get(rootnet_id, target_uid);
-                Weights::<T>::set(rootnet_id, origin_uid, weights);
-            }
+        for (origin, target) in delegations {
+            let Some(target_uid) = Self::get_uid_for_key(rootnet_id, &target) else {
+                continue;
+            };
+
+            let Some(origin_uid) = Self::get_uid_for_key(rootnet_id, &origin) else {
+                continue;
+            };
+
+            let weights = Weights::<T>::get(rootnet_id, target_uid);
+            max_weights = max_weights.max(weights.len() as u32);
+            Weights::<T>::set(rootnet_id, origin_uid, weights);
         }
+
+        T::WeightInfo::copy_delegated_weights(count, max_weights)
     }
 }
@@ -1,5 +1,6 @@
 use super::*;
 
+use frame_support::storage::StorageMap as StorageMapT;
 use sp_arithmetic::per_things::Percent;
 use sp_runtime::DispatchError;
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
@@ -56,8 +57,8 @@ impl<T: Config> Pallet<T> {
         destinations: Vec<T::AccountId>,
         amounts: Vec<u64>,
     ) -> dispatch::DispatchResult {
-        // --- 1. We check that the transaction is signed by the caller and retrieve the
-        let key = ensure_signed(origin.clone())?;
+        // --- 1. We check that the transaction is signed by a key managing itself
+        let key = Self::ensure_self_managed(origin)?;
 
         // --- 2. Ensure that the lengths of the module_keys and amounts are the same
         ensure!(
@@ -131,8 +132,8 @@ impl<T: Config> Pallet<T> {
         new_module_key: T::AccountId,
         amount: u64,
     ) -> dispatch::DispatchResult {
-        // --- 1. We check that the transaction is signed by the caller and retrieve the
-        let key = ensure_signed(origin.clone())?;
+        // --- 1. We check that the transaction is signed by a key managing itself
+        let key = Self::ensure_self_managed(origin)?;
 
         // --- 2. Check if both modules are registered
         // --- 2.1 old module check
@@ -157,10 +158,33 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughStakeToWithdraw
         );
 
-        // --- 4. Remove stake from the source module and add it to the destination module
-        Self::do_remove_stake(origin.clone(), module_key.clone(), amount)?;
-        // don't allow zero stakes
-        Self::do_add_stake(origin.clone(), new_module_key, amount)?;
+        // --- 4. Move the stake from the source module to the destination module.
+        // Under an unbonding period the stake goes through the unbonding queue like a removal, and
+        // is staked on the destination module once it is withdrawn, so it can't hop between
+        // modules right before an epoch.
+        let unbonding_period = Self::get_unbonding_period(&module_key)
+            .max(Self::get_unbonding_period(&new_module_key));
+
+        // --- 4.1 Stake locked by conviction votes can be moved, but can't leave the stake
+        if unbonding_period != 0 {
+            Self::ensure_stake_unlocked(&key, amount)?;
+        }
+
+        Self::decrease_stake(&key, &module_key, amount);
+        Self::deposit_event(Event::StakeRemoved(key.clone(), module_key.clone(), amount));
+
+        if unbonding_period == 0 {
+            Self::increase_stake(&key, &new_module_key, amount);
+            Self::deposit_event(Event::StakeAdded(key, new_module_key, amount));
+        } else {
+            Self::schedule_unlock(
+                &key,
+                &module_key,
+                amount,
+                unbonding_period,
+                Some(new_module_key),
+            )?;
+        }
 
         // --- 5. Done and ok
         Ok(())
@@ -171,9 +195,9 @@ impl<T: Config> Pallet<T> {
         module_key: T::AccountId,
         amount: u64,
     ) -> dispatch::DispatchResult {
-        // --- 1. We check that the transaction is signed by the caller and retrieve the
-        // T::AccountId key information.
-        let key = ensure_signed(origin)?;
+        // --- 1. We check that the transaction is signed by a key managing itself and retrieve
+        // the T::AccountId key information.
+        let key = Self::ensure_self_managed(origin)?;
 
         // --- 2. We check that the caller has enough balance to stake.
         ensure!(
@@ -242,9 +266,9 @@ impl<T: Config> Pallet<T> {
         module_key: T::AccountId,
         amount: u64,
     ) -> dispatch::DispatchResult {
-        // --- 1. We check the transaction is signed by the caller and retrieve the T::AccountId key
-        // information.
-        let key = ensure_signed(origin)?;
+        // --- 1. We check the transaction is signed by a key managing itself and retrieve the
+        // T::AccountId key information.
+        let key = Self::ensure_self_managed(origin)?;
 
         // --- 2. We check that the caller has enough stake in the module.
         ensure!(
@@ -258,6 +282,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::StakeTooSmall
         );
 
+        // --- 2.2 Stake locked by conviction votes can't be withdrawn
+        Self::ensure_stake_unlocked(&key, amount)?;
+
         // --- 3. We check that the module is registered.
         ensure!(
             Self::is_registered(None, &module_key.clone()),
@@ -275,12 +302,21 @@ impl<T: Config> Pallet<T> {
         let stake_before_remove: u64 = Self::get_stake_to_module(&key, &module_key.clone());
         let balance_before_remove: u64 = Self::get_balance_u64(&key);
         let module_stake_before_remove: u64 = Self::get_delegated_stake(&module_key);
+        let unbonding_period = Self::get_unbonding_period(&module_key);
 
         // --- 6. We remove the balance from the key.
         Self::decrease_stake(&key, &module_key, amount);
 
         // --- 7. We add the balancer to the key. If the above fails we will not credit this key.
-        Self::add_balance_to_account(&key, Self::u64_to_balance(amount).unwrap());
+        // If the module is under an unbonding period, the amount is queued instead, and can only
+        // be withdrawn once the period is over.
+        let expected_balance_after_remove = if unbonding_period == 0 {
+            Self::add_balance_to_account(&key, Self::u64_to_balance(amount).unwrap());
+            balance_before_remove.saturating_add(amount)
+        } else {
+            Self::schedule_unlock(&key, &module_key, amount, unbonding_period, None)?;
+            balance_before_remove
+        };
 
         // --- 8. Check after values
         let stake_after_remove: u64 = Self::get_stake_to_module(&key, &module_key.clone());
@@ -294,7 +330,7 @@ impl<T: Config> Pallet<T> {
             Error::<T>::StakeNotRemoved
         );
         ensure!(
-            balance_after_remove == balance_before_remove.saturating_add(amount),
+            balance_after_remove == expected_balance_after_remove,
             Error::<T>::BalanceNotAdded
         );
         ensure!(
@@ -308,24 +344,274 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Withdraws all of the caller's unbonded stake whose unbonding period is over. Transferred
+    /// stake is staked on its destination module instead, or paid out if the module is gone.
+    pub fn do_withdraw_unbonded(origin: T::RuntimeOrigin) -> dispatch::DispatchResult {
+        // --- 1. We check the transaction is signed by a key managing itself.
+        let key = Self::ensure_self_managed(origin)?;
+
+        // --- 2. Split the pending unlocks into the ones that can be withdrawn and the rest.
+        let current_block = Self::get_current_block_number();
+        let (unlocked, pending): (Vec<_>, Vec<_>) = PendingUnlocks::<T>::get(&key)
+            .into_iter()
+            .partition(|unlock| unlock.unlock_block <= current_block);
+        ensure!(!unlocked.is_empty(), Error::<T>::NoUnbondedStake);
+
+        let total_unlocked: u64 = unlocked.iter().map(|unlock| unlock.amount).sum();
+
+        // --- 3. Update the queue and the unbonding counter.
+        if pending.is_empty() {
+            PendingUnlocks::<T>::remove(&key);
+        } else {
+            PendingUnlocks::<T>::insert(&key, pending);
+        }
+        TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(total_unlocked));
+
+        // --- 4. Restake the transfers and credit the staker with the rest.
+        let mut amount: u64 = 0;
+        for unlock in unlocked {
+            match unlock.restake_to {
+                Some(module_key) if Self::is_registered(None, &module_key) => {
+                    Self::increase_stake(&key, &module_key, unlock.amount);
+                    Self::deposit_event(Event::StakeAdded(key.clone(), module_key, unlock.amount));
+                }
+                _ => amount = amount.saturating_add(unlock.amount),
+            }
+        }
+
+        if amount > 0 {
+            let amount_as_currency =
+                Self::u64_to_balance(amount).ok_or(Error::<T>::CouldNotConvertToBalance)?;
+            Self::add_balance_to_account(&key, amount_as_currency);
+            Self::deposit_event(Event::UnbondedWithdrawn(key, amount));
+        }
+
+        // --- 5. Done and ok.
+        Ok(())
+    }
+
+    /// Sets where the key's emission rewards are paid from the next epoch on. Module keys with a
+    /// controller are managed by it.
+    pub fn do_set_payout_preference(
+        origin: T::RuntimeOrigin,
+        key: T::AccountId,
+        preference: PayoutPreference<T::AccountId>,
+    ) -> dispatch::DispatchResult {
+        Self::ensure_module_controller(origin, &key)?;
+
+        if preference == PayoutPreference::Compound {
+            PayoutPreferences::<T>::remove(&key);
+        } else {
+            PayoutPreferences::<T>::insert(&key, preference.clone());
+        }
+
+        Self::deposit_event(Event::PayoutPreferenceSet(key, preference));
+
+        Ok(())
+    }
+
+    /// Returns the number of blocks stake removed from the module stays locked for.
+    /// Modules registered on multiple subnets use the longest period among them.
+    pub fn get_unbonding_period(module_key: &T::AccountId) -> u64 {
+        let global_period = UnbondingPeriod::<T>::get();
+
+        N::<T>::iter_keys()
+            .filter(|netuid| Uids::<T>::contains_key(netuid, module_key))
+            .map(|netuid| SubnetUnbondingPeriod::<T>::get(netuid).unwrap_or(global_period))
+            .max()
+            .unwrap_or(global_period)
+    }
+
+    pub fn get_pending_unlocks(staker: &T::AccountId) -> Vec<PendingUnlock<T>> {
+        PendingUnlocks::<T>::get(staker)
+    }
+
+    /// Queues the amount removed from the module, so it can be withdrawn after the unbonding
+    /// period. Queued stake is no longer part of `TotalStake` and earns nothing. Transferred
+    /// stake carries the module it is staked on once withdrawn.
+    fn schedule_unlock(
+        staker: &T::AccountId,
+        module_key: &T::AccountId,
+        amount: u64,
+        unbonding_period: u64,
+        restake_to: Option<T::AccountId>,
+    ) -> dispatch::DispatchResult {
+        let unlock_block = Self::get_current_block_number().saturating_add(unbonding_period);
+
+        PendingUnlocks::<T>::try_mutate(staker, |unlocks| -> dispatch::DispatchResult {
+            // Removals from the same module within a block share the same unlock entry.
+            if let Some(unlock) = unlocks.iter_mut().find(|unlock| {
+                unlock.unlock_block == unlock_block
+                    && &unlock.module_key == module_key
+                    && unlock.restake_to == restake_to
+            }) {
+                unlock.amount = unlock.amount.saturating_add(amount);
+                return Ok(());
+            }
+
+            ensure!(
+                unlocks.len() < MAX_PENDING_UNLOCKS,
+                Error::<T>::TooManyPendingUnlocks
+            );
+            unlocks.push(PendingUnlock {
+                module_key: module_key.clone(),
+                amount,
+                unlock_block,
+                restake_to,
+            });
+            Ok(())
+        })?;
+
+        TotalUnbonding::<T>::mutate(|total| *total = total.saturating_add(amount));
+
+        Self::deposit_event(Event::StakeUnbonding(
+            staker.clone(),
+            module_key.clone(),
+            amount,
+            unlock_block,
+        ));
+
+        Ok(())
+    }
+
     pub fn get_total_subnet_stake(netuid: u16) -> u64 {
         Keys::<T>::iter_prefix(netuid)
             .map(|(_, account_id)| Self::get_delegated_stake(&account_id))
             .sum()
     }
 
-    // Returns the delegation fee of a module
+    // Returns the delegation fee of a module, taken from the dividends shared with its stakers
     pub fn get_delegation_fee(module_key: &T::AccountId) -> Percent {
         let min_deleg_fee_global = FloorDelegationFee::<T>::get();
-        let delegation_fee = DelegationFee::<T>::get(module_key);
+        let delegation_fee = Self::get_active_delegation_fee(module_key);
 
         delegation_fee.max(min_deleg_fee_global)
     }
 
+    // Returns the miner delegation fee of a module, taken from the incentive shared with its
+    // stakers
+    pub fn get_miner_delegation_fee(module_key: &T::AccountId) -> Percent {
+        let min_deleg_fee_global = FloorDelegationFee::<T>::get();
+        let delegation_fee = Self::get_active_miner_delegation_fee(module_key);
+
+        delegation_fee.max(min_deleg_fee_global)
+    }
+
+    /// Returns the fee in effect at the current block, which is the pending one once its notice
+    /// period is over.
+    pub fn get_active_delegation_fee(module_key: &T::AccountId) -> Percent {
+        Self::active_fee::<DelegationFee<T>, PendingDelegationFee<T>>(module_key)
+    }
+
+    pub fn get_active_miner_delegation_fee(module_key: &T::AccountId) -> Percent {
+        Self::active_fee::<MinerDelegationFee<T>, PendingMinerDelegationFee<T>>(module_key)
+    }
+
+    /// Returns the announced fee increase that is not in effect yet, with the block it takes
+    /// effect at.
+    pub fn get_pending_delegation_fee(module_key: &T::AccountId) -> Option<(Percent, u64)> {
+        Self::pending_fee::<PendingDelegationFee<T>>(module_key)
+    }
+
+    pub fn get_pending_miner_delegation_fee(module_key: &T::AccountId) -> Option<(Percent, u64)> {
+        Self::pending_fee::<PendingMinerDelegationFee<T>>(module_key)
+    }
+
+    pub fn schedule_delegation_fee(module_key: &T::AccountId, fee: Percent) {
+        if let Some(effective_block) =
+            Self::schedule_fee::<DelegationFee<T>, PendingDelegationFee<T>>(module_key, fee)
+        {
+            Self::deposit_event(Event::DelegationFeeScheduled(
+                module_key.clone(),
+                fee,
+                effective_block,
+            ));
+        }
+    }
+
+    pub fn schedule_miner_delegation_fee(module_key: &T::AccountId, fee: Percent) {
+        if let Some(effective_block) = Self::schedule_fee::<
+            MinerDelegationFee<T>,
+            PendingMinerDelegationFee<T>,
+        >(module_key, fee)
+        {
+            Self::deposit_event(Event::MinerDelegationFeeScheduled(
+                module_key.clone(),
+                fee,
+                effective_block,
+            ));
+        }
+    }
+
+    fn active_fee<Active, Pending>(module_key: &T::AccountId) -> Percent
+    where
+        Active: StorageMapT<T::AccountId, Percent, Query = Percent>,
+        Pending: StorageMapT<T::AccountId, (Percent, u64), Query = Option<(Percent, u64)>>,
+    {
+        match Pending::get(module_key) {
+            Some((fee, effective_block)) if effective_block <= Self::get_current_block_number() => {
+                fee
+            }
+            _ => Active::get(module_key),
+        }
+    }
+
+    fn pending_fee<Pending>(module_key: &T::AccountId) -> Option<(Percent, u64)>
+    where
+        Pending: StorageMapT<T::AccountId, (Percent, u64), Query = Option<(Percent, u64)>>,
+    {
+        Pending::get(module_key)
+            .filter(|(_, effective_block)| *effective_block > Self::get_current_block_number())
+    }
+
+    /// Lowering a fee takes effect right away. Raising it is queued for the notice period, so
+    /// delegators can move their stake before the new fee applies. Returns the block the fee
+    /// takes effect at when it was queued.
+    fn schedule_fee<Active, Pending>(module_key: &T::AccountId, fee: Percent) -> Option<u64>
+    where
+        Active: StorageMapT<T::AccountId, Percent, Query = Percent>,
+        Pending: StorageMapT<T::AccountId, (Percent, u64), Query = Option<(Percent, u64)>>,
+    {
+        let active_fee = Self::active_fee::<Active, Pending>(module_key);
+        let pending = Self::pending_fee::<Pending>(module_key);
+
+        // Module updates resend the current fee, which must not touch an announced increase, and
+        // announcing the same increase again doesn't restart the notice period
+        if fee == active_fee || pending.is_some_and(|(pending_fee, _)| pending_fee == fee) {
+            return None;
+        }
+
+        if fee < active_fee {
+            Active::insert(module_key, fee);
+            Pending::remove(module_key);
+            return None;
+        }
+
+        // A fee already in effect is settled before queueing the new one
+        Active::insert(module_key, active_fee);
+
+        let effective_block =
+            Self::get_current_block_number().saturating_add(DelegationFeeNoticePeriod::<T>::get());
+        Pending::insert(module_key, (fee, effective_block));
+
+        Some(effective_block)
+    }
+
     pub fn has_enough_stake(key: &T::AccountId, module_key: &T::AccountId, amount: u64) -> bool {
         amount > 0 && Self::get_stake_to_module(key, module_key) >= amount
     }
 
+    /// Conviction votes lock the stake a key owns, whatever modules it is staked on, so the stake
+    /// left after removing the amount must still cover the locks.
+    fn ensure_stake_unlocked(key: &T::AccountId, amount: u64) -> dispatch::DispatchResult {
+        let remaining = Self::get_owned_stake(key).saturating_sub(amount);
+        ensure!(
+            remaining >= T::get_locked_stake(key),
+            Error::<T>::StakeIsLocked
+        );
+        Ok(())
+    }
+
     pub fn get_stake_to_module(key: &T::AccountId, module_key: &T::AccountId) -> u64 {
         Self::get_stake_to_vector(key)
             .into_iter()
@@ -347,6 +633,8 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn increase_stake(staker: &T::AccountId, staked: &T::AccountId, amount: u64) -> bool {
+        T::handle_voting_power_change(staker, amount, true);
+
         StakeFrom::<T>::mutate(staked, staker, |stake| {
             *stake = stake.saturating_add(amount);
         });
@@ -360,7 +648,50 @@ impl<T: Config> Pallet<T> {
         true
     }
 
+    /// Credits an emission reward earned by `staker` on `module_key`, following the staker's
+    /// payout preference. Returns the account the reward was paid to, or `None` if it was
+    /// compounded into the stake.
+    pub fn pay_reward(
+        staker: &T::AccountId,
+        module_key: &T::AccountId,
+        amount: u64,
+    ) -> Option<T::AccountId> {
+        let destination = match PayoutPreferences::<T>::get(staker) {
+            PayoutPreference::Compound => None,
+            PayoutPreference::PayToBalance => Some(staker.clone()),
+            PayoutPreference::PayToAccount(destination) => Some(destination),
+        };
+
+        match destination.zip(Self::u64_to_balance(amount)) {
+            Some((destination, balance)) => {
+                Self::add_balance_to_account(&destination, balance);
+                Some(destination)
+            }
+            None => {
+                Self::increase_stake(staker, module_key, amount);
+                None
+            }
+        }
+    }
+
+    /// Emits one event per staker and destination for the rewards paid out as balance on an
+    /// epoch.
+    pub fn deposit_reward_payouts(
+        netuid: u16,
+        payouts: BTreeMap<(T::AccountId, T::AccountId), u64>,
+    ) {
+        for ((staker, destination), amount) in payouts {
+            if amount == 0 {
+                continue;
+            }
+            Self::deposit_event(Event::RewardsPaidOut(netuid, staker, destination, amount));
+        }
+    }
+
     pub fn decrease_stake(staker: &T::AccountId, staked: &T::AccountId, amount: u64) {
+        let decreased = StakeTo::<T>::get(staker, staked).min(amount);
+        T::handle_voting_power_change(staker, decreased, false);
+
         let stake_from = StakeFrom::<T>::mutate(staked, staker, |stake| {
             *stake = stake.saturating_sub(amount);
             *stake
@@ -380,6 +711,30 @@ impl<T: Config> Pallet<T> {
         TotalStake::<T>::mutate(|total_stake| *total_stake = total_stake.saturating_sub(amount));
     }
 
+    /// Moves every stake the key holds or receives to the new key, including the stake it holds
+    /// on itself. The new key must not hold or receive any stake, and `TotalStake` is unchanged.
+    pub fn move_stake(key: &T::AccountId, new_key: &T::AccountId) {
+        let stakers: Vec<_> = StakeFrom::<T>::drain_prefix(key).collect();
+        for (staker, stake) in stakers {
+            StakeTo::<T>::remove(&staker, key);
+
+            let staker = if &staker == key {
+                new_key.clone()
+            } else {
+                staker
+            };
+            StakeFrom::<T>::insert(new_key, &staker, stake);
+            StakeTo::<T>::insert(&staker, new_key, stake);
+        }
+
+        let staked_modules: Vec<_> = StakeTo::<T>::drain_prefix(key).collect();
+        for (staked, stake) in staked_modules {
+            StakeFrom::<T>::remove(&staked, key);
+            StakeFrom::<T>::insert(&staked, new_key, stake);
+            StakeTo::<T>::insert(new_key, &staked, stake);
+        }
+    }
+
     // Decreases the stake by the amount while decreasing other counters.
     pub fn remove_stake_from_storage(staked: &T::AccountId) {
         // Get all stakers for this staked account
@@ -1,8 +1,11 @@
 use super::*;
 
 use frame_support::{
-    pallet_prelude::DispatchResult, storage::IterableStorageMap, IterableStorageDoubleMap,
+    pallet_prelude::{DispatchResult, MaxEncodedLen},
+    storage::IterableStorageMap,
+    IterableStorageDoubleMap,
 };
+use pallet_governance_api::GovernanceConfigurationPatch;
 use pallet_subnet_emission_api::SubnetConsensus;
 
 use self::global::BurnConfiguration;
@@ -26,6 +29,20 @@ impl<T: Config> SubnetChangeset<T> {
     }
 
     pub fn update(netuid: u16, params: SubnetParams<T>) -> Result<Self, DispatchError> {
+        let changeset = Self::update_with_founder(netuid, params)?;
+        ensure!(
+            changeset.params.founder == Founder::<T>::get(netuid),
+            Error::<T>::FounderChangeNotAllowed
+        );
+        Ok(changeset)
+    }
+
+    /// Like [`Self::update`], but the founder can be changed too. Only governance subnet params
+    /// proposals change it this way, founders offer their subnet with `propose_subnet_ownership`.
+    pub fn update_with_founder(
+        netuid: u16,
+        params: SubnetParams<T>,
+    ) -> Result<Self, DispatchError> {
         Self::validate_params(Some(netuid), &params)?;
         Ok(Self { params })
     }
@@ -34,6 +51,11 @@ impl<T: Config> SubnetChangeset<T> {
         Self::validate_params(Some(netuid), &self.params)?;
         Pallet::<T>::set_max_allowed_uids(netuid, self.params.max_allowed_uids)?;
         SubnetNames::<T>::insert(netuid, self.params.name.into_inner());
+        if Founder::<T>::get(netuid) != self.params.founder {
+            // Offers and updates scheduled by the previous founder are void
+            PendingSubnetOwnership::<T>::remove(netuid);
+            ScheduledSubnetUpdates::<T>::remove(netuid);
+        }
         Founder::<T>::insert(netuid, &self.params.founder);
         FounderShare::<T>::insert(netuid, self.params.founder_share);
         Tempo::<T>::insert(netuid, self.params.tempo);
@@ -56,6 +78,19 @@ impl<T: Config> SubnetChangeset<T> {
 
         AdjustmentAlpha::<T>::insert(netuid, self.params.adjustment_alpha);
         MinValidatorStake::<T>::insert(netuid, self.params.min_validator_stake);
+        SubnetUnbondingPeriod::<T>::set(netuid, self.params.unbonding_period);
+        if self.params.commit_reveal_weights && !CommitRevealWeights::<T>::get(netuid) {
+            // Weights set in plaintext could have been copied, only revealed weights
+            // are used once commit-reveal is enabled. They are cleared over the next blocks.
+            let enabled_at = Pallet::<T>::get_current_block_number();
+            PlaintextWeightsClearing::<T>::insert(netuid, (enabled_at, N::<T>::get(netuid)));
+        }
+        if !self.params.commit_reveal_weights {
+            // Commits can't be revealed anymore, and expire on their own
+            PlaintextWeightsClearing::<T>::remove(netuid);
+        }
+        CommitRevealWeights::<T>::insert(netuid, self.params.commit_reveal_weights);
+        RecordEpochEmission::<T>::insert(netuid, self.params.record_epoch_emission);
         if self.params.maximum_set_weight_calls_per_epoch == 0 {
             MaximumSetWeightCallsPerEpoch::<T>::remove(netuid);
         } else {
@@ -163,6 +198,11 @@ impl<T: Config> SubnetChangeset<T> {
             Error::<T>::InvalidMinValidatorStake
         );
 
+        ensure!(
+            params.unbonding_period.map_or(true, |period| period <= MAX_UNBONDING_PERIOD),
+            Error::<T>::InvalidUnbondingPeriod
+        );
+
         match Pallet::<T>::get_netuid_for_name(&params.name) {
             Some(id) if netuid.is_some_and(|netuid| netuid == id) => { /* subnet kept same name */ }
             Some(_) => return Err(Error::<T>::SubnetNameAlreadyExists.into()),
@@ -181,6 +221,89 @@ impl<T: Config> SubnetChangeset<T> {
     }
 }
 
+/// A partial update of the subnet params. Fields left as `None` keep their current value.
+#[derive(
+    Decode,
+    Encode,
+    PartialEq,
+    Eq,
+    Clone,
+    frame_support::DebugNoBound,
+    frame_support::DefaultNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct SubnetParamsPatch<T: Config> {
+    pub founder: Option<T::AccountId>,
+    pub founder_share: Option<u16>,
+    pub immunity_period: Option<u16>,
+    pub incentive_ratio: Option<u16>,
+    pub max_allowed_uids: Option<u16>,
+    pub max_allowed_weights: Option<u16>,
+    pub min_allowed_weights: Option<u16>,
+    pub max_weight_age: Option<u64>,
+    pub name: Option<BoundedVec<u8, ConstU32<256>>>,
+    pub metadata: Option<Option<BoundedVec<u8, ConstU32<59>>>>,
+    pub tempo: Option<u16>,
+    pub trust_ratio: Option<u16>,
+    pub maximum_set_weight_calls_per_epoch: Option<u16>,
+    pub bonds_ma: Option<u64>,
+    pub target_registrations_interval: Option<u16>,
+    pub target_registrations_per_interval: Option<u16>,
+    pub max_registrations_per_interval: Option<u16>,
+    pub adjustment_alpha: Option<u64>,
+    pub min_validator_stake: Option<u64>,
+    pub unbonding_period: Option<Option<u64>>,
+    pub commit_reveal_weights: Option<bool>,
+    pub record_epoch_emission: Option<bool>,
+    pub governance_config: GovernanceConfigurationPatch,
+}
+
+impl<T: Config> SubnetParamsPatch<T> {
+    /// Returns the params with the fields set in the patch replaced.
+    #[must_use]
+    pub fn merge(self, mut params: SubnetParams<T>) -> SubnetParams<T> {
+        macro_rules! merge {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if let Some(value) = self.$field {
+                        params.$field = value;
+                    }
+                )*
+            };
+        }
+
+        merge!(
+            founder,
+            founder_share,
+            immunity_period,
+            incentive_ratio,
+            max_allowed_uids,
+            max_allowed_weights,
+            min_allowed_weights,
+            max_weight_age,
+            name,
+            metadata,
+            tempo,
+            trust_ratio,
+            maximum_set_weight_calls_per_epoch,
+            bonds_ma,
+            target_registrations_interval,
+            target_registrations_per_interval,
+            max_registrations_per_interval,
+            adjustment_alpha,
+            min_validator_stake,
+            unbonding_period,
+            commit_reveal_weights,
+            record_epoch_emission,
+        );
+        params.governance_config = self.governance_config.merge(params.governance_config);
+
+        params
+    }
+}
+
 impl<T: Config> Pallet<T> {
     pub fn subnet_params(netuid: u16) -> SubnetParams<T> {
         SubnetParams {
@@ -205,6 +328,9 @@ impl<T: Config> Pallet<T> {
             min_validator_stake: MinValidatorStake::<T>::get(netuid),
             governance_config: T::get_subnet_governance_configuration(netuid),
             metadata: SubnetMetadata::<T>::get(netuid),
+            unbonding_period: SubnetUnbondingPeriod::<T>::get(netuid),
+            commit_reveal_weights: CommitRevealWeights::<T>::get(netuid),
+            record_epoch_emission: RecordEpochEmission::<T>::get(netuid),
         }
     }
 
@@ -285,6 +411,10 @@ impl<T: Config> Pallet<T> {
         for (key, _) in Uids::<T>::iter_prefix(netuid) {
             if !keys_in_other_netuids.contains(&key) {
                 DelegationFee::<T>::remove(&key);
+                PendingDelegationFee::<T>::remove(&key);
+                MinerDelegationFee::<T>::remove(&key);
+                PendingMinerDelegationFee::<T>::remove(&key);
+                ModuleController::<T>::remove(&key);
             }
         }
 
@@ -296,6 +426,7 @@ impl<T: Config> Pallet<T> {
 
         let _ = Weights::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = WeightSetAt::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = WeightCommits::<T>::clear_prefix(netuid, u32::MAX, None);
         Active::<T>::remove(netuid);
         Consensus::<T>::remove(netuid);
         Dividends::<T>::remove(netuid);
@@ -314,6 +445,8 @@ impl<T: Config> Pallet<T> {
         // ===============================
 
         Founder::<T>::remove(netuid);
+        PendingSubnetOwnership::<T>::remove(netuid);
+        ScheduledSubnetUpdates::<T>::remove(netuid);
         FounderShare::<T>::remove(netuid);
         Tempo::<T>::remove(netuid);
         ImmunityPeriod::<T>::remove(netuid);
@@ -330,6 +463,10 @@ impl<T: Config> Pallet<T> {
         MaxRegistrationsPerInterval::<T>::remove(netuid);
         AdjustmentAlpha::<T>::remove(netuid);
         MinValidatorStake::<T>::remove(netuid);
+        SubnetUnbondingPeriod::<T>::remove(netuid);
+        CommitRevealWeights::<T>::remove(netuid);
+        PlaintextWeightsClearing::<T>::remove(netuid);
+        RecordEpochEmission::<T>::remove(netuid);
         SubnetRegistrationBlock::<T>::remove(netuid);
         SubnetMetadata::<T>::remove(netuid);
 
@@ -375,6 +512,199 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Offers the subnet to a new founder, who has `SUBNET_OWNERSHIP_OFFER_PERIOD` blocks to
+    /// accept it. A new offer replaces the previous one, and the founder can withdraw it with
+    /// `cancel_subnet_ownership_offer`.
+    pub fn do_propose_subnet_ownership(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        new_founder: T::AccountId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(
+            SubnetNames::<T>::contains_key(netuid),
+            Error::<T>::NetuidDoesNotExist
+        );
+        ensure!(Founder::<T>::get(netuid) == key, Error::<T>::NotFounder);
+        ensure!(new_founder != key, Error::<T>::AlreadySubnetFounder);
+
+        let expiration_block =
+            Self::get_current_block_number().saturating_add(SUBNET_OWNERSHIP_OFFER_PERIOD);
+        PendingSubnetOwnership::<T>::insert(netuid, (&new_founder, expiration_block));
+
+        Self::deposit_event(Event::SubnetOwnershipProposed(
+            netuid,
+            new_founder,
+            expiration_block,
+        ));
+        Ok(())
+    }
+
+    /// Hands the subnet, and with it the founder share, over to the caller it was offered to.
+    pub fn do_accept_subnet_ownership(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let (new_founder, expiration_block) =
+            PendingSubnetOwnership::<T>::get(netuid).ok_or(Error::<T>::NoSubnetOwnershipOffer)?;
+        ensure!(new_founder == key, Error::<T>::NoSubnetOwnershipOffer);
+        ensure!(
+            Self::get_current_block_number() <= expiration_block,
+            Error::<T>::SubnetOwnershipOfferExpired
+        );
+
+        PendingSubnetOwnership::<T>::remove(netuid);
+        ScheduledSubnetUpdates::<T>::remove(netuid);
+        let old_founder = Founder::<T>::get(netuid);
+        Founder::<T>::insert(netuid, &key);
+
+        Self::deposit_event(Event::SubnetOwnershipTransferred(netuid, old_founder, key));
+        Ok(())
+    }
+
+    /// Withdraws the founder's pending offer of the subnet.
+    pub fn do_cancel_subnet_ownership_offer(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(Founder::<T>::get(netuid) == key, Error::<T>::NotFounder);
+        ensure!(
+            PendingSubnetOwnership::<T>::contains_key(netuid),
+            Error::<T>::NoSubnetOwnershipOffer
+        );
+
+        PendingSubnetOwnership::<T>::remove(netuid);
+
+        Self::deposit_event(Event::SubnetOwnershipOfferCancelled(netuid));
+        Ok(())
+    }
+
+    /// Drops the ownership offer of the subnet once it can no longer be accepted. Returns the
+    /// weight consumed.
+    pub(crate) fn clear_expired_subnet_ownership_offer(netuid: u16, block_number: u64) -> Weight {
+        match PendingSubnetOwnership::<T>::get(netuid) {
+            Some((_, expiration_block)) if expiration_block < block_number => {
+                PendingSubnetOwnership::<T>::remove(netuid);
+                Self::deposit_event(Event::SubnetOwnershipOfferCancelled(netuid));
+                T::DbWeight::get().writes(1)
+            }
+            _ => Weight::zero(),
+        }
+    }
+
+    /// Schedules the patch to be applied to the subnet at `at_block`, so modules can prepare for
+    /// it. A new schedule replaces the previous one.
+    pub fn do_schedule_subnet_update(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        patch: SubnetParamsPatch<T>,
+        at_block: u64,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(
+            SubnetNames::<T>::contains_key(netuid),
+            Error::<T>::NetuidDoesNotExist
+        );
+        ensure!(Founder::<T>::get(netuid) == key, Error::<T>::NotFounder);
+        ensure!(
+            at_block > Self::get_current_block_number(),
+            Error::<T>::InvalidScheduledBlock
+        );
+
+        // The patch is validated again when applied, as the subnet may change in between
+        SubnetChangeset::<T>::update(netuid, patch.clone().merge(Self::subnet_params(netuid)))?;
+
+        ScheduledSubnetUpdates::<T>::insert(netuid, (at_block, patch));
+
+        Self::deposit_event(Event::SubnetUpdateScheduled(netuid, at_block));
+        Ok(())
+    }
+
+    pub fn do_cancel_scheduled_subnet_update(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(Founder::<T>::get(netuid) == key, Error::<T>::NotFounder);
+        ensure!(
+            ScheduledSubnetUpdates::<T>::contains_key(netuid),
+            Error::<T>::NoScheduledSubnetUpdate
+        );
+
+        ScheduledSubnetUpdates::<T>::remove(netuid);
+
+        Self::deposit_event(Event::ScheduledSubnetUpdateCancelled(netuid));
+        Ok(())
+    }
+
+    /// Applies the update scheduled for the subnet if its block was reached. Updates that are no
+    /// longer valid are dropped. Returns the weight consumed.
+    pub(crate) fn apply_scheduled_subnet_update(netuid: u16, block_number: u64) -> Weight {
+        let Some((at_block, patch)) = ScheduledSubnetUpdates::<T>::get(netuid) else {
+            return Weight::zero();
+        };
+        if at_block > block_number {
+            return Weight::zero();
+        }
+
+        ScheduledSubnetUpdates::<T>::remove(netuid);
+
+        let params = patch.merge(Self::subnet_params(netuid));
+        let result = frame_support::storage::with_storage_layer(|| {
+            SubnetChangeset::<T>::update(netuid, params)?.apply(netuid)
+        });
+
+        match result {
+            Ok(()) => Self::deposit_event(Event::SubnetParamsUpdated(netuid)),
+            Err(err) => {
+                log::warn!("dropped scheduled update of subnet {netuid}: {err:?}");
+                Self::deposit_event(Event::ScheduledSubnetUpdateCancelled(netuid));
+            }
+        }
+
+        T::WeightInfo::apply_scheduled_subnet_update()
+    }
+
+    /// Clears a bounded number of the weights set in plaintext before the subnet enabled
+    /// commit-reveal, going down from its highest uid. Weights revealed since are kept.
+    /// Deregistrations move the highest uid down, so the uids left to clear stay below the
+    /// cursor. Returns the weight consumed.
+    pub(crate) fn clear_plaintext_weights(netuid: u16) -> Weight {
+        let Some((enabled_at, next_uid)) = PlaintextWeightsClearing::<T>::get(netuid) else {
+            return Weight::zero();
+        };
+
+        let next_uid = next_uid.min(N::<T>::get(netuid));
+        let last_uid = next_uid.saturating_sub(PLAINTEXT_WEIGHTS_CLEARED_PER_BLOCK);
+        for uid in last_uid..next_uid {
+            let set_at = WeightSetAt::<T>::get(netuid, uid);
+            if set_at.map_or(true, |set_at| set_at <= enabled_at) {
+                Weights::<T>::remove(netuid, uid);
+                WeightSetAt::<T>::remove(netuid, uid);
+            }
+        }
+
+        if last_uid == 0 {
+            PlaintextWeightsClearing::<T>::remove(netuid);
+        } else {
+            PlaintextWeightsClearing::<T>::insert(netuid, (enabled_at, last_uid));
+        }
+
+        let cleared = u64::from(next_uid.saturating_sub(last_uid));
+        T::DbWeight::get().reads_writes(
+            cleared.saturating_add(1),
+            cleared.saturating_mul(2).saturating_add(1),
+        )
+    }
+
+    pub fn get_scheduled_subnet_update(netuid: u16) -> Option<(u64, SubnetParamsPatch<T>)> {
+        ScheduledSubnetUpdates::<T>::get(netuid)
+    }
+
     // ---------------------------------
     // Setters
     // ---------------------------------
@@ -583,6 +913,20 @@ impl<T: Config> Pallet<T> {
             .unwrap_or(1000)
     }
 
+    /// Returns the index of the subnet epoch the current block belongs to. The index increases
+    /// on the same blocks the subnet epoch runs on.
+    pub fn get_current_epoch(netuid: u16) -> u64 {
+        Self::get_epoch_at(netuid, Self::get_current_block_number())
+    }
+
+    pub fn get_epoch_at(netuid: u16, block_number: u64) -> u64 {
+        let tempo = Tempo::<T>::get(netuid);
+
+        (block_number.saturating_add(u64::from(netuid)))
+            .checked_div(u64::from(tempo))
+            .unwrap_or_default()
+    }
+
     pub fn is_rootnet(netuid: u16) -> bool {
         matches!(
             T::get_subnet_consensus_type(netuid),
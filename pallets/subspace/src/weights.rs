@@ -1,7 +1,11 @@
 
-//! Autogenerated weights for `pallet_subspace`
+//! Weights for `pallet_subspace`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! Only the functions without an "Estimated by hand" note come from the benchmark run below.
+//! The others were estimated by hand after it and have to be regenerated with the same command
+//! before a release.
+//!
+//! BENCHMARK CLI VERSION 32.0.0
 //! DATE: 2024-07-31, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `pop-os`, CPU: `AMD Ryzen 9 5900X 12-Core Processor`
@@ -47,6 +51,22 @@ pub trait WeightInfo {
 	fn update_module() -> Weight;
 	fn update_subnet() -> Weight;
 	fn delegate_rootnet_control() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn commit_weights() -> Weight;
+	fn reveal_weights() -> Weight;
+	fn set_payout_preference() -> Weight;
+	fn adjust_registration_parameters(s: u32, ) -> Weight;
+	fn copy_delegated_weights(d: u32, w: u32, ) -> Weight;
+	fn rotate_module_key(s: u32, t: u32, d: u32, ) -> Weight;
+	fn set_module_controller() -> Weight;
+	fn propose_subnet_ownership() -> Weight;
+	fn accept_subnet_ownership() -> Weight;
+	fn cancel_subnet_ownership_offer() -> Weight;
+	fn schedule_subnet_update() -> Weight;
+	fn cancel_scheduled_subnet_update() -> Weight;
+	fn apply_scheduled_subnet_update() -> Weight;
+	fn bid_subnet_slot() -> Weight;
+	fn close_subnet_slot_auction() -> Weight;
 }
 
 /// Weights for `pallet_subspace` using the Substrate node and recommended hardware.
@@ -586,6 +606,331 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `SubspaceModule::PendingUnlocks` (r:1 w:1)
+	/// Proof: `SubspaceModule::PendingUnlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TotalUnbonding` (r:1 w:1)
+	/// Proof: `SubspaceModule::TotalUnbonding` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn withdraw_unbonded() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(24_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubspaceModule::CommitRevealWeights` (r:1 w:0)
+	/// Proof: `SubspaceModule::CommitRevealWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:1 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinValidatorStake` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinValidatorStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaximumSetWeightCallsPerEpoch` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaximumSetWeightCallsPerEpoch` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::WeightCommits` (r:0 w:1)
+	/// Proof: `SubspaceModule::WeightCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SetWeightCallsPerEpoch` (r:1 w:1)
+	/// Proof: `SubspaceModule::SetWeightCallsPerEpoch` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn commit_weights() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(32_000_000, 1102)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubspaceModule::CommitRevealWeights` (r:1 w:0)
+	/// Proof: `SubspaceModule::CommitRevealWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::WeightCommits` (r:1 w:1)
+	/// Proof: `SubspaceModule::WeightCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:1 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinAllowedWeights` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinAllowedWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxAllowedWeights` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxAllowedWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:1 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:2 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinWeightStake` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinWeightStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::LastUpdate` (r:1 w:1)
+	/// Proof: `SubspaceModule::LastUpdate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Weights` (r:0 w:1)
+	/// Proof: `SubspaceModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::WeightSetAt` (r:0 w:1)
+	/// Proof: `SubspaceModule::WeightSetAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reveal_weights() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(59_000_000, 1387)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SubspaceModule::PayoutPreferences` (r:0 w:1)
+	/// Proof: `SubspaceModule::PayoutPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_payout_preference() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetBurnConfig` (r:1 w:0)
+	/// Proof: `SubspaceModule::SubnetBurnConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetBurn` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetBurn` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetRegistrationsThisInterval` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetRegistrationsThisInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RegistrationsPerBlock` (r:1 w:1)
+	/// Proof: `SubspaceModule::RegistrationsPerBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Tempo` (r:257 w:0)
+	/// Proof: `SubspaceModule::Tempo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::BurnConfig` (r:1 w:0)
+	/// Proof: `SubspaceModule::BurnConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Burn` (r:256 w:256)
+	/// Proof: `SubspaceModule::Burn` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TargetRegistrationsInterval` (r:256 w:0)
+	/// Proof: `SubspaceModule::TargetRegistrationsInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RegistrationsThisInterval` (r:256 w:256)
+	/// Proof: `SubspaceModule::RegistrationsThisInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TargetRegistrationsPerInterval` (r:256 w:0)
+	/// Proof: `SubspaceModule::TargetRegistrationsPerInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::AdjustmentAlpha` (r:256 w:0)
+	/// Proof: `SubspaceModule::AdjustmentAlpha` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 256]`.
+	fn adjust_registration_parameters(s: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(10_000_000, 3806)
+			.saturating_add(Weight::from_parts(4_318_207, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2597).saturating_mul(s.into()))
+	}
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:2 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RootnetControlDelegation` (r:257 w:0)
+	/// Proof: `SubspaceModule::RootnetControlDelegation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:512 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Weights` (r:256 w:256)
+	/// Proof: `SubspaceModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `d` is `[0, 256]`.
+	/// The range of component `w` is `[1, 256]`.
+	fn copy_delegated_weights(d: u32, w: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(5_000_000, 3723)
+			.saturating_add(Weight::from_parts(11_894_635, 0).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(48_000, 0).saturating_mul(d.into()).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 5106).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(0, 8).saturating_mul(d.into()).saturating_mul(w.into()))
+	}
+	/// Storage: `SubspaceModule::ModuleController` (r:2 w:2)
+	/// Proof: `SubspaceModule::ModuleController` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:257 w:2)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:257 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeTo` (r:257 w:512)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:257 w:512)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingUnlocks` (r:2 w:2)
+	/// Proof: `SubspaceModule::PendingUnlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:0 w:1)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Metadata` (r:1 w:2)
+	/// Proof: `SubspaceModule::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::WeightCommits` (r:1 w:0)
+	/// Proof: `SubspaceModule::WeightCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::DelegationFee` (r:2 w:2)
+	/// Proof: `SubspaceModule::DelegationFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingDelegationFee` (r:2 w:0)
+	/// Proof: `SubspaceModule::PendingDelegationFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinerDelegationFee` (r:2 w:2)
+	/// Proof: `SubspaceModule::MinerDelegationFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingMinerDelegationFee` (r:2 w:0)
+	/// Proof: `SubspaceModule::PendingMinerDelegationFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PayoutPreferences` (r:2 w:0)
+	/// Proof: `SubspaceModule::PayoutPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RootnetControlDelegation` (r:1 w:256)
+	/// Proof: `SubspaceModule::RootnetControlDelegation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RootnetControlDelegators` (r:257 w:512)
+	/// Proof: `SubspaceModule::RootnetControlDelegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotingPowerDelegations` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotingPowerDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotingPowerDelegators` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotingPowerDelegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotingPowerDelegationCheckpoints` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotingPowerDelegationCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DelegatedVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::DelegatedVotingPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DelegatedVotingPowerCheckpoints` (r:1 w:0)
+	/// Proof: `GovernanceModule::DelegatedVotingPowerCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotedProposals` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VoteLocks` (r:1 w:0)
+	/// Proof: `GovernanceModule::VoteLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotingPowerCheckpoints` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotingPowerCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::LegitWhitelist` (r:1 w:0)
+	/// Proof: `GovernanceModule::LegitWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::OpenProposals` (r:1 w:0)
+	/// Proof: `GovernanceModule::OpenProposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::ReclaimedVotingPower` (r:128 w:0)
+	/// Proof: `GovernanceModule::ReclaimedVotingPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 256]`.
+	/// The range of component `t` is `[2, 256]`.
+	/// The range of component `d` is `[0, 256]`.
+	fn rotate_module_key(s: u32, t: u32, d: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(48_000_000, 1534)
+			.saturating_add(Weight::from_parts(2_100_000, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(9_400_000, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(7_800_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(159_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 2597).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 2548).saturating_mul(d.into()))
+	}
+	/// Storage: `SubspaceModule::ModuleController` (r:1 w:1)
+	/// Proof: `SubspaceModule::ModuleController` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:1 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_module_controller() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(15_000_000, 612)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetNames` (r:1 w:0)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:0)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingSubnetOwnership` (r:0 w:1)
+	/// Proof: `SubspaceModule::PendingSubnetOwnership` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_subnet_ownership() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(16_000_000, 702)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::PendingSubnetOwnership` (r:1 w:1)
+	/// Proof: `SubspaceModule::PendingSubnetOwnership` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:1)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_subnet_ownership() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(15_000_000, 566)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubspaceModule::Founder` (r:1 w:0)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingSubnetOwnership` (r:1 w:1)
+	/// Proof: `SubspaceModule::PendingSubnetOwnership` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_subnet_ownership_offer() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(14_000_000, 735)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetNames` (r:2 w:0)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:0)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::ScheduledSubnetUpdates` (r:0 w:1)
+	/// Proof: `SubspaceModule::ScheduledSubnetUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_subnet_update() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(59_000_000, 1842)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::Founder` (r:1 w:0)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::ScheduledSubnetUpdates` (r:1 w:1)
+	/// Proof: `SubspaceModule::ScheduledSubnetUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_scheduled_subnet_update() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(14_000_000, 735)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::ScheduledSubnetUpdates` (r:1 w:1)
+	/// Proof: `SubspaceModule::ScheduledSubnetUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetNames` (r:2 w:1)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:1)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxAllowedUids` (r:1 w:1)
+	/// Proof: `SubspaceModule::MaxAllowedUids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::CommitRevealWeights` (r:1 w:1)
+	/// Proof: `SubspaceModule::CommitRevealWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn apply_scheduled_subnet_update() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(105_000_000, 1951)
+			.saturating_add(T::DbWeight::get().reads(27_u64))
+			.saturating_add(T::DbWeight::get().writes(21_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetAuctionConfig` (r:1 w:0)
+	/// Proof: `SubspaceModule::SubnetAuctionConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxAllowedSubnets` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxAllowedSubnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetNames` (r:1 w:0)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinNameLength` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinNameLength` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxNameLength` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxNameLength` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetSlotAuction` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetSlotAuction` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bid_subnet_slot() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(62_000_000, 1523)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetSlotAuction` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetSlotAuction` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxAllowedSubnets` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxAllowedSubnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:2 w:2)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetNames` (r:2 w:2)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:2)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetGaps` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetGaps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn close_subnet_slot_auction() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(413_000_000, 3617)
+			.saturating_add(T::DbWeight::get().reads(62_u64))
+			.saturating_add(T::DbWeight::get().writes(64_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1124,4 +1469,329 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `SubspaceModule::PendingUnlocks` (r:1 w:1)
+	/// Proof: `SubspaceModule::PendingUnlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TotalUnbonding` (r:1 w:1)
+	/// Proof: `SubspaceModule::TotalUnbonding` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn withdraw_unbonded() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(24_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubspaceModule::CommitRevealWeights` (r:1 w:0)
+	/// Proof: `SubspaceModule::CommitRevealWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:1 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinValidatorStake` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinValidatorStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaximumSetWeightCallsPerEpoch` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaximumSetWeightCallsPerEpoch` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::WeightCommits` (r:0 w:1)
+	/// Proof: `SubspaceModule::WeightCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SetWeightCallsPerEpoch` (r:1 w:1)
+	/// Proof: `SubspaceModule::SetWeightCallsPerEpoch` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn commit_weights() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(32_000_000, 1102)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubspaceModule::CommitRevealWeights` (r:1 w:0)
+	/// Proof: `SubspaceModule::CommitRevealWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::WeightCommits` (r:1 w:1)
+	/// Proof: `SubspaceModule::WeightCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:1 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinAllowedWeights` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinAllowedWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxAllowedWeights` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxAllowedWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:1 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:2 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinWeightStake` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinWeightStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::LastUpdate` (r:1 w:1)
+	/// Proof: `SubspaceModule::LastUpdate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Weights` (r:0 w:1)
+	/// Proof: `SubspaceModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::WeightSetAt` (r:0 w:1)
+	/// Proof: `SubspaceModule::WeightSetAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reveal_weights() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(59_000_000, 1387)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SubspaceModule::PayoutPreferences` (r:0 w:1)
+	/// Proof: `SubspaceModule::PayoutPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_payout_preference() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetBurnConfig` (r:1 w:0)
+	/// Proof: `SubspaceModule::SubnetBurnConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetBurn` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetBurn` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetRegistrationsThisInterval` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetRegistrationsThisInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RegistrationsPerBlock` (r:1 w:1)
+	/// Proof: `SubspaceModule::RegistrationsPerBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Tempo` (r:257 w:0)
+	/// Proof: `SubspaceModule::Tempo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::BurnConfig` (r:1 w:0)
+	/// Proof: `SubspaceModule::BurnConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Burn` (r:256 w:256)
+	/// Proof: `SubspaceModule::Burn` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TargetRegistrationsInterval` (r:256 w:0)
+	/// Proof: `SubspaceModule::TargetRegistrationsInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RegistrationsThisInterval` (r:256 w:256)
+	/// Proof: `SubspaceModule::RegistrationsThisInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TargetRegistrationsPerInterval` (r:256 w:0)
+	/// Proof: `SubspaceModule::TargetRegistrationsPerInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::AdjustmentAlpha` (r:256 w:0)
+	/// Proof: `SubspaceModule::AdjustmentAlpha` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 256]`.
+	fn adjust_registration_parameters(s: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(10_000_000, 3806)
+			.saturating_add(Weight::from_parts(4_318_207, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2597).saturating_mul(s.into()))
+	}
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:2 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RootnetControlDelegation` (r:257 w:0)
+	/// Proof: `SubspaceModule::RootnetControlDelegation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:512 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Weights` (r:256 w:256)
+	/// Proof: `SubspaceModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `d` is `[0, 256]`.
+	/// The range of component `w` is `[1, 256]`.
+	fn copy_delegated_weights(d: u32, w: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(5_000_000, 3723)
+			.saturating_add(Weight::from_parts(11_894_635, 0).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(48_000, 0).saturating_mul(d.into()).saturating_mul(w.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 5106).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(0, 8).saturating_mul(d.into()).saturating_mul(w.into()))
+	}
+	/// Storage: `SubspaceModule::ModuleController` (r:2 w:2)
+	/// Proof: `SubspaceModule::ModuleController` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:257 w:2)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:257 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeTo` (r:257 w:512)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:257 w:512)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingUnlocks` (r:2 w:2)
+	/// Proof: `SubspaceModule::PendingUnlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:0 w:1)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Metadata` (r:1 w:2)
+	/// Proof: `SubspaceModule::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::WeightCommits` (r:1 w:0)
+	/// Proof: `SubspaceModule::WeightCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::DelegationFee` (r:2 w:2)
+	/// Proof: `SubspaceModule::DelegationFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingDelegationFee` (r:2 w:0)
+	/// Proof: `SubspaceModule::PendingDelegationFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinerDelegationFee` (r:2 w:2)
+	/// Proof: `SubspaceModule::MinerDelegationFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingMinerDelegationFee` (r:2 w:0)
+	/// Proof: `SubspaceModule::PendingMinerDelegationFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PayoutPreferences` (r:2 w:0)
+	/// Proof: `SubspaceModule::PayoutPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RootnetControlDelegation` (r:1 w:256)
+	/// Proof: `SubspaceModule::RootnetControlDelegation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RootnetControlDelegators` (r:257 w:512)
+	/// Proof: `SubspaceModule::RootnetControlDelegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotingPowerDelegations` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotingPowerDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotingPowerDelegators` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotingPowerDelegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotingPowerDelegationCheckpoints` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotingPowerDelegationCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DelegatedVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::DelegatedVotingPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DelegatedVotingPowerCheckpoints` (r:1 w:0)
+	/// Proof: `GovernanceModule::DelegatedVotingPowerCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotedProposals` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VoteLocks` (r:1 w:0)
+	/// Proof: `GovernanceModule::VoteLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::VotingPowerCheckpoints` (r:1 w:0)
+	/// Proof: `GovernanceModule::VotingPowerCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::LegitWhitelist` (r:1 w:0)
+	/// Proof: `GovernanceModule::LegitWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::OpenProposals` (r:1 w:0)
+	/// Proof: `GovernanceModule::OpenProposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::ReclaimedVotingPower` (r:128 w:0)
+	/// Proof: `GovernanceModule::ReclaimedVotingPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 256]`.
+	/// The range of component `t` is `[2, 256]`.
+	/// The range of component `d` is `[0, 256]`.
+	fn rotate_module_key(s: u32, t: u32, d: u32, ) -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(48_000_000, 1534)
+			.saturating_add(Weight::from_parts(2_100_000, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(9_400_000, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(7_800_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(159_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 2597).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 2548).saturating_mul(d.into()))
+	}
+	/// Storage: `SubspaceModule::ModuleController` (r:1 w:1)
+	/// Proof: `SubspaceModule::ModuleController` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:1 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_module_controller() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(15_000_000, 612)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetNames` (r:1 w:0)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:0)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingSubnetOwnership` (r:0 w:1)
+	/// Proof: `SubspaceModule::PendingSubnetOwnership` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_subnet_ownership() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(16_000_000, 702)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::PendingSubnetOwnership` (r:1 w:1)
+	/// Proof: `SubspaceModule::PendingSubnetOwnership` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:1)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_subnet_ownership() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(15_000_000, 566)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubspaceModule::Founder` (r:1 w:0)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::PendingSubnetOwnership` (r:1 w:1)
+	/// Proof: `SubspaceModule::PendingSubnetOwnership` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_subnet_ownership_offer() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(14_000_000, 735)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetNames` (r:2 w:0)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:0)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::ScheduledSubnetUpdates` (r:0 w:1)
+	/// Proof: `SubspaceModule::ScheduledSubnetUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_subnet_update() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(59_000_000, 1842)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::Founder` (r:1 w:0)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::ScheduledSubnetUpdates` (r:1 w:1)
+	/// Proof: `SubspaceModule::ScheduledSubnetUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_scheduled_subnet_update() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(14_000_000, 735)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SubspaceModule::ScheduledSubnetUpdates` (r:1 w:1)
+	/// Proof: `SubspaceModule::ScheduledSubnetUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetNames` (r:2 w:1)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:1)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxAllowedUids` (r:1 w:1)
+	/// Proof: `SubspaceModule::MaxAllowedUids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::CommitRevealWeights` (r:1 w:1)
+	/// Proof: `SubspaceModule::CommitRevealWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn apply_scheduled_subnet_update() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(105_000_000, 1951)
+			.saturating_add(RocksDbWeight::get().reads(27_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetAuctionConfig` (r:1 w:0)
+	/// Proof: `SubspaceModule::SubnetAuctionConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxAllowedSubnets` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxAllowedSubnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetNames` (r:1 w:0)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MinNameLength` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinNameLength` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxNameLength` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxNameLength` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetSlotAuction` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetSlotAuction` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bid_subnet_slot() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(62_000_000, 1523)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubspaceModule::SubnetSlotAuction` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetSlotAuction` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::MaxAllowedSubnets` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxAllowedSubnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:2 w:2)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetNames` (r:2 w:2)
+	/// Proof: `SubspaceModule::SubnetNames` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Founder` (r:1 w:2)
+	/// Proof: `SubspaceModule::Founder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetGaps` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetGaps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn close_subnet_slot_auction() -> Weight {
+		// Estimated by hand, not measured by the benchmark yet.
+		Weight::from_parts(413_000_000, 3617)
+			.saturating_add(RocksDbWeight::get().reads(62_u64))
+			.saturating_add(RocksDbWeight::get().writes(64_u64))
+	}
 }
\ No newline at end of file
@@ -17,7 +17,7 @@ use pallet_governance_api::GovernanceConfiguration;
 use pallet_grandpa::{
     fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
-use pallet_subnet_emission_api::SubnetConsensus;
+use pallet_subnet_emission_api::{SubnetConsensus, SubnetPricingMechanism};
 use smallvec::smallvec;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -33,7 +33,10 @@ use sp_runtime::{
 };
 use sp_std::{collections::btree_set::BTreeSet, prelude::*};
 use sp_version::RuntimeVersion;
-use subspace_runtime_api::{ModuleInfo, ModuleParams, ModuleStats};
+use subspace_runtime_api::{
+    DelegationFeeChange, EpochEmission, MetagraphModule, ModuleEmission, ModuleInfo, ModuleParams,
+    ModuleStats, PendingUnlock, ScheduledSubnetUpdate, SubnetMetagraph, SubnetParamsChanges,
+};
 
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
@@ -64,6 +67,7 @@ pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
 // Subspace module
+pub use pallet_subnet_emission;
 pub use pallet_subspace;
 
 // An index to a block.
@@ -111,7 +115,11 @@ pub mod opaque {
     }
 }
 
-pub type Migrations = pallet_subspace::migrations::v13::MigrateToV13<Runtime>;
+pub type Migrations = (
+    pallet_subspace::migrations::v13::MigrateToV13<Runtime>,
+    pallet_subspace::migrations::v14::MigrateToV14<Runtime>,
+    pallet_governance::migrations::MigrationV2<Runtime>,
+);
 
 // To learn more about runtime versioning, see:
 // https://docs.substrate.io/main-docs/build/upgrade#runtime-versioning
@@ -125,10 +133,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 120,
+    spec_version: 121,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -370,6 +378,8 @@ impl pallet_governance::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type WeightInfo = pallet_governance::weights::SubstrateWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type ProposalOrigin = RuntimeOrigin;
 }
 
 #[cfg(feature = "testnet-faucet")]
@@ -385,6 +395,7 @@ impl pallet_subnet_emission::Config for Runtime {
     type Decimals = ConstU8<9>; // The runtime has 9 token decimals
     type HalvingInterval = ConstU64<250_000_000>;
     type MaxSupply = ConstU64<1_000_000_000>;
+    type WeightInfo = pallet_subnet_emission::weights::SubstrateWeight<Runtime>;
 }
 
 pub const WEIGHT_MILLISECS_PER_BLOCK: u64 = 2000;
@@ -541,6 +552,7 @@ mod benches {
         [pallet_balances, Balances]
         [pallet_subspace, SubspaceModule]
         [pallet_governance, GovernanceModule]
+        [pallet_subnet_emission, SubnetEmissionModule]
         [pallet_timestamp, Timestamp]
         [pallet_utility, Utility]
     );
@@ -714,6 +726,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl subspace_runtime_api::SubspaceRuntimeApi<Block> for Runtime {
         fn get_module_info(key: AccountId, netuid: u16) -> ModuleInfo {
             let stats = SubspaceModule::get_module_stats(netuid, &key);
@@ -733,10 +746,125 @@ impl_runtime_apis! {
                     name: params.name,
                     address: params.address,
                     delegation_fee: params.delegation_fee,
+                    miner_delegation_fee: params.miner_delegation_fee,
                     controller: params.controller,
-                }
+                },
+                pending_delegation_fee: SubspaceModule::get_pending_delegation_fee(&key).map(
+                    |(fee, effective_block)| DelegationFeeChange {
+                        fee,
+                        effective_block,
+                    },
+                ),
+                pending_miner_delegation_fee: SubspaceModule::get_pending_miner_delegation_fee(
+                    &key,
+                )
+                .map(|(fee, effective_block)| DelegationFeeChange {
+                    fee,
+                    effective_block,
+                }),
             }
         }
+
+        fn get_pending_unlocks(key: AccountId) -> Vec<PendingUnlock> {
+            SubspaceModule::get_pending_unlocks(&key)
+                .into_iter()
+                .map(|unlock| PendingUnlock {
+                    module_key: unlock.module_key,
+                    amount: unlock.amount,
+                    unlock_block: unlock.unlock_block,
+                    restake_to: unlock.restake_to,
+                })
+                .collect()
+        }
+
+        fn get_subnet_metagraph(netuid: u16) -> SubnetMetagraph {
+            let modules = SubspaceModule::get_subnet_metagraph(netuid)
+                .into_iter()
+                .map(|module| MetagraphModule {
+                    uid: module.uid,
+                    key: module.key,
+                    name: module.name,
+                    address: module.address,
+                    stake: module.stake,
+                    incentive: module.incentive,
+                    dividends: module.dividends,
+                    trust: module.trust,
+                    rank: module.rank,
+                    consensus: module.consensus,
+                    validator_permit: module.validator_permit,
+                    last_update: module.last_update,
+                    emission: module.emission,
+                })
+                .collect();
+
+            SubnetMetagraph { netuid, modules }
+        }
+
+        fn get_last_epoch_emission(netuid: u16) -> Option<EpochEmission> {
+            pallet_subnet_emission::LastEpochEmission::<Runtime>::get(netuid).map(|record| {
+                EpochEmission {
+                    netuid,
+                    block: record.block,
+                    founder_emission: record.founder_emission,
+                    entries: record
+                        .entries
+                        .into_iter()
+                        .map(|entry| ModuleEmission {
+                            module_key: entry.module_key,
+                            staker: entry.staker,
+                            amount: entry.amount,
+                            fee: entry.fee,
+                        })
+                        .collect(),
+                    truncated: record.truncated,
+                }
+            })
+        }
+
+        fn get_scheduled_subnet_update(netuid: u16) -> Option<ScheduledSubnetUpdate> {
+            SubspaceModule::get_scheduled_subnet_update(netuid).map(|(at_block, patch)| {
+                ScheduledSubnetUpdate {
+                    netuid,
+                    at_block,
+                    changes: SubnetParamsChanges {
+                        founder: patch.founder,
+                        founder_share: patch.founder_share,
+                        immunity_period: patch.immunity_period,
+                        incentive_ratio: patch.incentive_ratio,
+                        max_allowed_uids: patch.max_allowed_uids,
+                        max_allowed_weights: patch.max_allowed_weights,
+                        min_allowed_weights: patch.min_allowed_weights,
+                        max_weight_age: patch.max_weight_age,
+                        name: patch.name.map(|name| name.into_inner()),
+                        metadata: patch
+                            .metadata
+                            .map(|metadata| metadata.map(|metadata| metadata.into_inner())),
+                        tempo: patch.tempo,
+                        trust_ratio: patch.trust_ratio,
+                        maximum_set_weight_calls_per_epoch: patch
+                            .maximum_set_weight_calls_per_epoch,
+                        vote_mode: patch.vote_mode.map(|vote_mode| match vote_mode {
+                            pallet_governance_api::VoteMode::Authority => {
+                                subspace_runtime_api::VoteMode::Authority
+                            }
+                            pallet_governance_api::VoteMode::Vote => {
+                                subspace_runtime_api::VoteMode::Vote
+                            }
+                        }),
+                        bonds_ma: patch.bonds_ma,
+                        target_registrations_interval: patch.target_registrations_interval,
+                        target_registrations_per_interval: patch
+                            .target_registrations_per_interval,
+                        max_registrations_per_interval: patch.max_registrations_per_interval,
+                        adjustment_alpha: patch.adjustment_alpha,
+                        min_validator_stake: patch.min_validator_stake,
+                        unbonding_period: patch.unbonding_period,
+                        commit_reveal_weights: patch.commit_reveal_weights,
+                        record_epoch_emission: patch.record_epoch_emission,
+                    },
+                }
+            })
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -885,6 +1013,14 @@ impl pallet_subnet_emission_api::SubnetEmissionApi for Runtime {
     ) {
         pallet_subnet_emission::SubnetConsensusType::<Runtime>::set(netuid, subnet_consensus)
     }
+
+    fn get_subnet_pricing_mechanism() -> SubnetPricingMechanism {
+        pallet_subnet_emission::SubnetPricing::<Runtime>::get()
+    }
+
+    fn schedule_subnet_pricing_mechanism(mechanism: SubnetPricingMechanism) -> u64 {
+        SubnetEmissionModule::schedule_subnet_pricing_mechanism(mechanism)
+    }
 }
 
 impl pallet_governance_api::GovernanceApi<<Runtime as frame_system::Config>::AccountId>
@@ -894,12 +1030,8 @@ impl pallet_governance_api::GovernanceApi<<Runtime as frame_system::Config>::Acc
         pallet_governance::DaoTreasuryAddress::<Runtime>::get()
     }
 
-    fn is_delegating_voting_power(delegator: &AccountId) -> bool {
-        GovernanceModule::is_delegating_voting_power(delegator)
-    }
-
-    fn update_delegating_voting_power(delegator: &AccountId, delegating: bool) -> DispatchResult {
-        GovernanceModule::update_delegating_voting_power(delegator, delegating)
+    fn get_locked_stake(key: &AccountId) -> u64 {
+        GovernanceModule::get_locked_stake(key)
     }
 
     fn get_global_governance_configuration() -> GovernanceConfiguration {
@@ -927,6 +1059,32 @@ impl pallet_governance_api::GovernanceApi<<Runtime as frame_system::Config>::Acc
         GovernanceModule::handle_subnet_removal(subnet_id);
     }
 
+    fn handle_module_key_rotation(key: &AccountId, new_key: &AccountId) {
+        GovernanceModule::handle_module_key_rotation(key, new_key);
+    }
+
+    fn has_governance_state(key: &AccountId) -> bool {
+        GovernanceModule::has_governance_state(key)
+    }
+
+    fn module_key_rotation_entries(key: &AccountId, limit: u32) -> u32 {
+        GovernanceModule::module_key_rotation_entries(key, limit)
+    }
+
+    fn module_key_rotation_weight(entries: u32) -> Weight {
+        use pallet_governance::weights::WeightInfo;
+        <Runtime as pallet_governance::Config>::WeightInfo::rotate_module_key(entries)
+    }
+
+    fn handle_voting_power_change(key: &AccountId, amount: u64, increase: bool) {
+        GovernanceModule::handle_voting_power_change(key, amount, increase);
+    }
+
+    fn voting_power_change_weight() -> Weight {
+        use pallet_governance::weights::WeightInfo;
+        <Runtime as pallet_governance::Config>::WeightInfo::handle_voting_power_change()
+    }
+
     fn execute_application(user_id: &AccountId) -> DispatchResult {
         GovernanceModule::execute_application(user_id)
     }
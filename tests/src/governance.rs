@@ -3,13 +3,32 @@
 // ---------
 use crate::mock::*;
 pub use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{
+    pallet_prelude::Get,
+    storage::unhashed,
+    traits::{OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+    weights::Weight,
+    BoundedBTreeSet, BoundedVec,
+};
 use pallet_governance::{
-    dao::ApplicationStatus, proposal::get_reward_allocation, Curator, CuratorApplications,
-    DaoTreasuryAddress, Error, GeneralSubnetApplicationCost, GlobalGovernanceConfig, GovernanceApi,
-    ProposalStatus, Proposals, SubnetGovernanceConfig, VoteMode,
+    dao::ApplicationStatus,
+    migrations::{convert_implicit_delegations, v1, MigrationV2},
+    proposal::{get_reward_allocation, tick_scheduled_runtime_calls},
+    Curator, CuratorApplications, DaoTreasuryAddress, DelegatedVotingPower, DelegationScope, Error,
+    GeneralSubnetApplicationCost, GlobalGovernanceConfig, GovernanceApi, ImplicitDelegationsCursor,
+    OpenProposals, ProposalData, ProposalMetadata, ProposalStatus, Proposals,
+    ScheduledRuntimeCalls, SubnetGovernanceConfig, TreasuryStreams, Vote, VoteLockExpirations,
+    VoteLocks, VoteMode, VotingPowerCheckpoints, VotingPowerDelegations, VotingPowerSnapshots,
+    MAX_CONVICTION, MAX_DELEGATION_SCOPES, MAX_RUNTIME_CALL_SIZE, RUNTIME_CALL_ENACTMENT_DELAY,
+};
+use pallet_governance_api::{GovernanceConfiguration, GovernanceConfigurationPatch, ProposalRules};
+use pallet_subspace::{
+    global::{BurnConfigurationPatch, GlobalParamsPatch},
+    subnet::{SubnetChangeset, SubnetParamsPatch},
+    CommitRevealWeights, GlobalParams, MinimumAllowedStake, SubnetParams, UnbondingPeriod,
 };
-use pallet_governance_api::GovernanceConfiguration;
-use pallet_subspace::{subnet::SubnetChangeset, GlobalParams, SubnetParams};
+use sp_core::H256;
+use sp_runtime::DispatchError;
 use substrate_fixed::{types::extra::U32, FixedI128};
 
 fn register(account: AccountId, subnet_id: u16, module: AccountId, stake: u64) {
@@ -73,15 +92,18 @@ fn global_proposal_validates_parameters() {
                 curator,
                 general_subnet_application_cost,
                 burn_config,
+                subnet_auction_config: _,
                 governance_config,
                 kappa,
                 rho,
                 subnet_immunity_period,
+                unbonding_period,
+                delegation_fee_notice_period,
             } = global_params;
 
             GovernanceMod::add_global_params_proposal(
                 get_origin(KEY),
-                vec![b'0'; 64],
+                proposal_metadata(),
                 max_name_length,
                 min_name_length,
                 max_allowed_subnets,
@@ -100,6 +122,8 @@ fn global_proposal_validates_parameters() {
                 kappa,
                 rho,
                 subnet_immunity_period,
+                unbonding_period,
+                delegation_fee_notice_period,
             )
         };
 
@@ -135,7 +159,7 @@ fn global_custom_proposal_is_accepted_correctly() {
 
         assert_ok!(GovernanceMod::do_add_global_custom_proposal(
             origin,
-            vec![b'0'; 64]
+            proposal_metadata()
         ));
 
         vote(FOR, 0, true);
@@ -173,7 +197,7 @@ fn subnet_custom_proposal_is_accepted_correctly() {
         assert_ok!(GovernanceMod::do_add_subnet_custom_proposal(
             origin,
             0,
-            vec![b'0'; 64]
+            proposal_metadata()
         ));
 
         vote(FOR, 0, true);
@@ -209,7 +233,7 @@ fn global_proposal_is_refused_correctly() {
 
         assert_ok!(GovernanceMod::do_add_global_custom_proposal(
             origin,
-            vec![b'0'; 64]
+            proposal_metadata()
         ));
 
         vote(FOR, 0, true);
@@ -251,17 +275,20 @@ fn global_params_proposal_accepted() {
             curator,
             general_subnet_application_cost,
             burn_config,
+            subnet_auction_config: _,
             mut governance_config,
             rho,
             kappa,
             subnet_immunity_period,
+            unbonding_period,
+            delegation_fee_notice_period,
         } = SubspaceMod::global_params();
 
         governance_config.proposal_cost = 69_420;
 
         GovernanceMod::add_global_params_proposal(
             get_origin(KEY),
-            vec![b'0'; 64],
+            proposal_metadata(),
             max_name_length,
             min_name_length,
             max_allowed_subnets,
@@ -280,6 +307,8 @@ fn global_params_proposal_accepted() {
             kappa,
             rho,
             subnet_immunity_period,
+            unbonding_period,
+            delegation_fee_notice_period,
         )
         .unwrap();
 
@@ -330,6 +359,9 @@ fn subnet_params_proposal_accepted() {
             max_registrations_per_interval,
             adjustment_alpha,
             min_validator_stake,
+            unbonding_period,
+            commit_reveal_weights,
+            record_epoch_emission,
             mut governance_config,
             ..
         } = SubspaceMod::subnet_params(0);
@@ -339,7 +371,7 @@ fn subnet_params_proposal_accepted() {
         GovernanceMod::add_subnet_params_proposal(
             get_origin(KEY),
             0,
-            vec![b'0'; 64],
+            proposal_metadata(),
             founder,
             name,
             founder_share,
@@ -359,16 +391,119 @@ fn subnet_params_proposal_accepted() {
             max_registrations_per_interval,
             adjustment_alpha,
             min_validator_stake,
+            unbonding_period,
+            commit_reveal_weights,
+            record_epoch_emission,
+        )
+        .unwrap();
+
+        vote(KEY, 0, true);
+        step_block(100);
+
+        assert_eq!(
+            SubnetGovernanceConfig::<Test>::get(0).vote_mode,
+            VoteMode::Authority
+        );
+    });
+}
+
+#[test]
+fn params_patch_proposals_are_merged_on_execution() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const KEY: u32 = 0;
+
+        register(KEY, 0, 0, to_nano(10));
+        config(1, 100);
+        // A burn config the merged global params can be applied with
+        pallet_subspace::BurnConfig::<Test>::mutate(|config| config.min_burn = to_nano(1));
+
+        SubnetChangeset::update(
+            0,
+            SubnetParams {
+                governance_config: Default::default(),
+                ..SubspaceMod::subnet_params(0)
+            },
         )
+        .unwrap()
+        .apply(0)
         .unwrap();
 
+        assert_ok!(GovernanceMod::add_global_params_patch_proposal(
+            get_origin(KEY),
+            proposal_metadata(),
+            GlobalParamsPatch {
+                burn_config: BurnConfigurationPatch {
+                    max_burn: Some(to_nano(300)),
+                    ..Default::default()
+                },
+                governance_config: GovernanceConfigurationPatch {
+                    proposal_cost: Some(69_420),
+                    // The global params are only valid with a longer expiration
+                    proposal_expiration: Some(200),
+                    early_finalization: Some(true),
+                    treasury_proposal_rules: Some(ProposalRules::new(60, 70)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ));
+        assert_ok!(GovernanceMod::add_subnet_params_patch_proposal(
+            get_origin(KEY),
+            0,
+            proposal_metadata(),
+            SubnetParamsPatch {
+                governance_config: GovernanceConfigurationPatch {
+                    vote_mode: Some(VoteMode::Authority),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ));
+        assert_err!(
+            GovernanceMod::add_subnet_params_patch_proposal(
+                get_origin(KEY),
+                0,
+                proposal_metadata(),
+                SubnetParamsPatch {
+                    tempo: Some(0),
+                    ..Default::default()
+                },
+            ),
+            pallet_subspace::Error::<Test>::InvalidTempo
+        );
+
+        // Changed while the proposals are open, must survive their execution
+        let max_allowed_weights = SubspaceMod::global_params().max_allowed_weights - 1;
+        pallet_subspace::MaxAllowedWeightsGlobal::<Test>::set(max_allowed_weights);
+        let tempo = SubspaceMod::subnet_params(0).tempo + 1;
+        pallet_subspace::Tempo::<Test>::insert(0, tempo);
+
         vote(KEY, 0, true);
+        vote(KEY, 1, true);
         step_block(100);
 
+        let global_governance_config = GlobalGovernanceConfig::<Test>::get();
+        assert_eq!(global_governance_config.proposal_cost, 69_420);
+        assert!(global_governance_config.early_finalization);
+        assert_eq!(
+            global_governance_config.treasury_proposal_rules,
+            ProposalRules::new(60, 70)
+        );
+        assert_eq!(global_governance_config.proposal_expiration, 200);
+        let burn_config = pallet_subspace::BurnConfig::<Test>::get();
+        assert_eq!(burn_config.max_burn, to_nano(300));
+        assert_eq!(burn_config.min_burn, to_nano(1));
+        assert_eq!(
+            SubspaceMod::global_params().max_allowed_weights,
+            max_allowed_weights
+        );
         assert_eq!(
             SubnetGovernanceConfig::<Test>::get(0).vote_mode,
             VoteMode::Authority
         );
+        assert_eq!(SubspaceMod::subnet_params(0).tempo, tempo);
     });
 }
 
@@ -385,19 +520,18 @@ fn global_proposals_counts_delegated_stake() {
         let origin = get_origin(0);
 
         register(FOR, 0, 0, to_nano(5));
-        delegate(FOR);
         register(AGAINST, 0, 1, to_nano(10));
 
         stake(FOR_DELEGATED, 0, to_nano(10));
-        delegate(FOR_DELEGATED);
+        delegate(FOR_DELEGATED, FOR, DelegationScope::All);
         stake(AGAINST_DELEGATED, 1, to_nano(3));
-        delegate(AGAINST_DELEGATED);
+        delegate(AGAINST_DELEGATED, AGAINST, DelegationScope::Global);
 
         config(1, 100);
 
         assert_ok!(GovernanceMod::do_add_global_custom_proposal(
             origin,
-            vec![b'0'; 64]
+            proposal_metadata()
         ));
 
         vote(FOR, 0, true);
@@ -430,27 +564,28 @@ fn subnet_proposals_counts_delegated_stake() {
 
         let origin = get_origin(0);
 
-        register(FOR, 0, 0, to_nano(5));
-        register(FOR, 1, 0, to_nano(5));
+        register(FOR, 0, 0, to_nano(10));
+        register(FOR, 1, 0, to_nano(10));
         register(AGAINST, 0, 1, to_nano(10));
         register(AGAINST, 1, 1, to_nano(10));
 
         stake(FOR_DELEGATED, 0, to_nano(10));
-        delegate(FOR_DELEGATED);
+        delegate(FOR_DELEGATED, FOR, DelegationScope::Subnet(0));
         stake(AGAINST_DELEGATED, 1, to_nano(3));
-        delegate(AGAINST_DELEGATED);
+        delegate(AGAINST_DELEGATED, AGAINST, DelegationScope::All);
 
+        // Delegations scoped to other proposals are not counted
         stake(FOR_DELEGATED_WRONG, 0, to_nano(10));
-        delegate(FOR_DELEGATED_WRONG);
+        delegate(FOR_DELEGATED_WRONG, FOR, DelegationScope::Subnet(1));
         stake(AGAINST_DELEGATED_WRONG, 1, to_nano(3));
-        delegate(AGAINST_DELEGATED_WRONG);
+        delegate(AGAINST_DELEGATED_WRONG, AGAINST, DelegationScope::Global);
 
         config(1, 100);
 
         assert_ok!(GovernanceMod::do_add_subnet_custom_proposal(
             origin,
             0,
-            vec![b'0'; 64]
+            proposal_metadata()
         ));
 
         vote(FOR, 0, true);
@@ -463,159 +598,1411 @@ fn subnet_proposals_counts_delegated_stake() {
             ProposalStatus::Accepted {
                 block: 100,
                 stake_for: 30_000_000_000,
-                stake_against: 26_000_000_000,
+                stake_against: 23_000_000_000,
             }
         );
     });
 }
 
 #[test]
-fn creates_treasury_transfer_proposal_and_transfers() {
+fn treasury_transfers_need_a_supermajority() {
     new_test_ext().execute_with(|| {
         zero_min_burn();
 
-        let origin = get_origin(0);
-        GovernanceMod::add_transfer_dao_treasury_proposal(
-            origin.clone(),
-            vec![b'0'; 64],
-            to_nano(5),
-            0,
-        )
-        .expect_err("proposal should not be created when treasury does not have enough money");
+        const FOR: u32 = 0;
+        const AGAINST: u32 = 1;
 
+        register(FOR, 0, FOR, to_nano(60));
+        register(AGAINST, 0, AGAINST, to_nano(40));
         add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
-        add_balance(0, to_nano(3));
-        register(0, 0, 0, to_nano(1));
-        config(to_nano(1), 100);
 
-        GovernanceMod::add_transfer_dao_treasury_proposal(origin, vec![b'0'; 64], to_nano(5), 0)
-            .expect("proposal should be created");
-        vote(0, 0, true);
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(FOR),
+            proposal_metadata(),
+            to_nano(5),
+            FOR
+        ));
+
+        vote(FOR, 0, true);
+        vote(AGAINST, 0, false);
 
         step_block(100);
 
-        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(5));
-        assert_eq!(get_balance(0), to_nano(8));
+        // 60% of the votes are in favor, short of the 66% required
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Refused {
+                block: 100,
+                stake_for: to_nano(60),
+                stake_against: to_nano(40),
+            }
+        );
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(10));
     });
 }
 
-/// This test, observes the distribution of governance reward logic over time.
 #[test]
-fn rewards_wont_exceed_treasury() {
+fn proposals_expire_without_the_quorum_of_their_kind() {
     new_test_ext().execute_with(|| {
         zero_min_burn();
 
-        // Fill the governance address with 1 mil so we are not limited by the max allocation
-        let amount = to_nano(1_000_000_000);
-        let key = DaoTreasuryAddress::<Test>::get();
-        add_balance(key, amount);
+        const VOTER: u32 = 0;
+        const IDLE: u32 = 1;
+
+        register(VOTER, 0, VOTER, to_nano(60));
+        register(IDLE, 0, IDLE, to_nano(40));
+
+        config(1, 100);
+        GlobalGovernanceConfig::<Test>::mutate(|config| {
+            config.custom_proposal_rules = ProposalRules::new(70, 50);
+        });
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(VOTER),
+            proposal_metadata()
+        ));
+        vote(VOTER, 0, true);
+
+        step_block(100);
 
-        let governance_config: GovernanceConfiguration = GlobalGovernanceConfig::<Test>::get();
-        let n = 0;
-        let allocation = get_reward_allocation::<Test>(&governance_config, n).unwrap();
         assert_eq!(
-            FixedI128::<U32>::saturating_from_num(allocation),
-            governance_config.max_proposal_reward_treasury_allocation
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Expired
+        );
+
+        assert_err!(
+            GovernanceMod::validate(GovernanceConfiguration {
+                treasury_proposal_rules: ProposalRules::new(50, 49),
+                ..Default::default()
+            }),
+            Error::<Test>::InvalidProposalRules
         );
     });
 }
 
 #[test]
-fn whitelist_executes_application_correctly() {
+fn abstentions_count_towards_the_quorum_only() {
     new_test_ext().execute_with(|| {
-        let key = 0;
-        let adding_key = 1;
-        let mut params = SubspaceMod::global_params();
-        params.curator = key;
-        assert_ok!(SubspaceMod::set_global_params(params));
+        zero_min_burn();
 
-        let proposal_cost = GeneralSubnetApplicationCost::<Test>::get();
-        let data = "test".as_bytes().to_vec();
+        const FOR: u32 = 0;
+        const AGAINST: u32 = 1;
+        const ABSTAIN: u32 = 2;
 
-        add_balance(key, proposal_cost + 1);
-        // first submit an application
-        let balance_before = SubspaceMod::get_balance_u64(&key);
+        register(FOR, 0, FOR, to_nano(20));
+        register(AGAINST, 0, AGAINST, to_nano(10));
+        register(ABSTAIN, 0, ABSTAIN, to_nano(30));
 
-        assert_ok!(GovernanceMod::add_dao_application(
-            get_origin(key),
-            adding_key,
-            data.clone(),
+        config(1, 100);
+        GlobalGovernanceConfig::<Test>::mutate(|config| {
+            config.custom_proposal_rules = ProposalRules::new(60, 50);
+        });
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(FOR),
+            proposal_metadata()
         ));
 
-        let balance_after = SubspaceMod::get_balance_u64(&key);
-        assert_eq!(balance_after, balance_before - proposal_cost);
+        vote(FOR, 0, true);
+        vote(AGAINST, 0, false);
+        assert_ok!(GovernanceMod::abstain_proposal(get_origin(ABSTAIN), 0));
+        assert_err!(
+            GovernanceMod::do_vote_proposal(get_origin(ABSTAIN), 0, Vote::For),
+            Error::<Test>::AlreadyVoted
+        );
 
-        // Assert that the proposal is initially in the Pending status
-        for (_, value) in CuratorApplications::<Test>::iter() {
-            assert_eq!(value.status, ApplicationStatus::Pending);
-            assert_eq!(value.user_id, adding_key);
-            assert_eq!(value.data, data);
-        }
+        let ProposalStatus::Open {
+            votes_abstain,
+            stake_abstain,
+            ..
+        } = Proposals::<Test>::get(0).unwrap().status
+        else {
+            panic!("proposal should still be open");
+        };
+        assert!(votes_abstain.contains(&ABSTAIN));
+        assert_eq!(stake_abstain, to_nano(30));
 
-        // add key to whitelist
-        assert_ok!(GovernanceMod::add_to_whitelist(
-            get_origin(key),
-            adding_key,
-            1,
-        ));
+        step_block(100);
 
-        let balance_after_accept = SubspaceMod::get_balance_u64(&key);
+        // The votes for and against alone don't reach the 60% quorum
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 100,
+                stake_for: to_nano(20),
+                stake_against: to_nano(10),
+            }
+        );
+    });
+}
 
-        assert_eq!(balance_after_accept, balance_before);
+#[test]
+fn settled_proposals_are_finalized_early() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
 
-        // Assert that the proposal is now in the Accepted status
-        for (_, value) in CuratorApplications::<Test>::iter() {
-            assert_eq!(value.status, ApplicationStatus::Accepted);
-            assert_eq!(value.user_id, adding_key);
-            assert_eq!(value.data, data);
+        const VOTER: u32 = 0;
+        const IDLE: u32 = 1;
+
+        register(VOTER, 0, VOTER, to_nano(90));
+        register(IDLE, 0, IDLE, to_nano(10));
+
+        config(1, 1_000);
+
+        for _ in 0..4 {
+            assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+                get_origin(VOTER),
+                proposal_metadata()
+            ));
         }
 
-        assert!(GovernanceMod::is_in_legit_whitelist(&adding_key));
+        // Stake added after the proposals were created can't vote on them
+        const LATE: u32 = 2;
+        register(LATE, 0, LATE, to_nano(900));
+
+        let vote_with_conviction = |proposal_id, agree| {
+            assert_ok!(GovernanceMod::vote_proposal_with_conviction(
+                get_origin(VOTER),
+                proposal_id,
+                agree,
+                MAX_CONVICTION
+            ));
+        };
+        vote_with_conviction(0, true);
+        vote_with_conviction(1, false);
+        vote_with_conviction(2, true);
+        vote(IDLE, 2, false);
+        vote(VOTER, 3, true);
+
+        // Disabled by default
+        step_block(100);
+        assert!(Proposals::<Test>::get(0).unwrap().is_active());
+        assert_ok!(GovernanceMod::remove_vote_proposal(get_origin(VOTER), 0));
+        vote_with_conviction(0, true);
+
+        // The votes of settled proposals can't be taken back until they are finalized
+        GlobalGovernanceConfig::<Test>::mutate(|config| config.early_finalization = true);
+        assert_err!(
+            GovernanceMod::remove_vote_proposal(get_origin(VOTER), 0),
+            Error::<Test>::ProposalSettled
+        );
+        assert_ok!(GovernanceMod::remove_vote_proposal(get_origin(VOTER), 3));
+        vote(VOTER, 3, true);
+        step_block(100);
+
+        // Even if all the stake held when it was created voted against it at the highest
+        // conviction, the proposal would pass
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 200,
+                stake_for: to_nano(540),
+                stake_against: 0,
+            }
+        );
+        assert_eq!(
+            Proposals::<Test>::get(1).unwrap().status,
+            ProposalStatus::Refused {
+                block: 200,
+                stake_for: 0,
+                stake_against: to_nano(540),
+            }
+        );
+        assert_eq!(
+            Proposals::<Test>::get(2).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 200,
+                stake_for: to_nano(540),
+                stake_against: to_nano(10),
+            }
+        );
+
+        // Without conviction, the rest of the stake could still outweigh the voter
+        assert!(Proposals::<Test>::get(3).unwrap().is_active());
     });
 }
 
-// ----------------
-// Registration
-// ----------------
+#[test]
+fn proposal_metadata_is_validated() {
+    new_test_ext().execute_with(|| {
+        const KEY: u32 = 0;
+        add_balance(KEY, to_nano(100));
+        config(1, 100);
+
+        let propose =
+            |metadata| GovernanceMod::add_global_custom_proposal(get_origin(KEY), metadata);
+
+        assert_err!(
+            propose(ProposalMetadata {
+                title: b" ".to_vec().try_into().unwrap(),
+                ..proposal_metadata()
+            }),
+            Error::<Test>::InvalidProposalTitle
+        );
+        assert_err!(
+            propose(ProposalMetadata {
+                title: vec![0xff, 0xfe].try_into().unwrap(),
+                ..proposal_metadata()
+            }),
+            Error::<Test>::InvalidProposalTitle
+        );
+        for cid in [
+            &b"QmPK1s3pNYLi9ERiq3BDxKa4XosgWwFRQUydHUtz4Ygpq"[..],
+            b"ipfs://QmPK1s3pNYLi9ERiq3BDxKa4XosgWwFRQUydHUtz4YgpqB",
+            b"bafy0",
+        ] {
+            assert_err!(
+                propose(ProposalMetadata {
+                    cid: cid.to_vec().try_into().unwrap(),
+                    ..proposal_metadata()
+                }),
+                Error::<Test>::InvalidProposalCid
+            );
+        }
+        for url in [
+            &b"http://forum.communeai.org"[..],
+            b"https://",
+            b"https://forum .org",
+        ] {
+            assert_err!(
+                propose(ProposalMetadata {
+                    discussion_url: Some(url.to_vec().try_into().unwrap()),
+                    ..proposal_metadata()
+                }),
+                Error::<Test>::InvalidProposalDiscussionUrl
+            );
+        }
+
+        assert_ok!(propose(ProposalMetadata {
+            cid: b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            discussion_url: None,
+            ..proposal_metadata()
+        }));
+    });
+}
 
 #[test]
-fn user_is_removed_from_whitelist() {
+fn proposals_can_be_amended_until_voting_opens() {
     new_test_ext().execute_with(|| {
-        let whitelist_key = 0;
-        let module_key = 1;
-        Curator::<Test>::put(whitelist_key);
+        zero_min_burn();
 
-        let proposal_cost = Test::get_global_governance_configuration().proposal_cost;
-        let data = "test".as_bytes().to_vec();
+        const PROPOSER: u32 = 0;
+        const VOTER: u32 = 1;
 
-        // apply
-        add_balance(whitelist_key, proposal_cost + 1);
-        // first submit an application
-        assert_ok!(GovernanceMod::add_dao_application(
-            get_origin(whitelist_key),
-            module_key,
-            data.clone(),
-        ));
+        register(VOTER, 0, VOTER, to_nano(10));
+        add_balance(PROPOSER, to_nano(10));
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
 
-        // Add the module_key to the whitelist
-        assert_ok!(GovernanceMod::add_to_whitelist(
-            get_origin(whitelist_key),
-            module_key,
-            1
+        config(1, 100);
+        GlobalGovernanceConfig::<Test>::mutate(|config| config.proposal_amendment_period = 100);
+
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(PROPOSER),
+            proposal_metadata(),
+            to_nano(5),
+            PROPOSER
         ));
-        assert!(GovernanceMod::is_in_legit_whitelist(&module_key));
 
-        // Remove the module_key from the whitelist
-        assert_ok!(GovernanceMod::remove_from_whitelist(
-            get_origin(whitelist_key),
-            module_key
+        let proposal = Proposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.voting_start_block, 100);
+        assert_eq!(proposal.expiration_block, 200);
+
+        assert_err!(
+            GovernanceMod::vote_proposal(get_origin(VOTER), 0, true),
+            Error::<Test>::VotingNotOpen
+        );
+
+        let metadata = ProposalMetadata {
+            title: b"Amended".to_vec().try_into().unwrap(),
+            ..proposal_metadata()
+        };
+        let data = ProposalData::TransferDaoTreasury {
+            account: PROPOSER,
+            amount: to_nano(8),
+        };
+
+        assert_err!(
+            GovernanceMod::amend_proposal(get_origin(VOTER), 0, metadata.clone(), None),
+            Error::<Test>::NotProposer
+        );
+        assert_err!(
+            GovernanceMod::amend_proposal(
+                get_origin(PROPOSER),
+                0,
+                metadata.clone(),
+                Some(ProposalData::GlobalCustom)
+            ),
+            Error::<Test>::InvalidProposalAmendment
+        );
+        assert_err!(
+            GovernanceMod::amend_proposal(
+                get_origin(PROPOSER),
+                0,
+                metadata.clone(),
+                Some(ProposalData::TransferDaoTreasury {
+                    account: PROPOSER,
+                    amount: to_nano(11),
+                })
+            ),
+            Error::<Test>::InsufficientDaoTreasuryFunds
+        );
+        assert_ok!(GovernanceMod::amend_proposal(
+            get_origin(PROPOSER),
+            0,
+            metadata.clone(),
+            Some(data.clone())
         ));
-        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+
+        let proposal = Proposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.metadata, metadata);
+        assert_eq!(proposal.data, data);
+
+        step_block(100);
+
+        assert_err!(
+            GovernanceMod::amend_proposal(get_origin(PROPOSER), 0, proposal_metadata(), None),
+            Error::<Test>::AmendmentPeriodOver
+        );
+        vote(VOTER, 0, true);
+
+        step_block(100);
+
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 200,
+                stake_for: to_nano(10),
+                stake_against: 0,
+            }
+        );
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(2));
     });
 }
 
 #[test]
-fn whitelist_curator_must_be_a_valid_key() {
+fn proposals_can_be_cancelled_by_their_proposer() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const PROPOSER: u32 = 0;
+        const VOTER: u32 = 1;
+
+        register(VOTER, 0, VOTER, to_nano(10));
+        add_balance(PROPOSER, to_nano(20));
+
+        config(to_nano(10), 200);
+
+        // The cost is refunded while nobody voted
+        assert_ok!(GovernanceMod::add_global_custom_proposal(
+            get_origin(PROPOSER),
+            proposal_metadata()
+        ));
+        assert_eq!(get_balance(PROPOSER), to_nano(10));
+        assert_ok!(GovernanceMod::cancel_proposal(get_origin(PROPOSER), 0));
+        assert_eq!(get_balance(PROPOSER), to_nano(20));
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Cancelled { block: 0 }
+        );
+        assert_err!(
+            GovernanceMod::cancel_proposal(get_origin(PROPOSER), 0),
+            Error::<Test>::ProposalIsFinished
+        );
+
+        // Half of it is burned once it was voted on, and the votes no longer lock stake
+        assert_ok!(GovernanceMod::add_global_custom_proposal(
+            get_origin(PROPOSER),
+            proposal_metadata()
+        ));
+        assert_ok!(GovernanceMod::vote_proposal_with_conviction(
+            get_origin(VOTER),
+            1,
+            true,
+            1
+        ));
+        assert!(!VoteLocks::<Test>::get(VOTER).is_empty());
+
+        step_block(50);
+
+        assert_err!(
+            GovernanceMod::cancel_proposal(get_origin(VOTER), 1),
+            Error::<Test>::NotProposer
+        );
+        assert_ok!(GovernanceMod::cancel_proposal(get_origin(PROPOSER), 1));
+
+        assert_eq!(get_balance(PROPOSER), to_nano(15));
+        assert_eq!(
+            Proposals::<Test>::get(1).unwrap().status,
+            ProposalStatus::Cancelled { block: 50 }
+        );
+        assert!(VoteLocks::<Test>::get(VOTER).is_empty());
+        assert_eq!(VotingPowerSnapshots::<Test>::iter_prefix(1).count(), 0);
+        assert!(!OpenProposals::<Test>::get().contains(&1));
+        assert_err!(
+            GovernanceMod::vote_proposal(get_origin(VOTER), 1, false),
+            Error::<Test>::ProposalClosed
+        );
+
+        step_block(150);
+        assert_eq!(
+            Proposals::<Test>::get(1).unwrap().status,
+            ProposalStatus::Cancelled { block: 50 }
+        );
+    });
+}
+
+#[test]
+fn treasury_streams_vest_after_their_cliff() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const VOTER: u32 = 0;
+        const RECIPIENT: u32 = 1;
+
+        register(VOTER, 0, VOTER, to_nano(10));
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        add_balance(treasury, to_nano(100));
+
+        config(1, 100);
+
+        assert_err!(
+            GovernanceMod::add_treasury_stream_proposal(
+                get_origin(VOTER),
+                proposal_metadata(),
+                RECIPIENT,
+                to_nano(50),
+                1_000,
+                1_001
+            ),
+            Error::<Test>::InvalidTreasuryStream
+        );
+        assert_ok!(GovernanceMod::add_treasury_stream_proposal(
+            get_origin(VOTER),
+            proposal_metadata(),
+            RECIPIENT,
+            to_nano(50),
+            1_000,
+            200
+        ));
+        vote(VOTER, 0, true);
+
+        step_block(100);
+
+        // The streamed amount is reserved on the treasury
+        assert_eq!(TreasuryStreams::<Test>::get(0).unwrap().start_block, 100);
+        assert_eq!(get_balance(treasury), to_nano(50));
+
+        assert_err!(
+            GovernanceMod::claim_stream(get_origin(VOTER), 0),
+            Error::<Test>::NotStreamRecipient
+        );
+
+        step_block(150);
+        assert_err!(
+            GovernanceMod::claim_stream(get_origin(RECIPIENT), 0),
+            Error::<Test>::NothingToClaim
+        );
+
+        // What vested during the cliff is paid at once
+        step_block(100);
+        assert_ok!(GovernanceMod::claim_stream(get_origin(RECIPIENT), 0));
+        assert_eq!(get_balance(RECIPIENT), 12_500_000_000);
+        assert_eq!(
+            TreasuryStreams::<Test>::get(0).unwrap().claimed,
+            12_500_000_000
+        );
+
+        step_block(1_000);
+        assert_ok!(GovernanceMod::claim_stream(get_origin(RECIPIENT), 0));
+        assert_eq!(get_balance(RECIPIENT), to_nano(50));
+        assert!(!TreasuryStreams::<Test>::contains_key(0));
+        assert_eq!(get_balance(treasury), to_nano(50));
+    });
+}
+
+#[test]
+fn cancelled_treasury_streams_return_the_remainder() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const VOTER: u32 = 0;
+        const RECIPIENT: u32 = 1;
+
+        register(VOTER, 0, VOTER, to_nano(10));
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        add_balance(treasury, to_nano(100));
+
+        config(1, 100);
+
+        assert_err!(
+            GovernanceMod::add_cancel_treasury_stream_proposal(
+                get_origin(VOTER),
+                proposal_metadata(),
+                0
+            ),
+            Error::<Test>::TreasuryStreamNotFound
+        );
+
+        assert_ok!(GovernanceMod::add_treasury_stream_proposal(
+            get_origin(VOTER),
+            proposal_metadata(),
+            RECIPIENT,
+            to_nano(40),
+            400,
+            0
+        ));
+        vote(VOTER, 0, true);
+        step_block(100);
+
+        step_block(100);
+        assert_ok!(GovernanceMod::claim_stream(get_origin(RECIPIENT), 0));
+        assert_eq!(get_balance(RECIPIENT), to_nano(10));
+
+        assert_ok!(GovernanceMod::add_cancel_treasury_stream_proposal(
+            get_origin(VOTER),
+            proposal_metadata(),
+            0
+        ));
+        vote(VOTER, 1, true);
+        step_block(100);
+
+        // The recipient gets what vested until the cancellation, the treasury the rest
+        assert!(matches!(
+            Proposals::<Test>::get(1).unwrap().status,
+            ProposalStatus::Accepted { block: 300, .. }
+        ));
+        assert!(!TreasuryStreams::<Test>::contains_key(0));
+        assert_eq!(get_balance(RECIPIENT), to_nano(20));
+        assert_eq!(get_balance(treasury), to_nano(80));
+    });
+}
+
+#[test]
+fn cancelling_a_fully_claimed_treasury_stream_is_accepted() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const VOTER: u32 = 0;
+        const RECIPIENT: u32 = 1;
+
+        register(VOTER, 0, VOTER, to_nano(10));
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        add_balance(treasury, to_nano(100));
+
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::add_treasury_stream_proposal(
+            get_origin(VOTER),
+            proposal_metadata(),
+            RECIPIENT,
+            to_nano(40),
+            50,
+            0
+        ));
+        vote(VOTER, 0, true);
+        step_block(100);
+
+        assert_ok!(GovernanceMod::add_cancel_treasury_stream_proposal(
+            get_origin(VOTER),
+            proposal_metadata(),
+            0
+        ));
+        vote(VOTER, 1, true);
+
+        step_block(60);
+        assert_ok!(GovernanceMod::claim_stream(get_origin(RECIPIENT), 0));
+        assert!(!TreasuryStreams::<Test>::contains_key(0));
+
+        // The stream is gone, so the cancellation has nothing left to do
+        step_block(40);
+        assert!(matches!(
+            Proposals::<Test>::get(1).unwrap().status,
+            ProposalStatus::Accepted { block: 200, .. }
+        ));
+        assert_eq!(get_balance(RECIPIENT), to_nano(40));
+        assert_eq!(get_balance(treasury), to_nano(60));
+    });
+}
+
+#[test]
+fn treasury_stream_claims_fail_when_the_reserve_falls_short() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const VOTER: u32 = 0;
+        const RECIPIENT: u32 = 1;
+
+        register(VOTER, 0, VOTER, to_nano(10));
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        add_balance(treasury, to_nano(100));
+
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::add_treasury_stream_proposal(
+            get_origin(VOTER),
+            proposal_metadata(),
+            RECIPIENT,
+            to_nano(40),
+            400,
+            0
+        ));
+        vote(VOTER, 0, true);
+        step_block(200);
+
+        // Only part of the vested amount is left reserved on the treasury
+        let _ = Balances::slash_reserved(&treasury, to_nano(35));
+
+        assert_err!(
+            GovernanceMod::claim_stream(get_origin(RECIPIENT), 0),
+            Error::<Test>::InsufficientDaoTreasuryFunds
+        );
+        assert_eq!(TreasuryStreams::<Test>::get(0).unwrap().claimed, 0);
+        assert_eq!(get_balance(RECIPIENT), 0);
+    });
+}
+
+#[test]
+fn votes_weigh_the_voting_power_at_proposal_creation() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const FOR: u32 = 0;
+        const AGAINST: u32 = 1;
+        const STAKER: u32 = 2;
+
+        register(FOR, 0, FOR, to_nano(10));
+        register(AGAINST, 0, AGAINST, to_nano(12));
+        stake(STAKER, FOR, to_nano(5));
+        delegate(STAKER, FOR, DelegationScope::All);
+
+        config(1, 200);
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(FOR),
+            proposal_metadata()
+        ));
+
+        // Stake added by the delegator after the proposal was created is not counted
+        stake(STAKER, AGAINST, to_nano(10));
+        assert_eq!(GovernanceMod::get_voting_power(&STAKER), to_nano(15));
+        assert_eq!(
+            GovernanceMod::get_proposal_voting_power(0, &STAKER),
+            to_nano(5)
+        );
+        assert_eq!(
+            GovernanceMod::get_proposal_voting_power(0, &AGAINST),
+            to_nano(12)
+        );
+
+        vote(FOR, 0, true);
+        vote(AGAINST, 0, false);
+
+        // Stake added after voting does not change the vote either
+        add_balance(AGAINST, to_nano(11));
+        assert_ok!(SubspaceMod::do_add_stake(
+            get_origin(AGAINST),
+            AGAINST,
+            to_nano(10)
+        ));
+
+        step_block(200);
+
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 200,
+                stake_for: to_nano(15),
+                stake_against: to_nano(12),
+            }
+        );
+        assert_eq!(VotingPowerSnapshots::<Test>::iter_prefix(0).count(), 0);
+        assert!(!OpenProposals::<Test>::get().contains(&0));
+    });
+}
+
+#[test]
+fn voting_power_is_checkpointed_once_per_open_proposal() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const MODULE: u32 = 0;
+        const STAKER: u32 = 1;
+
+        register(MODULE, 0, MODULE, to_nano(10));
+        stake(STAKER, MODULE, to_nano(5));
+
+        config(1, 200);
+
+        let propose = || {
+            assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+                get_origin(MODULE),
+                proposal_metadata()
+            ));
+        };
+
+        propose();
+        stake(STAKER, MODULE, to_nano(10));
+        // No proposal was opened since the last checkpoint, so this change records nothing
+        stake(STAKER, MODULE, to_nano(10));
+        propose();
+        stake(STAKER, MODULE, to_nano(10));
+        propose();
+
+        assert_eq!(VotingPowerCheckpoints::<Test>::get(STAKER).len(), 2);
+        assert_eq!(
+            GovernanceMod::get_proposal_voting_power(0, &STAKER),
+            to_nano(5)
+        );
+        assert_eq!(
+            GovernanceMod::get_proposal_voting_power(1, &STAKER),
+            to_nano(25)
+        );
+        assert_eq!(
+            GovernanceMod::get_proposal_voting_power(2, &STAKER),
+            to_nano(35)
+        );
+
+        // Checkpoints no open proposal needs are dropped on the next change
+        step_block(200);
+        assert!(OpenProposals::<Test>::get().is_empty());
+        stake(STAKER, MODULE, to_nano(10));
+        assert!(!VotingPowerCheckpoints::<Test>::contains_key(STAKER));
+    });
+}
+
+#[test]
+fn delegated_voting_power_moves_one_hop_to_the_delegate() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const A: u32 = 0;
+        const B: u32 = 1;
+        const C: u32 = 2;
+        const D: u32 = 3;
+
+        register(C, 0, C, to_nano(20));
+        register(D, 0, D, to_nano(7));
+        stake(A, C, to_nano(10));
+        stake(B, C, to_nano(5));
+
+        delegate(A, B, DelegationScope::All);
+        delegate(B, C, DelegationScope::All);
+        assert_eq!(
+            VotingPowerDelegations::<Test>::get(A, DelegationScope::All),
+            Some(B)
+        );
+        assert_eq!(
+            DelegatedVotingPower::<Test>::get(B, DelegationScope::All).delegated,
+            to_nano(10)
+        );
+
+        assert_err!(
+            GovernanceMod::delegate_voting_power(get_origin(A), A, DelegationScope::All),
+            Error::<Test>::DelegationCycle
+        );
+
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(C),
+            proposal_metadata()
+        ));
+
+        // The power A delegated to B doesn't flow further to C
+        vote(C, 0, true);
+        vote(D, 0, false);
+
+        let ProposalStatus::Open {
+            stake_for,
+            stake_against,
+            ..
+        } = Proposals::<Test>::get(0).unwrap().status
+        else {
+            panic!("proposal should be open");
+        };
+        assert_eq!((stake_for, stake_against), (to_nano(25), to_nano(7)));
+
+        // Voting directly takes the power back from the delegate
+        vote(B, 0, false);
+
+        let ProposalStatus::Open {
+            stake_for,
+            stake_against,
+            ..
+        } = Proposals::<Test>::get(0).unwrap().status
+        else {
+            panic!("proposal should be open");
+        };
+        assert_eq!((stake_for, stake_against), (to_nano(20), to_nano(22)));
+
+        // Undelegating after the proposal was created doesn't change it
+        assert_ok!(GovernanceMod::undelegate_voting_power(
+            get_origin(A),
+            DelegationScope::All
+        ));
+        assert_err!(
+            GovernanceMod::undelegate_voting_power(get_origin(A), DelegationScope::All),
+            Error::<Test>::NotDelegating
+        );
+        assert_eq!(GovernanceMod::get_vote_weight(0, &B, None), to_nano(15));
+
+        // A still takes its power back from the delegate it had when the proposal was created
+        vote(A, 0, true);
+
+        step_block(100);
+
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 100,
+                stake_for: to_nano(30),
+                stake_against: to_nano(12),
+            }
+        );
+    });
+}
+
+#[test]
+fn voting_power_is_delegated_on_a_bounded_number_of_scopes() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const DELEGATOR: u32 = 0;
+        const DELEGATE: u32 = 1;
+        const OTHER_DELEGATE: u32 = 2;
+
+        delegate(DELEGATOR, DELEGATE, DelegationScope::All);
+        for subnet_id in 1..MAX_DELEGATION_SCOPES as u16 {
+            delegate(DELEGATOR, DELEGATE, DelegationScope::Subnet(subnet_id));
+        }
+
+        assert_err!(
+            GovernanceMod::delegate_voting_power(
+                get_origin(DELEGATOR),
+                DELEGATE,
+                DelegationScope::Global
+            ),
+            Error::<Test>::TooManyDelegations
+        );
+
+        // Replacing a delegation doesn't add a scope
+        delegate(DELEGATOR, OTHER_DELEGATE, DelegationScope::All);
+        assert_eq!(
+            VotingPowerDelegations::<Test>::get(DELEGATOR, DelegationScope::All),
+            Some(OTHER_DELEGATE)
+        );
+    });
+}
+
+#[test]
+fn conviction_vote_scales_tally_and_locks_stake_until_unlock() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const FOR: u32 = 0;
+        const AGAINST: u32 = 1;
+
+        register(FOR, 0, FOR, to_nano(10));
+        register(AGAINST, 0, AGAINST, to_nano(15));
+
+        config(1, 200);
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(FOR),
+            proposal_metadata()
+        ));
+
+        assert_ok!(GovernanceMod::vote_proposal_with_conviction(
+            get_origin(FOR),
+            0,
+            true,
+            3
+        ));
+        vote(AGAINST, 0, false);
+
+        step_block(100);
+
+        let ProposalStatus::Open {
+            stake_for,
+            stake_against,
+            ..
+        } = Proposals::<Test>::get(0).unwrap().status
+        else {
+            panic!("proposal should still be open");
+        };
+        assert_eq!(stake_for, to_nano(30));
+        assert_eq!(stake_against, to_nano(15));
+
+        // The lock lasts three proposal durations after the expiration block
+        let unlock_block = 200 + 3 * 200;
+        assert_eq!(GovernanceMod::get_locked_stake(&FOR), to_nano(10));
+        assert_err!(
+            SubspaceMod::do_remove_stake(get_origin(FOR), FOR, to_nano(1)),
+            pallet_subspace::Error::<Test>::StakeIsLocked
+        );
+        assert_ok!(SubspaceMod::do_remove_stake(
+            get_origin(AGAINST),
+            AGAINST,
+            to_nano(1)
+        ));
+
+        // Votes keep the voting power the voter had when the proposal was created
+        step_block(100);
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 200,
+                stake_for: to_nano(30),
+                stake_against: to_nano(15),
+            }
+        );
+
+        run_to_block(unlock_block - 1);
+        assert_err!(
+            SubspaceMod::do_remove_stake(get_origin(FOR), FOR, to_nano(1)),
+            pallet_subspace::Error::<Test>::StakeIsLocked
+        );
+
+        run_to_block(unlock_block);
+        assert_eq!(GovernanceMod::get_locked_stake(&FOR), 0);
+        assert_ok!(SubspaceMod::do_remove_stake(
+            get_origin(FOR),
+            FOR,
+            to_nano(1)
+        ));
+        assert!(VoteLocks::<Test>::get(FOR).is_empty());
+    });
+}
+
+#[test]
+fn conviction_bonus_ignores_stake_added_after_proposal_creation() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const VOTER: u32 = 0;
+
+        register(VOTER, 0, VOTER, to_nano(10));
+
+        config(1, 200);
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(VOTER),
+            proposal_metadata()
+        ));
+
+        stake(VOTER, VOTER, to_nano(40));
+        assert_ok!(GovernanceMod::vote_proposal_with_conviction(
+            get_origin(VOTER),
+            0,
+            true,
+            MAX_CONVICTION
+        ));
+
+        // Only the stake owned when the proposal was created is locked and multiplied
+        let ProposalStatus::Open { stake_for, .. } = Proposals::<Test>::get(0).unwrap().status
+        else {
+            panic!("proposal should be open");
+        };
+        assert_eq!(stake_for, to_nano(10) * MAX_CONVICTION as u64);
+        assert_eq!(GovernanceMod::get_locked_stake(&VOTER), to_nano(10));
+        assert_eq!(
+            GovernanceMod::get_conviction_bonus(&VOTER, 0),
+            to_nano(10) * (MAX_CONVICTION as u64 - 1)
+        );
+    });
+}
+
+#[test]
+fn conviction_votes_lock_stake_delegated_to_modules() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+
+        const MODULE: u32 = 0;
+        const OTHER_MODULE: u32 = 1;
+        const STAKER: u32 = 2;
+
+        register(MODULE, 0, MODULE, to_nano(10));
+        register(OTHER_MODULE, 0, OTHER_MODULE, to_nano(10));
+        stake(STAKER, MODULE, to_nano(20));
+
+        config(1, 200);
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(MODULE),
+            proposal_metadata()
+        ));
+        assert_ok!(GovernanceMod::vote_proposal_with_conviction(
+            get_origin(STAKER),
+            0,
+            true,
+            1
+        ));
+        assert_eq!(GovernanceMod::get_locked_stake(&STAKER), to_nano(20));
+
+        assert_err!(
+            SubspaceMod::do_remove_stake(get_origin(STAKER), MODULE, to_nano(1)),
+            pallet_subspace::Error::<Test>::StakeIsLocked
+        );
+
+        // Locked stake can move between modules as long as it stays staked
+        assert_ok!(SubspaceMod::do_transfer_stake(
+            get_origin(STAKER),
+            MODULE,
+            OTHER_MODULE,
+            to_nano(5)
+        ));
+        UnbondingPeriod::<Test>::set(10);
+        assert_err!(
+            SubspaceMod::do_transfer_stake(get_origin(STAKER), MODULE, OTHER_MODULE, to_nano(5)),
+            pallet_subspace::Error::<Test>::StakeIsLocked
+        );
+
+        let unlock_block = 200 + 200;
+        assert!(VoteLockExpirations::<Test>::contains_key(
+            unlock_block,
+            STAKER
+        ));
+        run_to_block(unlock_block);
+        assert!(VoteLocks::<Test>::get(STAKER).is_empty());
+        assert!(!VoteLockExpirations::<Test>::contains_key(
+            unlock_block,
+            STAKER
+        ));
+    });
+}
+
+#[test]
+fn removing_a_conviction_vote_releases_its_lock() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const VOTER: u32 = 0;
+
+        register(VOTER, 0, VOTER, to_nano(10));
+
+        config(1, 200);
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(VOTER),
+            proposal_metadata()
+        ));
+
+        assert_err!(
+            GovernanceMod::vote_proposal_with_conviction(get_origin(VOTER), 0, true, 0),
+            Error::<Test>::InvalidConviction
+        );
+        assert_err!(
+            GovernanceMod::vote_proposal_with_conviction(
+                get_origin(VOTER),
+                0,
+                true,
+                MAX_CONVICTION + 1
+            ),
+            Error::<Test>::InvalidConviction
+        );
+
+        assert_ok!(GovernanceMod::vote_proposal_with_conviction(
+            get_origin(VOTER),
+            0,
+            true,
+            MAX_CONVICTION
+        ));
+        assert_eq!(GovernanceMod::get_locked_stake(&VOTER), to_nano(10));
+
+        assert_ok!(GovernanceMod::remove_vote_proposal(get_origin(VOTER), 0));
+        assert_eq!(GovernanceMod::get_locked_stake(&VOTER), 0);
+        assert_ok!(SubspaceMod::do_remove_stake(
+            get_origin(VOTER),
+            VOTER,
+            to_nano(10)
+        ));
+    });
+}
+
+fn accept_runtime_call_proposal(call: RuntimeCall) -> u64 {
+    zero_min_burn();
+
+    const FOR: u32 = 0;
+
+    register(FOR, 0, FOR, to_nano(10));
+
+    config(1, 100);
+
+    assert_ok!(GovernanceMod::add_runtime_call_proposal(
+        get_origin(FOR),
+        proposal_metadata(),
+        Box::new(call)
+    ));
+
+    vote(FOR, 0, true);
+
+    step_block(100);
+
+    assert_eq!(
+        Proposals::<Test>::get(0).unwrap().status,
+        ProposalStatus::Accepted {
+            block: 100,
+            stake_for: to_nano(10),
+            stake_against: 0,
+        }
+    );
+
+    100 + RUNTIME_CALL_ENACTMENT_DELAY
+}
+
+#[test]
+fn runtime_call_proposal_is_dispatched_after_enactment_delay() {
+    new_test_ext().execute_with(|| {
+        let code_hash = H256::repeat_byte(1);
+        let enactment_block =
+            accept_runtime_call_proposal(RuntimeCall::System(TestRuntimeCall::authorize_upgrade {
+                code_hash,
+            }));
+        assert_eq!(
+            ScheduledRuntimeCalls::<Test>::get(0).map(|(block, _)| block),
+            Some(enactment_block)
+        );
+
+        run_to_block(enactment_block - 1);
+        assert!(ScheduledRuntimeCalls::<Test>::get(0).is_some());
+
+        run_to_block(enactment_block);
+        assert!(ScheduledRuntimeCalls::<Test>::get(0).is_none());
+        System::assert_has_event(RuntimeEvent::System(
+            frame_system::Event::UpgradeAuthorized {
+                code_hash,
+                check_version: true,
+            },
+        ));
+        System::assert_has_event(RuntimeEvent::GovernanceMod(
+            pallet_governance::Event::RuntimeCallDispatched(0, Ok(())),
+        ));
+    });
+}
+
+#[test]
+fn runtime_call_proposal_is_not_dispatched_as_root() {
+    new_test_ext().execute_with(|| {
+        const BENEFICIARY: u32 = 5;
+
+        let enactment_block =
+            accept_runtime_call_proposal(RuntimeCall::Balances(BalanceCall::force_set_balance {
+                who: BENEFICIARY,
+                new_free: to_nano(42),
+            }));
+
+        run_to_block(enactment_block);
+        assert_eq!(get_balance(BENEFICIARY), 0);
+        System::assert_has_event(RuntimeEvent::GovernanceMod(
+            pallet_governance::Event::RuntimeCallDispatched(0, Err(DispatchError::BadOrigin)),
+        ));
+    });
+}
+
+#[test]
+fn runtime_call_proposal_waits_for_block_weight() {
+    new_test_ext().execute_with(|| {
+        let enactment_block =
+            accept_runtime_call_proposal(RuntimeCall::System(TestRuntimeCall::remark {
+                remark: vec![0; 32],
+            }));
+
+        run_to_block(enactment_block - 1);
+        System::set_block_number(enactment_block);
+
+        let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+        tick_scheduled_runtime_calls::<Test>(enactment_block, max_block);
+        assert!(ScheduledRuntimeCalls::<Test>::get(0).is_some());
+
+        tick_scheduled_runtime_calls::<Test>(enactment_block, Weight::zero());
+        assert!(ScheduledRuntimeCalls::<Test>::get(0).is_none());
+        System::assert_has_event(RuntimeEvent::GovernanceMod(
+            pallet_governance::Event::RuntimeCallDispatched(0, Ok(())),
+        ));
+    });
+}
+
+#[test]
+fn runtime_call_proposal_rejects_oversized_calls() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        register(0, 0, 0, to_nano(10));
+
+        config(1, 100);
+
+        let call = RuntimeCall::System(TestRuntimeCall::remark {
+            remark: vec![0; MAX_RUNTIME_CALL_SIZE as usize + 1],
+        });
+        assert_err!(
+            GovernanceMod::add_runtime_call_proposal(
+                get_origin(0),
+                proposal_metadata(),
+                Box::new(call)
+            ),
+            Error::<Test>::RuntimeCallTooLarge
+        );
+    });
+}
+
+#[test]
+fn creates_treasury_transfer_proposal_and_transfers() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let origin = get_origin(0);
+        GovernanceMod::add_transfer_dao_treasury_proposal(
+            origin.clone(),
+            proposal_metadata(),
+            to_nano(5),
+            0,
+        )
+        .expect_err("proposal should not be created when treasury does not have enough money");
+
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
+
+        GovernanceMod::add_transfer_dao_treasury_proposal(
+            origin,
+            proposal_metadata(),
+            to_nano(5),
+            0,
+        )
+        .expect("proposal should be created");
+        vote(0, 0, true);
+
+        step_block(100);
+
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(5));
+        assert_eq!(get_balance(0), to_nano(8));
+    });
+}
+
+/// This test, observes the distribution of governance reward logic over time.
+#[test]
+fn rewards_wont_exceed_treasury() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        // Fill the governance address with 1 mil so we are not limited by the max allocation
+        let amount = to_nano(1_000_000_000);
+        let key = DaoTreasuryAddress::<Test>::get();
+        add_balance(key, amount);
+
+        let governance_config: GovernanceConfiguration = GlobalGovernanceConfig::<Test>::get();
+        let n = 0;
+        let allocation = get_reward_allocation::<Test>(&governance_config, n).unwrap();
+        assert_eq!(
+            FixedI128::<U32>::saturating_from_num(allocation),
+            governance_config.max_proposal_reward_treasury_allocation
+        );
+    });
+}
+
+#[test]
+fn whitelist_executes_application_correctly() {
+    new_test_ext().execute_with(|| {
+        let key = 0;
+        let adding_key = 1;
+        let mut params = SubspaceMod::global_params();
+        params.curator = key;
+        assert_ok!(SubspaceMod::set_global_params(params));
+
+        let proposal_cost = GeneralSubnetApplicationCost::<Test>::get();
+        let data = "test".as_bytes().to_vec();
+
+        add_balance(key, proposal_cost + 1);
+        // first submit an application
+        let balance_before = SubspaceMod::get_balance_u64(&key);
+
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(key),
+            adding_key,
+            data.clone(),
+        ));
+
+        let balance_after = SubspaceMod::get_balance_u64(&key);
+        assert_eq!(balance_after, balance_before - proposal_cost);
+
+        // Assert that the proposal is initially in the Pending status
+        for (_, value) in CuratorApplications::<Test>::iter() {
+            assert_eq!(value.status, ApplicationStatus::Pending);
+            assert_eq!(value.user_id, adding_key);
+            assert_eq!(value.data, data);
+        }
+
+        // add key to whitelist
+        assert_ok!(GovernanceMod::add_to_whitelist(
+            get_origin(key),
+            adding_key,
+            1,
+        ));
+
+        let balance_after_accept = SubspaceMod::get_balance_u64(&key);
+
+        assert_eq!(balance_after_accept, balance_before);
+
+        // Assert that the proposal is now in the Accepted status
+        for (_, value) in CuratorApplications::<Test>::iter() {
+            assert_eq!(value.status, ApplicationStatus::Accepted);
+            assert_eq!(value.user_id, adding_key);
+            assert_eq!(value.data, data);
+        }
+
+        assert!(GovernanceMod::is_in_legit_whitelist(&adding_key));
+    });
+}
+
+// ----------------
+// Registration
+// ----------------
+
+#[test]
+fn user_is_removed_from_whitelist() {
+    new_test_ext().execute_with(|| {
+        let whitelist_key = 0;
+        let module_key = 1;
+        Curator::<Test>::put(whitelist_key);
+
+        let proposal_cost = Test::get_global_governance_configuration().proposal_cost;
+        let data = "test".as_bytes().to_vec();
+
+        // apply
+        add_balance(whitelist_key, proposal_cost + 1);
+        // first submit an application
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(whitelist_key),
+            module_key,
+            data.clone(),
+        ));
+
+        // Add the module_key to the whitelist
+        assert_ok!(GovernanceMod::add_to_whitelist(
+            get_origin(whitelist_key),
+            module_key,
+            1
+        ));
+        assert!(GovernanceMod::is_in_legit_whitelist(&module_key));
+
+        // Remove the module_key from the whitelist
+        assert_ok!(GovernanceMod::remove_from_whitelist(
+            get_origin(whitelist_key),
+            module_key
+        ));
+        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+    });
+}
+
+#[test]
+fn whitelist_curator_must_be_a_valid_key() {
     new_test_ext().execute_with(|| {
         let whitelist_key = 0;
         let invalid_key = 1;
@@ -630,3 +2017,196 @@ fn whitelist_curator_must_be_a_valid_key() {
         assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
     });
 }
+
+fn v1_governance_config(config: GovernanceConfiguration) -> v1::GovernanceConfiguration {
+    v1::GovernanceConfiguration {
+        proposal_cost: config.proposal_cost,
+        proposal_expiration: config.proposal_expiration,
+        vote_mode: config.vote_mode,
+        proposal_reward_treasury_allocation: config.proposal_reward_treasury_allocation,
+        max_proposal_reward_treasury_allocation: config.max_proposal_reward_treasury_allocation,
+        proposal_reward_interval: config.proposal_reward_interval,
+    }
+}
+
+fn v1_proposal(id: u64, data: v1::ProposalData<Test>) -> v1::Proposal<Test> {
+    let mut votes_for = BoundedBTreeSet::new();
+    votes_for.try_insert(0).unwrap();
+
+    v1::Proposal {
+        id,
+        proposer: 0,
+        expiration_block: 200,
+        data,
+        status: v1::ProposalStatus::Open {
+            votes_for,
+            votes_against: BoundedBTreeSet::new(),
+            stake_for: 0,
+            stake_against: 0,
+        },
+        metadata: BoundedVec::truncate_from(b"old proposal".to_vec()),
+        proposal_cost: 0,
+        creation_block: 0,
+    }
+}
+
+#[test]
+fn migration_keeps_params_proposals_stored_with_the_old_layout() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        register(0, 0, 0, to_nano(10));
+        CommitRevealWeights::<Test>::insert(0, true);
+        UnbondingPeriod::<Test>::set(50);
+
+        let global = SubspaceMod::global_params();
+        let old_global = v1::GlobalParams::<Test> {
+            max_name_length: global.max_name_length,
+            min_name_length: global.min_name_length,
+            max_allowed_subnets: global.max_allowed_subnets,
+            max_allowed_modules: global.max_allowed_modules,
+            max_registrations_per_block: global.max_registrations_per_block,
+            max_allowed_weights: global.max_allowed_weights,
+            floor_delegation_fee: global.floor_delegation_fee,
+            floor_founder_share: global.floor_founder_share,
+            min_weight_stake: global.min_weight_stake,
+            curator: global.curator,
+            general_subnet_application_cost: global.general_subnet_application_cost,
+            subnet_immunity_period: global.subnet_immunity_period,
+            burn_config: global.burn_config.clone(),
+            governance_config: v1_governance_config(global.governance_config.clone()),
+            kappa: 10,
+            rho: global.rho,
+        };
+
+        let subnet = SubspaceMod::subnet_params(0);
+        let old_subnet = v1::SubnetParams::<Test> {
+            founder: subnet.founder,
+            founder_share: subnet.founder_share,
+            immunity_period: subnet.immunity_period,
+            incentive_ratio: subnet.incentive_ratio,
+            max_allowed_uids: subnet.max_allowed_uids,
+            max_allowed_weights: subnet.max_allowed_weights,
+            min_allowed_weights: subnet.min_allowed_weights,
+            max_weight_age: subnet.max_weight_age,
+            name: subnet.name.clone(),
+            metadata: subnet.metadata.clone(),
+            tempo: 500,
+            trust_ratio: subnet.trust_ratio,
+            maximum_set_weight_calls_per_epoch: subnet.maximum_set_weight_calls_per_epoch,
+            bonds_ma: subnet.bonds_ma,
+            target_registrations_interval: subnet.target_registrations_interval,
+            target_registrations_per_interval: subnet.target_registrations_per_interval,
+            max_registrations_per_interval: subnet.max_registrations_per_interval,
+            adjustment_alpha: subnet.adjustment_alpha,
+            min_validator_stake: subnet.min_validator_stake,
+            governance_config: v1_governance_config(subnet.governance_config.clone()),
+        };
+
+        unhashed::put(
+            &GlobalGovernanceConfig::<Test>::hashed_key(),
+            &v1_governance_config(GlobalGovernanceConfig::<Test>::get()),
+        );
+        unhashed::put(
+            &SubnetGovernanceConfig::<Test>::hashed_key_for(0),
+            &v1_governance_config(subnet.governance_config.clone()),
+        );
+        unhashed::put(
+            &Proposals::<Test>::hashed_key_for(0),
+            &v1_proposal(0, v1::ProposalData::GlobalParams(old_global)),
+        );
+        unhashed::put(
+            &Proposals::<Test>::hashed_key_for(1),
+            &v1_proposal(
+                1,
+                v1::ProposalData::SubnetParams {
+                    subnet_id: 0,
+                    params: old_subnet,
+                },
+            ),
+        );
+        StorageVersion::new(1).put::<GovernanceMod>();
+
+        MigrationV2::<Test>::on_runtime_upgrade();
+
+        // The params added since keep their current value
+        let Some(ProposalData::GlobalParams(params)) =
+            Proposals::<Test>::get(0).map(|proposal| proposal.data)
+        else {
+            panic!("global params proposal should survive the migration");
+        };
+        assert_eq!(params.kappa, 10);
+        assert_eq!(params.unbonding_period, 50);
+        assert_eq!(params.subnet_auction_config, global.subnet_auction_config);
+
+        let Some(ProposalData::SubnetParams { subnet_id, params }) =
+            Proposals::<Test>::get(1).map(|proposal| proposal.data)
+        else {
+            panic!("subnet params proposal should survive the migration");
+        };
+        assert_eq!(subnet_id, 0);
+        assert_eq!(params.tempo, 500);
+        assert!(params.commit_reveal_weights);
+        assert_eq!(params.governance_config, subnet.governance_config);
+
+        assert_eq!(OpenProposals::<Test>::get().into_inner(), vec![0, 1]);
+        assert_eq!(StorageVersion::get::<GovernanceMod>(), 2);
+    });
+}
+
+#[test]
+fn implicit_delegations_are_converted_a_few_stakers_per_block() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const MODULE: u32 = 0;
+        const OTHER_MODULE: u32 = 1;
+        const STAKER: u32 = 2;
+        const OPTED_OUT: u32 = 3;
+        const DELEGATING: u32 = 4;
+
+        register(MODULE, 0, MODULE, to_nano(10));
+        register(OTHER_MODULE, 0, OTHER_MODULE, to_nano(10));
+        stake(STAKER, MODULE, to_nano(5));
+        stake(STAKER, OTHER_MODULE, to_nano(8));
+        stake(OPTED_OUT, MODULE, to_nano(5));
+        stake(DELEGATING, MODULE, to_nano(5));
+        delegate(DELEGATING, OTHER_MODULE, DelegationScope::All);
+
+        let mut opted_out = BoundedBTreeSet::new();
+        opted_out.try_insert(OPTED_OUT).unwrap();
+        v1::NotDelegatingVotingPower::<Test>::put(opted_out);
+        ImplicitDelegationsCursor::<Test>::put(BoundedVec::new());
+
+        // Two stakers are converted per call, the cursor resumes after them
+        convert_implicit_delegations::<Test>(2);
+        assert!(ImplicitDelegationsCursor::<Test>::get().is_some());
+        for _ in 0..5 {
+            convert_implicit_delegations::<Test>(2);
+        }
+        assert!(ImplicitDelegationsCursor::<Test>::get().is_none());
+        assert!(!v1::NotDelegatingVotingPower::<Test>::exists());
+
+        // The module holding most of the stake gets the voting power
+        assert_eq!(
+            VotingPowerDelegations::<Test>::get(STAKER, DelegationScope::All),
+            Some(OTHER_MODULE)
+        );
+        assert_eq!(
+            VotingPowerDelegations::<Test>::get(OPTED_OUT, DelegationScope::All),
+            None
+        );
+        assert_eq!(
+            VotingPowerDelegations::<Test>::get(DELEGATING, DelegationScope::All),
+            Some(OTHER_MODULE)
+        );
+        assert_eq!(
+            VotingPowerDelegations::<Test>::get(MODULE, DelegationScope::All),
+            None
+        );
+        assert_eq!(
+            DelegatedVotingPower::<Test>::get(OTHER_MODULE, DelegationScope::All).delegated,
+            to_nano(18)
+        );
+    });
+}
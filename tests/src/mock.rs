@@ -3,12 +3,13 @@
 use frame_support::{
     ensure, parameter_types,
     traits::{Currency, Everything, Get, Hooks},
+    weights::Weight,
     PalletId,
 };
 use frame_system as system;
 use pallet_governance::GlobalGovernanceConfig;
 use pallet_governance_api::*;
-use pallet_subnet_emission_api::{SubnetConsensus, SubnetEmissionApi};
+use pallet_subnet_emission_api::{SubnetConsensus, SubnetEmissionApi, SubnetPricingMechanism};
 use scale_info::prelude::collections::BTreeSet;
 use sp_core::{ConstU16, H256};
 use std::cell::RefCell;
@@ -16,7 +17,7 @@ use std::cell::RefCell;
 use pallet_subspace::{
     subnet::SubnetChangeset, Address, BurnConfig, DefaultKey, DefaultMinValidatorStake,
     DefaultSubnetParams, Dividends, Emission, Incentive, LastUpdate, MaxRegistrationsPerBlock,
-    Name, SubnetBurn, SubnetBurnConfig, SubnetParams, Tempo, TotalStake, N,
+    Name, SubnetBurn, SubnetBurnConfig, SubnetParams, Tempo, TotalStake, TotalUnbonding, N,
 };
 use sp_runtime::{
     traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
@@ -85,12 +86,8 @@ impl GovernanceApi<<Test as frame_system::Config>::AccountId> for Test {
         SubspacePalletId::get().into_account_truncating()
     }
 
-    fn is_delegating_voting_power(_delegator: &AccountId) -> bool {
-        false
-    }
-
-    fn update_delegating_voting_power(_delegator: &AccountId, _delegating: bool) -> DispatchResult {
-        Ok(())
+    fn get_locked_stake(key: &AccountId) -> u64 {
+        pallet_governance::Pallet::<Test>::get_locked_stake(key)
     }
 
     fn get_global_governance_configuration() -> GovernanceConfiguration {
@@ -116,6 +113,30 @@ impl GovernanceApi<<Test as frame_system::Config>::AccountId> for Test {
         pallet_governance::Pallet::<Test>::handle_subnet_removal(subnet_id)
     }
 
+    fn handle_module_key_rotation(key: &AccountId, new_key: &AccountId) {
+        pallet_governance::Pallet::<Test>::handle_module_key_rotation(key, new_key)
+    }
+
+    fn has_governance_state(key: &AccountId) -> bool {
+        pallet_governance::Pallet::<Test>::has_governance_state(key)
+    }
+
+    fn module_key_rotation_entries(key: &AccountId, limit: u32) -> u32 {
+        pallet_governance::Pallet::<Test>::module_key_rotation_entries(key, limit)
+    }
+
+    fn module_key_rotation_weight(entries: u32) -> Weight {
+        <() as pallet_governance::weights::WeightInfo>::rotate_module_key(entries)
+    }
+
+    fn handle_voting_power_change(key: &AccountId, amount: u64, increase: bool) {
+        pallet_governance::Pallet::<Test>::handle_voting_power_change(key, amount, increase)
+    }
+
+    fn voting_power_change_weight() -> Weight {
+        <() as pallet_governance::weights::WeightInfo>::handle_voting_power_change()
+    }
+
     fn execute_application(user_id: &AccountId) -> DispatchResult {
         pallet_governance::Pallet::<Test>::execute_application(user_id)
     }
@@ -210,6 +231,14 @@ impl SubnetEmissionApi for Test {
     ) {
         pallet_subnet_emission::SubnetConsensusType::<Test>::set(netuid, subnet_consensus)
     }
+
+    fn get_subnet_pricing_mechanism() -> SubnetPricingMechanism {
+        pallet_subnet_emission::SubnetPricing::<Test>::get()
+    }
+
+    fn schedule_subnet_pricing_mechanism(mechanism: SubnetPricingMechanism) -> u64 {
+        pallet_subnet_emission::Pallet::<Test>::schedule_subnet_pricing_mechanism(mechanism)
+    }
 }
 
 impl pallet_subnet_emission::Config for Test {
@@ -218,6 +247,7 @@ impl pallet_subnet_emission::Config for Test {
     type Decimals = Decimals;
     type HalvingInterval = HalvingInterval;
     type MaxSupply = MaxSupply;
+    type WeightInfo = ();
 }
 
 impl pallet_governance::Config for Test {
@@ -225,6 +255,8 @@ impl pallet_governance::Config for Test {
     type Currency = Balances;
     type PalletId = SubspacePalletId;
     type WeightInfo = ();
+    type RuntimeCall = RuntimeCall;
+    type ProposalOrigin = RuntimeOrigin;
 }
 
 impl pallet_balances::Config for Test {
@@ -345,10 +377,12 @@ pub fn add_balance(key: AccountId, amount: Balance) {
     let _ = <Balances as Currency<AccountId>>::deposit_creating(&key, amount);
 }
 
-pub fn delegate(account: u32) {
-    assert_ok!(GovernanceMod::enable_vote_power_delegation(get_origin(
-        account
-    )));
+pub fn delegate(account: u32, to: u32, scope: pallet_governance::DelegationScope) {
+    assert_ok!(GovernanceMod::delegate_voting_power(
+        get_origin(account),
+        to,
+        scope
+    ));
 }
 
 #[allow(dead_code)]
@@ -637,18 +671,27 @@ pub fn get_balance(key: AccountId) -> Balance {
 
 pub fn get_total_issuance() -> u64 {
     let total_staked_balance = TotalStake::<Test>::get();
+    let total_unbonding_balance = TotalUnbonding::<Test>::get();
     let total_free_balance = pallet_balances::Pallet::<Test>::total_issuance();
-    total_staked_balance + total_free_balance
+    total_staked_balance + total_unbonding_balance + total_free_balance
 }
 
 pub fn vote(account: u32, proposal_id: u64, agree: bool) {
     assert_ok!(GovernanceMod::do_vote_proposal(
         get_origin(account),
         proposal_id,
-        agree
+        agree.into()
     ));
 }
 
+pub fn proposal_metadata() -> pallet_governance::ProposalMetadata {
+    pallet_governance::ProposalMetadata {
+        title: b"Proposal".to_vec().try_into().unwrap(),
+        cid: b"QmPK1s3pNYLi9ERiq3BDxKa4XosgWwFRQUydHUtz4YgpqB".to_vec().try_into().unwrap(),
+        discussion_url: Some(b"https://forum.communeai.org/t/1".to_vec().try_into().unwrap()),
+    }
+}
+
 pub fn config(proposal_cost: u64, proposal_expiration: u32) {
     GlobalGovernanceConfig::<Test>::set(GovernanceConfiguration {
         proposal_cost,
@@ -6,11 +6,16 @@ use frame_support::{assert_ok, traits::Currency};
 use log::info;
 use pallet_governance::DaoTreasuryAddress;
 use pallet_subnet_emission::{
-    subnet_consensus::yuma::{AccountKey, EmissionMap, ModuleKey, YumaEpoch},
-    PendingEmission, SubnetConsensusType, SubnetEmission, UnitEmission,
+    subnet_consensus::yuma::{
+        AccountKey, EmissionMap, ModuleKey, YumaEpoch, YumaProgress, YumaStage,
+    },
+    EpochQueue, LastEpochEmission, MaxEpochsPerBlock, MultiBlockEpochThreshold, PendingEmission,
+    PendingSubnetPricing, SubnetConsensusType, SubnetEmission, SubnetPricing, UnitEmission,
+    YumaEpochProgress, SUBNET_PRICING_ACTIVATION_DELAY,
 };
-use pallet_subnet_emission_api::SubnetConsensus;
+use pallet_subnet_emission_api::{SubnetConsensus, SubnetPricingMechanism};
 use pallet_subspace::*;
+use parity_scale_codec::Encode;
 
 #[test]
 fn test_dividends_same_stake() {
@@ -1256,3 +1261,706 @@ fn yuma_change_permits() {
         );
     });
 }
+
+#[test]
+fn subnet_pricing_always_emits_the_whole_block_emission() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        assert_ok!(register_module(0, 0, to_nano(1_000), false));
+        assert_ok!(register_module(1, 1, to_nano(2_000), false));
+        assert_ok!(register_module(2, 2, to_nano(4_000), false));
+
+        let emission = 1_000_000_007;
+        let total = |priced: &BTreeMap<u16, u64>| priced.values().sum::<u64>();
+
+        SubnetPricing::<Test>::set(SubnetPricingMechanism::Equal);
+        let priced = SubnetEmissionMod::get_subnet_pricing(emission);
+        assert_eq!(priced.len(), 3);
+        assert_eq!(total(&priced), emission);
+
+        SubnetPricing::<Test>::set(SubnetPricingMechanism::Stake);
+        let priced = SubnetEmissionMod::get_subnet_pricing(emission);
+        assert_eq!(total(&priced), emission);
+        assert!(priced[&0] < priced[&1] && priced[&1] < priced[&2]);
+
+        let table = vec![(0, 1), (1, 2), (5, 3)].try_into().unwrap();
+        SubnetPricing::<Test>::set(SubnetPricingMechanism::Fixed(table));
+        let priced = SubnetEmissionMod::get_subnet_pricing(emission);
+        assert_eq!(total(&priced), emission);
+        assert_eq!(priced[&2], 0);
+        assert_eq!(SubnetEmission::<Test>::get(2), 0);
+        assert_in_range!(priced[&1], emission / 3 * 2, 2);
+    });
+}
+
+#[test]
+fn subnet_pricing_splits_equally_when_the_fixed_table_prices_nothing() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        assert_ok!(register_module(0, 0, to_nano(1_000), false));
+        assert_ok!(register_module(1, 1, to_nano(2_000), false));
+
+        let emission = 1_000_000_001;
+        for table in [vec![(0, 0), (1, 0)], vec![(5, 1)]] {
+            SubnetPricing::<Test>::set(SubnetPricingMechanism::Fixed(table.try_into().unwrap()));
+            let priced = SubnetEmissionMod::get_subnet_pricing(emission);
+            assert_eq!(priced.values().sum::<u64>(), emission);
+            assert_in_range!(priced[&0], emission / 2, 1);
+            assert_in_range!(priced[&1], emission / 2, 1);
+        }
+    });
+}
+
+#[test]
+fn stake_pricing_counts_keys_on_several_subnets_once() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        assert_ok!(register_module(0, 0, to_nano(1_000), false));
+        assert_ok!(register_module(1, 0, 0, false));
+        assert_ok!(register_module(2, 2, to_nano(1_000), false));
+
+        SubnetPricing::<Test>::set(SubnetPricingMechanism::Stake);
+        let priced = SubnetEmissionMod::get_subnet_pricing(1_000_000);
+        assert_eq!(priced[&0], priced[&1]);
+        assert_in_range!(priced[&2], priced[&0] * 2, 2);
+
+        // The subnet stakes are kept until the pricing interval is over
+        SubspaceMod::increase_stake(&2, &2, to_nano(1_000));
+        assert_eq!(SubnetEmissionMod::get_subnet_pricing(1_000_000), priced);
+    });
+}
+
+#[test]
+fn subnet_pricing_switch_takes_effect_at_the_scheduled_block() {
+    new_test_ext().execute_with(|| {
+        let activation_block =
+            SubnetEmissionMod::schedule_subnet_pricing_mechanism(SubnetPricingMechanism::Equal);
+        assert_eq!(
+            activation_block,
+            System::block_number() + SUBNET_PRICING_ACTIVATION_DELAY
+        );
+
+        step_block(SUBNET_PRICING_ACTIVATION_DELAY as u16 - 1);
+        assert_eq!(SubnetPricing::<Test>::get(), SubnetPricingMechanism::Root);
+
+        step_block(1);
+        assert_eq!(SubnetPricing::<Test>::get(), SubnetPricingMechanism::Equal);
+        assert!(PendingSubnetPricing::<Test>::get().is_none());
+    });
+}
+
+#[test]
+fn miner_delegation_fee_shares_incentive_with_stakers() {
+    new_test_ext().execute_with(|| {
+        zero_min_validator_stake();
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        FloorFounderShare::<Test>::put(0);
+
+        // Register general subnet
+        assert_ok!(register_module(0, 10, 1, false));
+
+        let netuid: u16 = 1;
+        let validator = 0;
+        let miner = 1;
+        let staker = 2;
+
+        assert_ok!(register_module(netuid, validator, to_nano(100), false));
+        assert_ok!(register_module(netuid, miner, to_nano(10), false));
+        stake(staker, miner, to_nano(10));
+
+        step_block(1);
+
+        let miner_uid = SubspaceMod::get_uid_for_key(netuid, &miner).unwrap();
+        assert_ok!(SubspaceMod::set_weights(
+            RuntimeOrigin::signed(validator),
+            netuid,
+            vec![miner_uid],
+            vec![u16::MAX],
+        ));
+
+        let staker_share = |emissions: &EmissionMap<Test>| {
+            emissions
+                .get(&ModuleKey(miner))
+                .and_then(|m| m.get(&AccountKey(staker)))
+                .copied()
+                .unwrap_or_default()
+        };
+        let miner_share = |emissions: &EmissionMap<Test>| {
+            emissions
+                .get(&ModuleKey(miner))
+                .and_then(|m| m.get(&AccountKey(miner)))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        // By default the miner keeps its whole incentive
+        let emissions = YumaEpoch::<Test>::new(netuid, to_nano(100)).run().unwrap();
+        assert_eq!(staker_share(&emissions), 0);
+        assert!(miner_share(&emissions) > 0);
+
+        MinerDelegationFee::<Test>::insert(miner, sp_runtime::Percent::from_percent(50));
+
+        let ratio = SubspaceMod::get_ownership_ratios(netuid, &miner)
+            .into_iter()
+            .find(|(key, _)| *key == staker)
+            .map(|(_, ratio)| ratio)
+            .unwrap();
+
+        let emissions = YumaEpoch::<Test>::new(netuid, to_nano(100)).run().unwrap();
+        let total = staker_share(&emissions) + miner_share(&emissions);
+        let expected =
+            (ratio * substrate_fixed::types::I64F64::from_num(total)).to_num::<u64>() / 2;
+
+        assert!(staker_share(&emissions) > 0);
+        assert_in_range!(staker_share(&emissions), expected, 10);
+    });
+}
+
+#[test]
+fn payout_preference_pays_rewards_as_balance() {
+    new_test_ext().execute_with(|| {
+        zero_min_validator_stake();
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        FloorFounderShare::<Test>::put(0);
+
+        // Register general subnet
+        assert_ok!(register_module(0, 10, 1, false));
+
+        let netuid: u16 = 1;
+        let validator = 0;
+        let miner = 1;
+        let staker = 2;
+        let destination = 3;
+
+        assert_ok!(register_module(netuid, validator, to_nano(100), false));
+        assert_ok!(register_module(netuid, miner, to_nano(10), false));
+        stake(staker, validator, to_nano(10));
+
+        step_block(1);
+
+        let miner_uid = SubspaceMod::get_uid_for_key(netuid, &miner).unwrap();
+        assert_ok!(SubspaceMod::set_weights(
+            RuntimeOrigin::signed(validator),
+            netuid,
+            vec![miner_uid],
+            vec![u16::MAX],
+        ));
+
+        assert_ok!(SubspaceMod::set_payout_preference(
+            get_origin(validator),
+            validator,
+            PayoutPreference::PayToBalance
+        ));
+        assert_ok!(SubspaceMod::set_payout_preference(
+            get_origin(staker),
+            staker,
+            PayoutPreference::PayToAccount(destination)
+        ));
+
+        let validator_stake = StakeTo::<Test>::get(validator, validator);
+        let staker_stake = StakeTo::<Test>::get(staker, validator);
+        let miner_stake = StakeTo::<Test>::get(miner, miner);
+        let validator_balance = get_balance(validator);
+        let staker_balance = get_balance(staker);
+        let destination_balance = get_balance(destination);
+
+        let emissions = YumaEpoch::<Test>::new(netuid, to_nano(100)).run().unwrap();
+        let reward = |module: AccountId, account: AccountId| {
+            emissions
+                .get(&ModuleKey(module))
+                .and_then(|m| m.get(&AccountKey(account)))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        let validator_reward = reward(validator, validator);
+        let staker_reward = reward(validator, staker);
+        assert!(validator_reward > 0);
+        assert!(staker_reward > 0);
+
+        // Liquid rewards leave the stake untouched
+        assert_eq!(StakeTo::<Test>::get(validator, validator), validator_stake);
+        assert_eq!(StakeTo::<Test>::get(staker, validator), staker_stake);
+        assert_eq!(get_balance(validator), validator_balance + validator_reward);
+        assert_eq!(get_balance(staker), staker_balance);
+        assert_eq!(
+            get_balance(destination),
+            destination_balance + staker_reward
+        );
+
+        // The miner kept the default preference and compounds its rewards
+        assert_eq!(
+            StakeTo::<Test>::get(miner, miner),
+            miner_stake + reward(miner, miner)
+        );
+
+        assert_ok!(SubspaceMod::set_payout_preference(
+            get_origin(staker),
+            staker,
+            PayoutPreference::Compound
+        ));
+        assert!(!PayoutPreferences::<Test>::contains_key(staker));
+    });
+}
+
+#[test]
+fn records_the_last_epoch_emission_when_enabled() {
+    new_test_ext().execute_with(|| {
+        zero_min_validator_stake();
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        FloorFounderShare::<Test>::put(0);
+
+        // Register general subnet
+        assert_ok!(register_module(0, 10, 1, false));
+
+        let netuid: u16 = 1;
+        let validator = 0;
+        let miner = 1;
+        let staker = 2;
+
+        assert_ok!(register_module(netuid, validator, to_nano(100), false));
+        assert_ok!(register_module(netuid, miner, to_nano(10), false));
+        stake(staker, validator, to_nano(10));
+
+        step_block(1);
+
+        let miner_uid = SubspaceMod::get_uid_for_key(netuid, &miner).unwrap();
+        assert_ok!(SubspaceMod::set_weights(
+            RuntimeOrigin::signed(validator),
+            netuid,
+            vec![miner_uid],
+            vec![u16::MAX],
+        ));
+
+        // Nothing is recorded unless the subnet opts in
+        YumaEpoch::<Test>::new(netuid, to_nano(100)).run().unwrap();
+        assert!(LastEpochEmission::<Test>::get(netuid).is_none());
+
+        update_params!(netuid => { record_epoch_emission: true });
+
+        let emissions = YumaEpoch::<Test>::new(netuid, to_nano(100)).run().unwrap();
+        let record = LastEpochEmission::<Test>::get(netuid).unwrap();
+
+        assert_eq!(record.block, SubspaceMod::get_current_block_number());
+        assert!(!record.truncated);
+        assert_eq!(
+            record.entries.len(),
+            emissions.values().map(|stakers| stakers.len()).sum::<usize>()
+        );
+        assert!(record.entries.windows(2).all(|pair| pair[0].amount >= pair[1].amount));
+
+        for entry in record.entries.iter() {
+            let amount = emissions
+                .get(&ModuleKey(entry.module_key))
+                .and_then(|stakers| stakers.get(&AccountKey(entry.staker)))
+                .copied();
+            assert_eq!(Some(entry.amount), amount);
+        }
+
+        // The staker's share of the dividends went through the delegation fee
+        let staker_entry = record
+            .entries
+            .iter()
+            .find(|entry| entry.module_key == validator && entry.staker == staker)
+            .unwrap();
+        let fee = SubspaceMod::get_delegation_fee(&validator);
+        assert_eq!(
+            staker_entry.fee,
+            fee.mul_floor(staker_entry.amount + staker_entry.fee)
+        );
+        assert!(staker_entry.fee > 0);
+
+        update_params!(netuid => { record_epoch_emission: false });
+        YumaEpoch::<Test>::new(netuid, to_nano(100)).run().unwrap();
+        assert!(LastEpochEmission::<Test>::get(netuid).is_none());
+    });
+}
+
+#[test]
+fn on_initialize_weight_accounts_for_every_subnet() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::Hooks;
+
+        zero_min_burn();
+        assert_ok!(register_module(0, 0, to_nano(10), false));
+
+        // No subnet runs an epoch on block 10
+        let subspace_weight = SubspaceMod::on_initialize(10);
+        let emission_weight = SubnetEmissionMod::on_initialize(10);
+        assert!(subspace_weight.ref_time() > 0);
+        assert!(emission_weight.ref_time() > 0);
+
+        assert_ok!(register_module(1, 1, to_nano(10), false));
+
+        assert!(SubspaceMod::on_initialize(10).ref_time() > subspace_weight.ref_time());
+        assert!(SubnetEmissionMod::on_initialize(10).ref_time() > emission_weight.ref_time());
+
+        // Proposals are only ticked every 100 blocks
+        assert!(
+            GovernanceMod::on_initialize(100).ref_time()
+                > GovernanceMod::on_initialize(101).ref_time()
+        );
+    });
+}
+
+/// Registers treasury subnets, whose epochs pay the whole pending emission to the treasury.
+fn register_treasury_subnets(netuids: core::ops::Range<u16>) {
+    for netuid in netuids {
+        assert_ok!(register_subnet(u32::from(netuid) + 10, netuid));
+        SubnetConsensusType::<Test>::insert(netuid, SubnetConsensus::Treasury);
+    }
+    SubnetPricing::<Test>::set(SubnetPricingMechanism::Equal);
+}
+
+#[test]
+fn epochs_over_the_block_budget_are_deferred_without_losing_emission() {
+    new_test_ext().execute_with(|| {
+        register_treasury_subnets(0..3);
+        // Every subnet is due on block 10
+        for netuid in 0..3 {
+            Tempo::<Test>::insert(netuid, 10 + netuid);
+        }
+        MaxEpochsPerBlock::<Test>::set(1);
+
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        let distributed =
+            || get_balance(treasury) + (0..3).map(PendingEmission::<Test>::get).sum::<u64>();
+        let mut expected = distributed();
+
+        for block in 1..=40 {
+            step_block(1);
+            expected += (0..3).map(SubnetEmission::<Test>::get).sum::<u64>();
+            assert_eq!(
+                distributed(),
+                expected,
+                "emission mismatch on block {block}"
+            );
+
+            match block {
+                9 => assert!((0..3).all(|netuid| PendingEmission::<Test>::get(netuid) > 0)),
+                10 => {
+                    assert_eq!(PendingEmission::<Test>::get(0), 0);
+                    assert!(PendingEmission::<Test>::get(1) > 0);
+                    assert_eq!(EpochQueue::<Test>::get(), vec![1, 2]);
+                }
+                11 => {
+                    assert_eq!(PendingEmission::<Test>::get(1), 0);
+                    assert!(PendingEmission::<Test>::get(2) > 0);
+                    assert_eq!(EpochQueue::<Test>::get(), vec![2]);
+                }
+                12 => {
+                    assert_eq!(PendingEmission::<Test>::get(2), 0);
+                    assert!(EpochQueue::<Test>::get().is_empty());
+                }
+                _ => {}
+            }
+        }
+    });
+}
+
+#[test]
+fn queued_epochs_are_not_queued_twice() {
+    new_test_ext().execute_with(|| {
+        register_treasury_subnets(0..4);
+        // Every subnet is due on every block
+        for netuid in 0..4 {
+            Tempo::<Test>::insert(netuid, 1);
+        }
+        MaxEpochsPerBlock::<Test>::set(1);
+
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        let distributed =
+            || get_balance(treasury) + (0..4).map(PendingEmission::<Test>::get).sum::<u64>();
+        let mut expected = distributed();
+
+        for _ in 0..20 {
+            step_block(1);
+            expected += (0..4).map(SubnetEmission::<Test>::get).sum::<u64>();
+            assert_eq!(distributed(), expected);
+
+            // One epoch runs per block, the others wait for their turn
+            let mut queue = EpochQueue::<Test>::get();
+            assert_eq!(queue.len(), 3);
+            queue.sort();
+            queue.dedup();
+            assert_eq!(queue.len(), 3);
+        }
+
+        // The subnets take turns, so none of them accrues more than 4 blocks of emission
+        for netuid in 0..4 {
+            assert!(
+                PendingEmission::<Test>::get(netuid) <= SubnetEmission::<Test>::get(netuid) * 4
+            );
+        }
+    });
+}
+
+/// Registers 10 modules on subnet 0, the first 3 of them setting weights on the others.
+fn setup_weighted_subnet() -> u16 {
+    let netuid = 0;
+    zero_min_burn();
+    zero_min_validator_stake();
+    MaxRegistrationsPerBlock::<Test>::set(1000);
+    SubnetPricing::<Test>::set(SubnetPricingMechanism::Equal);
+
+    for key in 0..10 {
+        assert_ok!(register_module(
+            netuid,
+            key,
+            to_nano(1_000 + u64::from(key)),
+            false
+        ));
+    }
+    step_block(1);
+
+    let miners: Vec<u16> =
+        (3..10).map(|key| SubspaceMod::get_uid_for_key(netuid, &key).unwrap()).collect();
+    for validator in 0..3u16 {
+        let weights = miners.iter().map(|uid| (uid + validator) % 7 + 1).collect();
+        assert_ok!(SubspaceMod::set_weights(
+            RuntimeOrigin::signed(validator.into()),
+            netuid,
+            miners.clone(),
+            weights
+        ));
+    }
+
+    netuid
+}
+
+type YumaSnapshot = (
+    Vec<Vec<u16>>,
+    Vec<Vec<bool>>,
+    Vec<Vec<u64>>,
+    Vec<Vec<(u16, u16)>>,
+);
+
+/// Everything a Yuma epoch writes to storage, along with the stake of the modules.
+fn yuma_snapshot(netuid: u16) -> YumaSnapshot {
+    let n = N::<Test>::get(netuid);
+    let stakes = (0..n)
+        .map(|uid| {
+            SubspaceMod::get_delegated_stake(&SubspaceMod::get_key_for_uid(netuid, uid).unwrap())
+        })
+        .collect();
+
+    (
+        vec![
+            Consensus::<Test>::get(netuid),
+            Dividends::<Test>::get(netuid),
+            Incentive::<Test>::get(netuid),
+            PruningScores::<Test>::get(netuid),
+            Rank::<Test>::get(netuid),
+            Trust::<Test>::get(netuid),
+            ValidatorTrust::<Test>::get(netuid),
+        ],
+        vec![
+            Active::<Test>::get(netuid),
+            ValidatorPermits::<Test>::get(netuid),
+        ],
+        vec![Emission::<Test>::get(netuid), stakes],
+        (0..n).map(|uid| Bonds::<Test>::get(netuid, uid)).collect(),
+    )
+}
+
+#[test]
+fn multi_block_yuma_stages_match_the_single_block_epoch() {
+    let run = |multi_block: bool| {
+        new_test_ext().execute_with(|| {
+            let netuid = setup_weighted_subnet();
+
+            // The bonds of a previous epoch feed the moving average of the next one
+            YumaEpoch::<Test>::new(netuid, to_nano(100)).run().unwrap();
+            step_block(1);
+
+            let emissions = if multi_block {
+                let mut progress = YumaProgress::<Test>::new(netuid, to_nano(100));
+                let max_size = YumaProgress::<Test>::max_encoded_size(N::<Test>::get(netuid));
+                let mut steps = 1;
+                let emissions = loop {
+                    if let Some(emissions) = progress.step().unwrap() {
+                        break emissions;
+                    }
+                    // Only the per-module values are kept between the stages
+                    assert!(progress.encoded_size() as u32 <= max_size);
+                    steps += 1;
+                };
+                assert_eq!(steps, 4);
+                emissions
+            } else {
+                YumaEpoch::<Test>::new(netuid, to_nano(100)).run().unwrap()
+            };
+
+            (emissions, yuma_snapshot(netuid))
+        })
+    };
+
+    let (single_emissions, single_snapshot) = run(false);
+    let (multi_emissions, multi_snapshot) = run(true);
+
+    assert!(!single_emissions.is_empty());
+    assert_eq!(single_emissions, multi_emissions);
+    assert_eq!(single_snapshot, multi_snapshot);
+}
+
+#[test]
+fn multi_block_yuma_ignores_weights_changed_between_stages() {
+    let run = |multi_block: bool| {
+        new_test_ext().execute_with(|| {
+            let netuid = setup_weighted_subnet();
+            step_block(1);
+
+            let emissions = if multi_block {
+                let mut progress = YumaProgress::<Test>::new(netuid, to_nano(100));
+                assert!(progress.step().unwrap().is_none());
+                assert!(progress.step().unwrap().is_none());
+
+                // The later stages compute from the weights the epoch started with
+                let miners: Vec<u16> = (3..10)
+                    .map(|key| SubspaceMod::get_uid_for_key(netuid, &key).unwrap())
+                    .collect();
+                assert_ok!(SubspaceMod::set_weights(
+                    RuntimeOrigin::signed(0),
+                    netuid,
+                    miners.clone(),
+                    vec![1; miners.len()]
+                ));
+                assert!(progress.is_current());
+
+                loop {
+                    if let Some(emissions) = progress.step().unwrap() {
+                        break emissions;
+                    }
+                }
+            } else {
+                YumaEpoch::<Test>::new(netuid, to_nano(100)).run().unwrap()
+            };
+
+            (emissions, yuma_snapshot(netuid))
+        })
+    };
+
+    let (single_emissions, single_snapshot) = run(false);
+    let (multi_emissions, multi_snapshot) = run(true);
+
+    assert!(!single_emissions.is_empty());
+    assert_eq!(single_emissions, multi_emissions);
+    assert_eq!(single_snapshot, multi_snapshot);
+}
+
+#[test]
+fn multi_block_yuma_follows_modules_deregistered_between_stages() {
+    new_test_ext().execute_with(|| {
+        let netuid = setup_weighted_subnet();
+        step_block(1);
+
+        let mut progress = YumaProgress::<Test>::new(netuid, to_nano(100));
+        assert!(progress.step().unwrap().is_none());
+
+        // The last module takes the uid of the removed one
+        let removed_uid = SubspaceMod::get_uid_for_key(netuid, &3).unwrap();
+        assert_ok!(SubspaceMod::remove_module(netuid, removed_uid, false));
+        assert_eq!(SubspaceMod::get_uid_for_key(netuid, &9), Some(removed_uid));
+        assert!(progress.is_current());
+
+        let emissions = loop {
+            if let Some(emissions) = progress.step().unwrap() {
+                break emissions;
+            }
+        };
+
+        // The values are laid out on the modules left, the removed one is not paid and its
+        // emission stays pending
+        let n = N::<Test>::get(netuid) as usize;
+        assert_eq!(n, 9);
+        assert_eq!(Incentive::<Test>::get(netuid).len(), n);
+        assert_eq!(Emission::<Test>::get(netuid).len(), n);
+        assert_eq!(ValidatorPermits::<Test>::get(netuid).len(), n);
+        assert!(!emissions.contains_key(&ModuleKey(3)));
+        assert!(emissions.contains_key(&ModuleKey(9)));
+        let validator_bonds = Bonds::<Test>::get(netuid, 0);
+        assert!(!validator_bonds.is_empty());
+        assert!(validator_bonds.iter().all(|(uid, _)| usize::from(*uid) < n));
+        assert!(progress.emission_to_drain() < to_nano(100));
+    });
+}
+
+#[test]
+fn multi_block_epochs_past_the_block_budget_wait_for_the_next_block() {
+    new_test_ext().execute_with(|| {
+        setup_weighted_subnet();
+        for key in 10..13 {
+            assert_ok!(register_module(1, key, to_nano(1_000), false));
+        }
+        step_block(1);
+        MaxEpochsPerBlock::<Test>::set(1);
+
+        for netuid in 0..2 {
+            SubnetConsensusType::<Test>::insert(netuid, SubnetConsensus::Yuma);
+            YumaEpochProgress::<Test>::insert(netuid, YumaProgress::<Test>::new(netuid, 1));
+        }
+        let stage =
+            |netuid| YumaEpochProgress::<Test>::get(netuid).map(|progress| progress.stage());
+
+        // A single stage fits the budget of the block
+        step_block(1);
+        let stages = [stage(0), stage(1)];
+        assert!(stages.contains(&Some(YumaStage::Consensus)));
+        assert!(stages.contains(&Some(YumaStage::Prepare)));
+
+        step_block(7);
+        assert!((0..2).all(|netuid| !YumaEpochProgress::<Test>::contains_key(netuid)));
+    });
+}
+
+#[test]
+fn large_subnets_run_yuma_over_several_blocks() {
+    let run = |threshold: u16| {
+        new_test_ext().execute_with(|| {
+            let netuid = setup_weighted_subnet();
+            MultiBlockEpochThreshold::<Test>::set(threshold);
+
+            let epoch_block = (System::block_number() + 1..)
+                .find(|block| SubspaceMod::blocks_until_next_epoch(netuid, *block) == 0)
+                .unwrap();
+            run_to_block(epoch_block - 1);
+            let before = yuma_snapshot(netuid);
+
+            run_to_block(epoch_block);
+            let multi_block = YumaEpochProgress::<Test>::contains_key(netuid);
+            if multi_block {
+                // Nothing is written until the last stage commits the results
+                run_to_block(epoch_block + 2);
+                assert_eq!(yuma_snapshot(netuid), before);
+                let progress = YumaEpochProgress::<Test>::get(netuid).unwrap();
+                assert_eq!(progress.stage(), YumaStage::Commit);
+            }
+
+            run_to_block(epoch_block + 3);
+            assert!(!YumaEpochProgress::<Test>::contains_key(netuid));
+            assert_ne!(yuma_snapshot(netuid), before);
+
+            (
+                multi_block,
+                yuma_snapshot(netuid),
+                PendingEmission::<Test>::get(netuid),
+            )
+        })
+    };
+
+    let (single_block, single_snapshot, single_pending) = run(u16::MAX);
+    let (multi_block, multi_snapshot, multi_pending) = run(1);
+
+    assert!(!single_block);
+    assert!(multi_block);
+    assert_eq!(single_snapshot, multi_snapshot);
+    // The blocks accrued while the epoch was computed stay pending in both cases
+    assert!(multi_pending > 0);
+    assert_eq!(single_pending, multi_pending);
+}
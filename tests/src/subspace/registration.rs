@@ -326,10 +326,12 @@ mod module_validation {
                 SubspaceMod::update_module(
                     origin_0.clone(),
                     subnet,
+                    key_0,
                     name.to_vec(),
                     addr.to_vec(),
                     None,
                     None,
+                    None,
                 )
             });
 
@@ -341,10 +343,12 @@ mod module_validation {
                 SubspaceMod::update_module(
                     origin_1.clone(),
                     subnet,
+                    key_1,
                     name.to_vec(),
                     addr.to_vec(),
                     Some(Percent::from_percent(5)),
                     None,
+                    None,
                 )
             };
 
@@ -589,3 +593,473 @@ fn test_subnet_immunity() {
         );
     });
 }
+
+#[test]
+fn rotate_module_key_keeps_the_module_and_moves_its_state() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (key, new_key, staker, other_module) = (0, 10, 5, 2);
+
+        assert_ok!(register_module(0, key, to_nano(100), false));
+        assert_ok!(register_module(1, key, to_nano(50), false));
+        assert_ok!(register_module(0, other_module, to_nano(100), false));
+        let uids = (
+            SubspaceMod::get_uid_for_key(0, &key).unwrap(),
+            SubspaceMod::get_uid_for_key(1, &key).unwrap(),
+        );
+
+        SubspaceMod::increase_stake(&staker, &key, to_nano(20));
+        SubspaceMod::increase_stake(&key, &other_module, to_nano(30));
+        Metadata::<Test>::insert(0, key, b"metadata".to_vec());
+        DelegationFee::<Test>::insert(key, Percent::from_percent(10));
+        PayoutPreferences::<Test>::insert(key, PayoutPreference::PayToBalance);
+        Bonds::<Test>::insert(0, uids.0, vec![(1, 100)]);
+        RootnetControlDelegation::<Test>::insert(key, other_module);
+        RootnetControlDelegators::<Test>::insert(other_module, key, ());
+        RootnetControlDelegation::<Test>::insert(3, key);
+        RootnetControlDelegators::<Test>::insert(key, 3, ());
+        pallet_governance::LegitWhitelist::<Test>::insert(key, 1);
+        delegate(key, other_module, pallet_governance::DelegationScope::All);
+        delegate(staker, key, pallet_governance::DelegationScope::All);
+
+        let registration_block = RegistrationBlock::<Test>::get(0, uids.0);
+        let total_stake = TotalStake::<Test>::get();
+
+        assert_ok!(SubspaceMod::rotate_module_key(
+            get_origin(key),
+            0,
+            key,
+            new_key,
+            rotation_witness(key)
+        ));
+
+        assert!(!SubspaceMod::is_key_registered_on_any_network(&key));
+        assert_eq!(Uids::<Test>::get(0, new_key), Some(uids.0));
+        assert_eq!(Uids::<Test>::get(1, new_key), Some(uids.1));
+        assert_eq!(Keys::<Test>::get(0, uids.0), Some(new_key));
+        assert_eq!(Keys::<Test>::get(1, uids.1), Some(new_key));
+        assert_eq!(
+            RegistrationBlock::<Test>::get(0, uids.0),
+            registration_block
+        );
+        assert_eq!(Bonds::<Test>::get(0, uids.0), vec![(1, 100)]);
+        assert_eq!(
+            Metadata::<Test>::get(0, new_key),
+            Some(b"metadata".to_vec())
+        );
+        assert_eq!(Metadata::<Test>::get(0, key), None);
+
+        assert_eq!(
+            SubspaceMod::get_delegation_fee(&new_key),
+            Percent::from_percent(10)
+        );
+        assert!(!DelegationFee::<Test>::contains_key(key));
+        assert_eq!(
+            PayoutPreferences::<Test>::get(new_key),
+            PayoutPreference::PayToBalance
+        );
+
+        assert_eq!(
+            SubspaceMod::get_stake_to_module(&new_key, &new_key),
+            to_nano(150)
+        );
+        assert_eq!(
+            SubspaceMod::get_stake_to_module(&staker, &new_key),
+            to_nano(20)
+        );
+        assert_eq!(
+            SubspaceMod::get_stake_to_module(&new_key, &other_module),
+            to_nano(30)
+        );
+        assert_eq!(SubspaceMod::get_delegated_stake(&new_key), to_nano(170));
+        assert_eq!(SubspaceMod::get_delegated_stake(&key), 0);
+        assert_eq!(SubspaceMod::get_owned_stake(&key), 0);
+        assert_eq!(StakeFrom::<Test>::get(other_module, key), 0);
+        assert_eq!(TotalStake::<Test>::get(), total_stake);
+
+        assert_eq!(
+            RootnetControlDelegation::<Test>::get(new_key),
+            Some(other_module)
+        );
+        assert_eq!(RootnetControlDelegation::<Test>::get(3), Some(new_key));
+        assert!(!RootnetControlDelegation::<Test>::contains_key(key));
+        assert!(RootnetControlDelegators::<Test>::contains_key(
+            other_module,
+            new_key
+        ));
+        assert!(RootnetControlDelegators::<Test>::contains_key(new_key, 3));
+        assert_eq!(
+            RootnetControlDelegators::<Test>::iter_prefix(key).count(),
+            0
+        );
+
+        assert_eq!(pallet_governance::LegitWhitelist::<Test>::get(new_key), 1);
+        assert!(!pallet_governance::LegitWhitelist::<Test>::contains_key(
+            key
+        ));
+        let delegations = |key| {
+            pallet_governance::VotingPowerDelegations::<Test>::get(
+                key,
+                pallet_governance::DelegationScope::All,
+            )
+        };
+        assert_eq!(delegations(new_key), Some(other_module));
+        assert_eq!(delegations(staker), Some(new_key));
+        assert_eq!(delegations(key), None);
+        assert_eq!(
+            pallet_governance::VotingPowerDelegators::<Test>::iter_prefix(key).count(),
+            0
+        );
+    });
+}
+
+/// The witness declaring exactly what a rotation of the key moves.
+fn rotation_witness(key: AccountId) -> KeyRotationWitness {
+    KeyRotationWitness {
+        subnets: N::<Test>::iter_keys().count() as u32,
+        stakes: (StakeFrom::<Test>::iter_prefix(key).count()
+            + StakeTo::<Test>::iter_prefix(key).count()) as u32,
+        rootnet_delegators: RootnetControlDelegators::<Test>::iter_prefix(key).count() as u32,
+        governance_entries: pallet_governance::Pallet::<Test>::module_key_rotation_entries(
+            &key,
+            u32::MAX,
+        ),
+    }
+}
+
+#[test]
+fn rotate_module_key_requires_an_unused_key() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        assert_ok!(register_module(0, 0, to_nano(100), false));
+        assert_ok!(register_module(0, 1, to_nano(100), false));
+        SubspaceMod::increase_stake(&2, &1, to_nano(10));
+
+        assert_err!(
+            SubspaceMod::rotate_module_key(get_origin(3), 0, 3, 4, rotation_witness(3)),
+            Error::<Test>::ModuleDoesNotExist
+        );
+        for new_key in [0, 1, 2] {
+            assert_err!(
+                SubspaceMod::rotate_module_key(get_origin(0), 0, 0, new_key, rotation_witness(0)),
+                Error::<Test>::NewKeyInUse
+            );
+        }
+
+        assert_ok!(SubspaceMod::rotate_module_key(
+            get_origin(0),
+            0,
+            0,
+            3,
+            rotation_witness(0)
+        ));
+        assert_eq!(SubspaceMod::get_uid_for_key(0, &3), Some(0));
+    });
+}
+
+#[test]
+fn rotate_module_key_rejects_a_key_with_governance_state() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let (key, delegator, delegate_key, whitelisted, new_key) = (0, 20, 21, 22, 23);
+        assert_ok!(register_module(0, key, to_nano(100), false));
+        delegate(
+            delegator,
+            delegate_key,
+            pallet_governance::DelegationScope::All,
+        );
+        pallet_governance::LegitWhitelist::<Test>::insert(whitelisted, 1);
+
+        for used in [delegator, delegate_key, whitelisted] {
+            assert_err!(
+                SubspaceMod::rotate_module_key(
+                    get_origin(key),
+                    0,
+                    key,
+                    used,
+                    rotation_witness(key)
+                ),
+                Error::<Test>::NewKeyInUse
+            );
+        }
+
+        assert_ok!(SubspaceMod::rotate_module_key(
+            get_origin(key),
+            0,
+            key,
+            new_key,
+            rotation_witness(key)
+        ));
+        assert_eq!(SubspaceMod::get_uid_for_key(0, &new_key), Some(0));
+    });
+}
+
+#[test]
+fn rotate_module_key_moves_pending_unlocks() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        UnbondingPeriod::<Test>::set(10);
+
+        let (key, new_key, staker) = (0, 10, 5);
+        assert_ok!(register_module(0, key, to_nano(100), false));
+        assert_ok!(register_module(0, 1, to_nano(100), false));
+        SubspaceMod::increase_stake(&staker, &1, to_nano(10));
+        assert_ok!(SubspaceMod::remove_stake(get_origin(key), key, to_nano(10)));
+        assert_ok!(SubspaceMod::remove_stake(
+            get_origin(staker),
+            1,
+            to_nano(10)
+        ));
+
+        assert_err!(
+            SubspaceMod::rotate_module_key(get_origin(key), 0, key, staker, rotation_witness(key)),
+            Error::<Test>::NewKeyInUse
+        );
+
+        assert_ok!(SubspaceMod::rotate_module_key(
+            get_origin(key),
+            0,
+            key,
+            new_key,
+            rotation_witness(key)
+        ));
+        assert!(SubspaceMod::get_pending_unlocks(&key).is_empty());
+        let unlocks = SubspaceMod::get_pending_unlocks(&new_key);
+        assert_eq!(unlocks.len(), 1);
+        assert_eq!(unlocks[0].module_key, new_key);
+        assert_eq!(unlocks[0].amount, to_nano(10));
+
+        step_block(10);
+
+        let balance = SubspaceMod::get_balance_u64(&new_key);
+        assert_ok!(SubspaceMod::withdraw_unbonded(get_origin(new_key)));
+        assert_eq!(
+            SubspaceMod::get_balance_u64(&new_key),
+            balance + to_nano(10)
+        );
+    });
+}
+
+#[test]
+fn rotate_module_key_rejects_a_short_witness() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let (key, new_key) = (0, 10);
+        assert_ok!(register_module(0, key, to_nano(100), false));
+        SubspaceMod::increase_stake(&5, &key, to_nano(10));
+        RootnetControlDelegation::<Test>::insert(3, key);
+        RootnetControlDelegators::<Test>::insert(key, 3, ());
+        delegate(5, key, pallet_governance::DelegationScope::All);
+
+        let witness = rotation_witness(key);
+        for short in [
+            KeyRotationWitness {
+                subnets: 0,
+                ..witness
+            },
+            KeyRotationWitness {
+                stakes: witness.stakes - 1,
+                ..witness
+            },
+            KeyRotationWitness {
+                rootnet_delegators: 0,
+                ..witness
+            },
+            KeyRotationWitness {
+                governance_entries: witness.governance_entries - 1,
+                ..witness
+            },
+        ] {
+            assert_noop!(
+                SubspaceMod::rotate_module_key(get_origin(key), 0, key, new_key, short),
+                Error::<Test>::InvalidKeyRotationWitness
+            );
+        }
+
+        assert_ok!(SubspaceMod::rotate_module_key(
+            get_origin(key),
+            0,
+            key,
+            new_key,
+            witness
+        ));
+    });
+}
+
+#[test]
+fn module_controller_alone_manages_the_module() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (netuid, key, controller, new_key) = (0, 0, 1, 2);
+        assert_ok!(register_module(netuid, key, to_nano(100), false));
+
+        let update_metadata = |caller: AccountId, module_key: AccountId| {
+            let params = SubspaceMod::module_params(netuid, &module_key);
+            SubspaceMod::update_module(
+                get_origin(caller),
+                netuid,
+                module_key,
+                params.name,
+                params.address,
+                None,
+                None,
+                Some(b"metadata".to_vec()),
+            )
+        };
+
+        assert_err!(
+            SubspaceMod::set_module_controller(get_origin(controller), key, Some(controller)),
+            Error::<Test>::NotModuleController
+        );
+        assert_ok!(SubspaceMod::set_module_controller(
+            get_origin(key),
+            key,
+            Some(controller)
+        ));
+        assert_eq!(
+            SubspaceMod::module_params(netuid, &key).controller,
+            controller
+        );
+
+        // The module key can only set weights from now on
+        assert_err!(
+            update_metadata(key, key),
+            Error::<Test>::NotModuleController
+        );
+        assert_err!(
+            SubspaceMod::deregister(get_origin(key), netuid, key),
+            Error::<Test>::NotModuleController
+        );
+        assert_err!(
+            SubspaceMod::rotate_module_key(
+                get_origin(key),
+                netuid,
+                key,
+                new_key,
+                rotation_witness(key)
+            ),
+            Error::<Test>::NotModuleController
+        );
+        assert_err!(
+            SubspaceMod::set_module_controller(get_origin(key), key, None),
+            Error::<Test>::NotModuleController
+        );
+        assert_err!(
+            register_module(1, key, to_nano(10), false),
+            Error::<Test>::NotModuleController
+        );
+
+        assert_ok!(update_metadata(controller, key));
+        assert_eq!(
+            Metadata::<Test>::get(netuid, key),
+            Some(b"metadata".to_vec())
+        );
+
+        // The controller follows the module to its new key
+        assert_ok!(SubspaceMod::rotate_module_key(
+            get_origin(controller),
+            netuid,
+            key,
+            new_key,
+            rotation_witness(key)
+        ));
+        assert_eq!(ModuleController::<Test>::get(new_key), Some(controller));
+        assert!(!ModuleController::<Test>::contains_key(key));
+
+        // Clearing the controller hands the module back to its key
+        assert_ok!(SubspaceMod::set_module_controller(
+            get_origin(controller),
+            new_key,
+            None
+        ));
+        assert_eq!(
+            SubspaceMod::module_params(netuid, &new_key).controller,
+            new_key
+        );
+        assert_ok!(SubspaceMod::deregister(
+            get_origin(new_key),
+            netuid,
+            new_key
+        ));
+        assert!(!SubspaceMod::is_key_registered_on_any_network(&new_key));
+    });
+}
+
+#[test]
+fn module_key_with_a_controller_cannot_move_stake() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        let (netuid, key, controller, other_module, destination) = (0, 0, 1, 2, 3);
+        assert_ok!(register_module(netuid, key, to_nano(100), false));
+        assert_ok!(register_module(netuid, other_module, to_nano(100), false));
+        add_balance(key, to_nano(10));
+        assert_ok!(SubspaceMod::set_module_controller(
+            get_origin(key),
+            key,
+            Some(controller)
+        ));
+
+        let refused: [DispatchResult; 6] = [
+            SubspaceMod::add_stake(get_origin(key), other_module, to_nano(1)),
+            SubspaceMod::remove_stake(get_origin(key), key, to_nano(1)),
+            SubspaceMod::transfer_stake(get_origin(key), key, other_module, to_nano(1)),
+            SubspaceMod::transfer_multiple(get_origin(key), vec![destination], vec![to_nano(1)]),
+            SubspaceMod::withdraw_unbonded(get_origin(key)),
+            SubspaceMod::set_payout_preference(
+                get_origin(key),
+                key,
+                PayoutPreference::PayToAccount(destination),
+            ),
+        ];
+        for result in refused {
+            assert_err!(result, Error::<Test>::NotModuleController);
+        }
+        assert_eq!(StakeTo::<Test>::get(key, key), to_nano(100));
+        assert!(!PayoutPreferences::<Test>::contains_key(key));
+
+        // The controller sets where the module's rewards go
+        assert_ok!(SubspaceMod::set_payout_preference(
+            get_origin(controller),
+            key,
+            PayoutPreference::PayToBalance
+        ));
+        assert_eq!(
+            PayoutPreferences::<Test>::get(key),
+            PayoutPreference::PayToBalance
+        );
+
+        // Once handed back, the key moves its stake again
+        assert_ok!(SubspaceMod::set_module_controller(
+            get_origin(controller),
+            key,
+            None
+        ));
+        assert_ok!(SubspaceMod::remove_stake(get_origin(key), key, to_nano(1)));
+    });
+}
+
+#[test]
+fn module_controller_is_dropped_with_the_module() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (netuid, key, controller) = (0, 0, 1);
+        assert_ok!(register_module(netuid, key, to_nano(100), false));
+        assert_ok!(SubspaceMod::set_module_controller(
+            get_origin(key),
+            key,
+            Some(controller)
+        ));
+
+        assert_ok!(SubspaceMod::deregister(get_origin(controller), netuid, key));
+        assert!(!ModuleController::<Test>::contains_key(key));
+
+        // A new registration of the key starts without a controller
+        assert_ok!(register_module(netuid, key, to_nano(100), false));
+        assert_eq!(SubspaceMod::module_params(netuid, &key).controller, key);
+    });
+}
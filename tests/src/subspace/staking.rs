@@ -1,6 +1,7 @@
 use crate::mock::*;
 use frame_support::assert_noop;
 use pallet_subspace::*;
+use sp_runtime::Percent;
 use substrate_fixed::types::I64F64;
 
 #[test]
@@ -218,3 +219,205 @@ fn test_ownership_ratio() {
         }
     });
 }
+
+#[test]
+fn removed_stake_is_locked_until_the_unbonding_period_ends() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        UnbondingPeriod::<Test>::set(10);
+
+        let key = 0;
+        let stake_amount = to_nano(10);
+        assert_ok!(register_module(0, key, stake_amount, false));
+        let total_issuance = get_total_issuance();
+
+        assert_ok!(SubspaceMod::remove_stake(
+            get_origin(key),
+            key,
+            stake_amount
+        ));
+        assert_eq!(SubspaceMod::get_owned_stake(&key), 0);
+        assert_eq!(SubspaceMod::get_balance_u64(&key), 1);
+        assert_eq!(TotalStake::<Test>::get(), 0);
+        assert_eq!(TotalUnbonding::<Test>::get(), stake_amount);
+        assert_eq!(get_total_issuance(), total_issuance);
+
+        let unlocks = SubspaceMod::get_pending_unlocks(&key);
+        assert_eq!(unlocks.len(), 1);
+        assert_eq!(unlocks[0].module_key, key);
+        assert_eq!(unlocks[0].amount, stake_amount);
+        assert_eq!(unlocks[0].unlock_block, System::block_number() + 10);
+
+        assert_noop!(
+            SubspaceMod::withdraw_unbonded(get_origin(key)),
+            Error::<Test>::NoUnbondedStake
+        );
+
+        step_block(10);
+
+        assert_ok!(SubspaceMod::withdraw_unbonded(get_origin(key)));
+        assert_eq!(SubspaceMod::get_balance_u64(&key), stake_amount + 1);
+        assert_eq!(TotalUnbonding::<Test>::get(), 0);
+        assert!(SubspaceMod::get_pending_unlocks(&key).is_empty());
+        assert_eq!(get_total_issuance(), total_issuance);
+    });
+}
+
+#[test]
+fn subnet_unbonding_period_overrides_the_global_one() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        UnbondingPeriod::<Test>::set(10);
+
+        let key = 0;
+        assert_ok!(register_module(0, key, to_nano(10), false));
+        assert_eq!(SubspaceMod::get_unbonding_period(&key), 10);
+
+        SubnetUnbondingPeriod::<Test>::set(0, Some(0));
+        assert_eq!(SubspaceMod::get_unbonding_period(&key), 0);
+
+        assert_ok!(SubspaceMod::remove_stake(get_origin(key), key, to_nano(5)));
+        assert_eq!(SubspaceMod::get_balance_u64(&key), to_nano(5) + 1);
+        assert!(SubspaceMod::get_pending_unlocks(&key).is_empty());
+
+        SubnetUnbondingPeriod::<Test>::set(0, Some(20));
+        assert_ok!(SubspaceMod::remove_stake(get_origin(key), key, to_nano(5)));
+        assert_eq!(
+            SubspaceMod::get_pending_unlocks(&key)[0].unlock_block,
+            System::block_number() + 20
+        );
+    });
+}
+
+#[test]
+fn withdraws_only_unlocked_chunks() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        UnbondingPeriod::<Test>::set(10);
+
+        let key = 0;
+        assert_ok!(register_module(0, key, to_nano(10), false));
+
+        assert_ok!(SubspaceMod::remove_stake(get_origin(key), key, to_nano(4)));
+        step_block(5);
+        assert_ok!(SubspaceMod::remove_stake(get_origin(key), key, to_nano(6)));
+        step_block(5);
+
+        assert_ok!(SubspaceMod::withdraw_unbonded(get_origin(key)));
+        assert_eq!(SubspaceMod::get_balance_u64(&key), to_nano(4) + 1);
+
+        let unlocks = SubspaceMod::get_pending_unlocks(&key);
+        assert_eq!(unlocks.len(), 1);
+        assert_eq!(unlocks[0].amount, to_nano(6));
+        assert_eq!(TotalUnbonding::<Test>::get(), to_nano(6));
+    });
+}
+
+#[test]
+fn transferred_stake_unbonds_before_landing_on_the_new_module() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        UnbondingPeriod::<Test>::set(10);
+
+        let (key_1, key_2) = (0, 1);
+        let stake_amount = to_nano(10);
+        assert_ok!(register_module(0, key_1, stake_amount, false));
+        assert_ok!(register_module(0, key_2, 1, false));
+
+        assert_ok!(SubspaceMod::transfer_stake(
+            get_origin(key_1),
+            key_1,
+            key_2,
+            stake_amount,
+        ));
+        assert_eq!(SubspaceMod::get_total_stake_from(&key_1), 0);
+        assert_eq!(SubspaceMod::get_total_stake_from(&key_2), 1);
+        assert_eq!(TotalUnbonding::<Test>::get(), stake_amount);
+
+        let unlocks = SubspaceMod::get_pending_unlocks(&key_1);
+        assert_eq!(unlocks.len(), 1);
+        assert_eq!(unlocks[0].restake_to, Some(key_2));
+
+        step_block(10);
+
+        assert_ok!(SubspaceMod::withdraw_unbonded(get_origin(key_1)));
+        assert_eq!(
+            SubspaceMod::get_stake_to_module(&key_1, &key_2),
+            stake_amount
+        );
+        assert_eq!(SubspaceMod::get_balance_u64(&key_1), 1);
+        assert_eq!(TotalUnbonding::<Test>::get(), 0);
+    });
+}
+
+#[test]
+fn delegation_fee_increase_waits_for_the_notice_period() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let netuid = 0;
+        let key = 0;
+        assert_ok!(register_module(netuid, key, to_nano(10), false));
+        DelegationFeeNoticePeriod::<Test>::set(100);
+
+        let update_fee = |fee: u8| {
+            let params = SubspaceMod::module_params(netuid, &key);
+            SubspaceMod::update_module(
+                get_origin(key),
+                netuid,
+                key,
+                params.name,
+                params.address,
+                Some(Percent::from_percent(fee)),
+                None,
+                None,
+            )
+        };
+
+        assert_ok!(update_fee(20));
+        assert_eq!(
+            SubspaceMod::get_delegation_fee(&key),
+            Percent::from_percent(5)
+        );
+        assert_eq!(
+            SubspaceMod::get_pending_delegation_fee(&key),
+            Some((Percent::from_percent(20), 100))
+        );
+
+        // Resending the current fee keeps the announced increase
+        assert_ok!(update_fee(5));
+        assert_eq!(
+            SubspaceMod::get_pending_delegation_fee(&key),
+            Some((Percent::from_percent(20), 100))
+        );
+
+        step_block(99);
+        assert_eq!(
+            SubspaceMod::get_delegation_fee(&key),
+            Percent::from_percent(5)
+        );
+
+        step_block(1);
+        assert_eq!(
+            SubspaceMod::get_delegation_fee(&key),
+            Percent::from_percent(20)
+        );
+        assert_eq!(SubspaceMod::get_pending_delegation_fee(&key), None);
+        assert_eq!(
+            SubspaceMod::module_params(netuid, &key).delegation_fee,
+            Percent::from_percent(20)
+        );
+
+        // Lowering the fee is applied right away
+        assert_ok!(update_fee(10));
+        assert_eq!(
+            SubspaceMod::get_delegation_fee(&key),
+            Percent::from_percent(10)
+        );
+        assert_eq!(SubspaceMod::get_pending_delegation_fee(&key), None);
+    });
+}
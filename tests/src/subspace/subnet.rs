@@ -64,6 +64,9 @@ fn subnet_update_changes_all_parameter_values() {
             max_registrations_per_interval: 16,
             adjustment_alpha: 17,
             min_validator_stake: to_nano(50_000),
+            unbonding_period: Some(23),
+            commit_reveal_weights: true,
+            record_epoch_emission: true,
             governance_config: GovernanceConfiguration {
                 proposal_cost: 18,
                 proposal_expiration: 19,
@@ -71,6 +74,7 @@ fn subnet_update_changes_all_parameter_values() {
                 proposal_reward_treasury_allocation: Percent::from_parts(20),
                 max_proposal_reward_treasury_allocation: 21,
                 proposal_reward_interval: 22,
+                ..Default::default()
             },
         };
 
@@ -94,6 +98,9 @@ fn subnet_update_changes_all_parameter_values() {
             max_registrations_per_interval,
             adjustment_alpha,
             min_validator_stake,
+            unbonding_period,
+            commit_reveal_weights,
+            record_epoch_emission,
             governance_config,
         } = params.clone();
 
@@ -129,6 +136,15 @@ fn subnet_update_changes_all_parameter_values() {
         );
         assert_eq!(AdjustmentAlpha::<Test>::get(netuid), adjustment_alpha);
         assert_eq!(MinValidatorStake::<Test>::get(netuid), min_validator_stake);
+        assert_eq!(SubnetUnbondingPeriod::<Test>::get(netuid), unbonding_period);
+        assert_eq!(
+            CommitRevealWeights::<Test>::get(netuid),
+            commit_reveal_weights
+        );
+        assert_eq!(
+            RecordEpochEmission::<Test>::get(netuid),
+            record_epoch_emission
+        );
 
         assert_eq!(
             SubnetGovernanceConfig::<Test>::get(netuid),
@@ -257,6 +273,9 @@ fn update_subnet_verifies_names_uniquiness_integrity() {
                 params.max_registrations_per_interval,
                 params.adjustment_alpha,
                 params.min_validator_stake,
+                params.unbonding_period,
+                params.commit_reveal_weights,
+                params.record_epoch_emission,
             )
         };
 
@@ -320,3 +339,591 @@ fn subnet_is_replaced_on_reaching_max_allowed_modules() {
         assert_eq!(*max_netuid, 5);
     });
 }
+
+#[test]
+fn subnet_metagraph_returns_every_module_in_uid_order() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let netuid = 0;
+        let stakes = [to_nano(10), to_nano(20), to_nano(30)];
+        for (key, stake) in stakes.iter().enumerate() {
+            assert_ok!(register_module(netuid, key as u32, *stake, false));
+        }
+
+        Incentive::<Test>::set(netuid, vec![1, 2, 3]);
+        Dividends::<Test>::set(netuid, vec![4, 5, 6]);
+        ValidatorPermits::<Test>::set(netuid, vec![true, false, true]);
+        Emission::<Test>::set(netuid, vec![7, 8, 9]);
+
+        let metagraph = SubspaceMod::get_subnet_metagraph(netuid);
+        assert_eq!(metagraph.len(), stakes.len());
+
+        for (uid, module) in metagraph.iter().enumerate() {
+            let uid = uid as u16;
+            assert_eq!(module.uid, uid);
+            assert_eq!(module.key, uid as u32);
+            assert_eq!(module.name, Name::<Test>::get(netuid, uid));
+            assert_eq!(module.address, Address::<Test>::get(netuid, uid));
+            assert_eq!(module.stake, stakes[uid as usize]);
+            assert_eq!(module.incentive, uid + 1);
+            assert_eq!(module.dividends, uid + 4);
+            assert_eq!(module.validator_permit, uid != 1);
+            assert_eq!(module.emission, uid as u64 + 7);
+        }
+
+        assert!(SubspaceMod::get_subnet_metagraph(netuid + 1).is_empty());
+    });
+}
+
+#[test]
+fn subnet_ownership_is_transferred_once_accepted() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (netuid, founder, new_founder) = (0, 0, 1);
+        assert_ok!(register_module(netuid, founder, to_nano(10), false));
+
+        assert_err!(
+            SubspaceMod::propose_subnet_ownership(get_origin(new_founder), netuid, new_founder),
+            Error::<Test>::NotFounder
+        );
+        assert_err!(
+            SubspaceMod::propose_subnet_ownership(get_origin(founder), netuid, founder),
+            Error::<Test>::AlreadySubnetFounder
+        );
+
+        assert_ok!(SubspaceMod::propose_subnet_ownership(
+            get_origin(founder),
+            netuid,
+            new_founder
+        ));
+        let expiration_block = System::block_number() + SUBNET_OWNERSHIP_OFFER_PERIOD;
+        assert_eq!(
+            PendingSubnetOwnership::<Test>::get(netuid),
+            Some((new_founder, expiration_block))
+        );
+        // Nothing changes until the offer is accepted
+        assert_eq!(Founder::<Test>::get(netuid), founder);
+
+        assert_err!(
+            SubspaceMod::accept_subnet_ownership(get_origin(2), netuid),
+            Error::<Test>::NoSubnetOwnershipOffer
+        );
+
+        step_block(10);
+        assert_ok!(SubspaceMod::accept_subnet_ownership(
+            get_origin(new_founder),
+            netuid
+        ));
+        assert_eq!(Founder::<Test>::get(netuid), new_founder);
+        assert!(!PendingSubnetOwnership::<Test>::contains_key(netuid));
+
+        System::assert_last_event(
+            pallet_subspace::Event::SubnetOwnershipTransferred(netuid, founder, new_founder).into(),
+        );
+    });
+}
+
+#[test]
+fn subnet_ownership_offer_expires() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (netuid, founder, new_founder) = (0, 0, 1);
+        assert_ok!(register_module(netuid, founder, to_nano(10), false));
+
+        assert_ok!(SubspaceMod::propose_subnet_ownership(
+            get_origin(founder),
+            netuid,
+            new_founder
+        ));
+
+        System::set_block_number(System::block_number() + SUBNET_OWNERSHIP_OFFER_PERIOD + 1);
+        assert_err!(
+            SubspaceMod::accept_subnet_ownership(get_origin(new_founder), netuid),
+            Error::<Test>::SubnetOwnershipOfferExpired
+        );
+        assert_eq!(Founder::<Test>::get(netuid), founder);
+
+        // The expired offer is dropped on the next block
+        step_block(1);
+        assert!(!PendingSubnetOwnership::<Test>::contains_key(netuid));
+        System::assert_has_event(
+            pallet_subspace::Event::SubnetOwnershipOfferCancelled(netuid).into(),
+        );
+    });
+}
+
+#[test]
+fn subnet_ownership_offer_can_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (netuid, founder, new_founder) = (0, 0, 1);
+        assert_ok!(register_module(netuid, founder, to_nano(10), false));
+
+        assert_err!(
+            SubspaceMod::cancel_subnet_ownership_offer(get_origin(founder), netuid),
+            Error::<Test>::NoSubnetOwnershipOffer
+        );
+        assert_ok!(SubspaceMod::propose_subnet_ownership(
+            get_origin(founder),
+            netuid,
+            new_founder
+        ));
+        assert_err!(
+            SubspaceMod::cancel_subnet_ownership_offer(get_origin(new_founder), netuid),
+            Error::<Test>::NotFounder
+        );
+
+        assert_ok!(SubspaceMod::cancel_subnet_ownership_offer(
+            get_origin(founder),
+            netuid
+        ));
+        assert!(!PendingSubnetOwnership::<Test>::contains_key(netuid));
+        System::assert_last_event(
+            pallet_subspace::Event::SubnetOwnershipOfferCancelled(netuid).into(),
+        );
+
+        assert_err!(
+            SubspaceMod::accept_subnet_ownership(get_origin(new_founder), netuid),
+            Error::<Test>::NoSubnetOwnershipOffer
+        );
+        assert_eq!(Founder::<Test>::get(netuid), founder);
+    });
+}
+
+#[test]
+fn update_subnet_refuses_founder_changes() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (netuid, founder) = (0, 0);
+        assert_ok!(register_module(netuid, founder, to_nano(10), false));
+
+        let params = SubnetParams {
+            founder: 1,
+            ..SubspaceMod::subnet_params(netuid)
+        };
+        assert_err!(
+            SubnetChangeset::<Test>::update(netuid, params.clone()).map(|_| ()),
+            Error::<Test>::FounderChangeNotAllowed
+        );
+
+        // Governance proposals can still hand the subnet over, voiding pending offers
+        assert_ok!(SubspaceMod::propose_subnet_ownership(
+            get_origin(founder),
+            netuid,
+            2
+        ));
+        assert_ok!(SubnetChangeset::<Test>::update_with_founder(netuid, params)
+            .and_then(|changeset| changeset.apply(netuid)));
+        assert_eq!(Founder::<Test>::get(netuid), 1);
+        assert!(!PendingSubnetOwnership::<Test>::contains_key(netuid));
+    });
+}
+
+#[test]
+fn update_subnet_params_only_changes_patched_fields() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (netuid, founder) = (0, 0);
+        assert_ok!(register_module(netuid, founder, to_nano(10), false));
+
+        let before = SubspaceMod::subnet_params(netuid);
+        let patch = subnet::SubnetParamsPatch::<Test> {
+            tempo: Some(before.tempo + 1),
+            metadata: Some(Some(b"https://example.com".to_vec().try_into().unwrap())),
+            ..Default::default()
+        };
+
+        assert_err!(
+            SubspaceMod::update_subnet_params(get_origin(1), netuid, patch.clone()),
+            Error::<Test>::NotFounder
+        );
+        assert_ok!(SubspaceMod::update_subnet_params(
+            get_origin(founder),
+            netuid,
+            patch
+        ));
+
+        let after = SubspaceMod::subnet_params(netuid);
+        assert_eq!(after.tempo, before.tempo + 1);
+        assert_eq!(after.metadata.as_deref(), Some(&b"https://example.com"[..]));
+        assert_eq!(after.name, before.name);
+        assert_eq!(after.founder_share, before.founder_share);
+        assert_eq!(after.max_allowed_uids, before.max_allowed_uids);
+
+        // Merged params still go through the usual validation
+        assert_err!(
+            SubspaceMod::update_subnet_params(
+                get_origin(founder),
+                netuid,
+                subnet::SubnetParamsPatch {
+                    tempo: Some(0),
+                    ..Default::default()
+                }
+            ),
+            Error::<Test>::InvalidTempo
+        );
+        assert_err!(
+            SubspaceMod::update_subnet_params(
+                get_origin(founder),
+                netuid,
+                subnet::SubnetParamsPatch {
+                    founder: Some(1),
+                    ..Default::default()
+                }
+            ),
+            Error::<Test>::FounderChangeNotAllowed
+        );
+    });
+}
+
+#[test]
+fn scheduled_subnet_update_is_applied_at_its_block() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (netuid, founder) = (0, 0);
+        assert_ok!(register_module(netuid, founder, to_nano(10), false));
+        step_block(1);
+
+        let tempo = Tempo::<Test>::get(netuid);
+        let patch = subnet::SubnetParamsPatch::<Test> {
+            tempo: Some(tempo + 10),
+            ..Default::default()
+        };
+        let at_block = System::block_number() + 5;
+
+        assert_err!(
+            SubspaceMod::schedule_subnet_update(get_origin(1), netuid, patch.clone(), at_block),
+            Error::<Test>::NotFounder
+        );
+        assert_err!(
+            SubspaceMod::schedule_subnet_update(
+                get_origin(founder),
+                netuid,
+                patch.clone(),
+                System::block_number()
+            ),
+            Error::<Test>::InvalidScheduledBlock
+        );
+        assert_err!(
+            SubspaceMod::schedule_subnet_update(
+                get_origin(founder),
+                netuid,
+                subnet::SubnetParamsPatch {
+                    tempo: Some(0),
+                    ..Default::default()
+                },
+                at_block
+            ),
+            Error::<Test>::InvalidTempo
+        );
+
+        assert_ok!(SubspaceMod::schedule_subnet_update(
+            get_origin(founder),
+            netuid,
+            patch.clone(),
+            at_block
+        ));
+        assert_eq!(
+            SubspaceMod::get_scheduled_subnet_update(netuid),
+            Some((at_block, patch))
+        );
+
+        step_block(4);
+        assert_eq!(Tempo::<Test>::get(netuid), tempo);
+
+        step_block(1);
+        assert_eq!(Tempo::<Test>::get(netuid), tempo + 10);
+        assert_eq!(SubspaceMod::get_scheduled_subnet_update(netuid), None);
+        System::assert_has_event(pallet_subspace::Event::SubnetParamsUpdated(netuid).into());
+    });
+}
+
+#[test]
+fn scheduled_subnet_update_can_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let (netuid, founder) = (0, 0);
+        assert_ok!(register_module(netuid, founder, to_nano(10), false));
+        step_block(1);
+
+        let tempo = Tempo::<Test>::get(netuid);
+        let at_block = System::block_number() + 5;
+        assert_ok!(SubspaceMod::schedule_subnet_update(
+            get_origin(founder),
+            netuid,
+            subnet::SubnetParamsPatch {
+                tempo: Some(tempo + 10),
+                ..Default::default()
+            },
+            at_block
+        ));
+
+        assert_err!(
+            SubspaceMod::cancel_scheduled_subnet_update(get_origin(1), netuid),
+            Error::<Test>::NotFounder
+        );
+        assert_ok!(SubspaceMod::cancel_scheduled_subnet_update(
+            get_origin(founder),
+            netuid
+        ));
+        assert_err!(
+            SubspaceMod::cancel_scheduled_subnet_update(get_origin(founder), netuid),
+            Error::<Test>::NoScheduledSubnetUpdate
+        );
+
+        step_block(5);
+        assert_eq!(Tempo::<Test>::get(netuid), tempo);
+
+        // Updates no longer valid at their block are dropped
+        assert_ok!(SubspaceMod::schedule_subnet_update(
+            get_origin(founder),
+            netuid,
+            subnet::SubnetParamsPatch {
+                tempo: Some(tempo + 10),
+                ..Default::default()
+            },
+            System::block_number() + 1
+        ));
+        MaxWeightAge::<Test>::insert(netuid, tempo as u64 + 1);
+
+        step_block(1);
+        assert_eq!(Tempo::<Test>::get(netuid), tempo);
+        assert_eq!(SubspaceMod::get_scheduled_subnet_update(netuid), None);
+        System::assert_has_event(
+            pallet_subspace::Event::ScheduledSubnetUpdateCancelled(netuid).into(),
+        );
+    });
+}
+
+#[test]
+fn subnet_slots_are_auctioned_once_all_are_taken() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::ReservableCurrency;
+
+        zero_min_burn();
+        SubnetImmunityPeriod::<Test>::set(0);
+        assert_ok!(register_module(0, 0, to_nano(10), false));
+        assert_ok!(register_module(1, 1, to_nano(10), false));
+        MaxAllowedSubnets::<Test>::set(2);
+        SubnetAuctionConfig::<Test>::set(global::SubnetAuctionConfiguration {
+            enabled: true,
+            duration: 10,
+            min_bid: to_nano(10),
+            _pd: Default::default(),
+        });
+        step_block(1);
+
+        let (first_bidder, second_bidder) = (10, 11);
+        add_balance(first_bidder, to_nano(50));
+        add_balance(second_bidder, to_nano(50));
+
+        assert_err!(
+            SubspaceMod::register(
+                get_origin(first_bidder),
+                b"auctioned".to_vec(),
+                b"module".to_vec(),
+                b"0.0.0.0:30333".to_vec(),
+                first_bidder,
+                None,
+                None,
+            ),
+            Error::<Test>::SubnetSlotAuctionRequired
+        );
+
+        assert_err!(
+            SubspaceMod::bid_subnet_slot(
+                get_origin(first_bidder),
+                b"auctioned".to_vec(),
+                None,
+                to_nano(5)
+            ),
+            Error::<Test>::SubnetBidTooLow
+        );
+        assert_err!(
+            SubspaceMod::bid_subnet_slot(
+                get_origin(first_bidder),
+                b"test0".to_vec(),
+                None,
+                to_nano(10)
+            ),
+            Error::<Test>::SubnetNameAlreadyExists
+        );
+        assert_ok!(SubspaceMod::bid_subnet_slot(
+            get_origin(first_bidder),
+            b"auctioned".to_vec(),
+            None,
+            to_nano(10)
+        ));
+        assert_eq!(Balances::reserved_balance(first_bidder), to_nano(10));
+        let end_block = System::block_number() + 10;
+        System::assert_has_event(
+            pallet_subspace::Event::SubnetSlotAuctionStarted(end_block).into(),
+        );
+
+        assert_err!(
+            SubspaceMod::bid_subnet_slot(
+                get_origin(second_bidder),
+                b"outbid".to_vec(),
+                None,
+                to_nano(10)
+            ),
+            Error::<Test>::SubnetBidTooLow
+        );
+        assert_ok!(SubspaceMod::bid_subnet_slot(
+            get_origin(second_bidder),
+            b"outbid".to_vec(),
+            None,
+            to_nano(20)
+        ));
+        // The outbid bidder is refunded right away
+        assert_eq!(Balances::reserved_balance(first_bidder), 0);
+        assert_eq!(get_balance(first_bidder), to_nano(50));
+        assert_eq!(Balances::reserved_balance(second_bidder), to_nano(20));
+
+        run_to_block(end_block - 1);
+        assert_eq!(SubspaceMod::get_netuid_for_name(b"outbid"), None);
+
+        step_block(1);
+        let netuid = SubspaceMod::get_netuid_for_name(b"outbid").expect("subnet was not created");
+        assert_eq!(Founder::<Test>::get(netuid), second_bidder);
+        assert_eq!(SubspaceMod::get_total_subnets(), 2);
+        assert!(SubnetSlotAuction::<Test>::get().is_none());
+        assert_eq!(Balances::reserved_balance(second_bidder), 0);
+        assert_eq!(get_balance(second_bidder), to_nano(30));
+        System::assert_has_event(
+            pallet_subspace::Event::SubnetSlotAuctionWon(netuid, second_bidder, to_nano(20)).into(),
+        );
+    });
+}
+
+/// Opens the subnet slot auction, through the global params, once `subnets` subnets take every
+/// slot.
+fn setup_subnet_slot_auction(subnets: u16) {
+    zero_min_burn();
+    SubnetImmunityPeriod::<Test>::set(0);
+    for netuid in 0..subnets {
+        assert_ok!(register_module(netuid, netuid as u32, to_nano(10), false));
+        pallet_subnet_emission::SubnetConsensusType::<Test>::insert(
+            netuid,
+            pallet_subnet_emission_api::SubnetConsensus::Yuma,
+        );
+    }
+    // A burn config the global params can be applied with
+    BurnConfig::<Test>::mutate(|config| config.min_burn = to_nano(1));
+
+    let patch = global::GlobalParamsPatch::<Test> {
+        max_allowed_subnets: Some(subnets),
+        subnet_auction_config: global::SubnetAuctionConfigurationPatch {
+            enabled: Some(true),
+            duration: Some(10),
+            min_bid: Some(to_nano(10)),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_ok!(SubspaceMod::set_global_params(
+        patch.merge(SubspaceMod::global_params())
+    ));
+    assert!(SubnetAuctionConfig::<Test>::get().enabled);
+    step_block(1);
+}
+
+#[test]
+fn subnet_auction_config_is_validated() {
+    new_test_ext().execute_with(|| {
+        let params = SubspaceMod::global_params();
+        for patch in [
+            global::SubnetAuctionConfigurationPatch {
+                duration: Some(0),
+                ..Default::default()
+            },
+            global::SubnetAuctionConfigurationPatch {
+                min_bid: Some(0),
+                ..Default::default()
+            },
+        ] {
+            let patch = global::GlobalParamsPatch::<Test> {
+                subnet_auction_config: patch,
+                ..Default::default()
+            };
+            assert_err!(
+                SubspaceMod::check_global_params(&patch.merge(params.clone())),
+                Error::<Test>::InvalidSubnetAuctionConfig
+            );
+        }
+    });
+}
+
+#[test]
+fn subnet_slot_auction_evicts_the_lowest_emission_subnet() {
+    new_test_ext().execute_with(|| {
+        setup_subnet_slot_auction(3);
+
+        let bidder = 10;
+        add_balance(bidder, to_nano(50));
+        assert_ok!(SubspaceMod::bid_subnet_slot(
+            get_origin(bidder),
+            b"auctioned".to_vec(),
+            None,
+            to_nano(10)
+        ));
+        let end_block = System::block_number() + 10;
+
+        run_to_block(end_block - 1);
+        pallet_subnet_emission::SubnetEmission::<Test>::insert(0, to_nano(3));
+        pallet_subnet_emission::SubnetEmission::<Test>::insert(1, to_nano(1));
+        pallet_subnet_emission::SubnetEmission::<Test>::insert(2, to_nano(2));
+        step_block(1);
+
+        // The subnet takes the slot of the lowest emission subnet
+        assert_eq!(SubspaceMod::get_netuid_for_name(b"auctioned"), Some(1));
+        assert_eq!(SubspaceMod::get_netuid_for_name(b"test1"), None);
+        assert_eq!(SubspaceMod::get_netuid_for_name(b"test0"), Some(0));
+        assert_eq!(SubspaceMod::get_netuid_for_name(b"test2"), Some(2));
+        assert_eq!(Founder::<Test>::get(1), bidder);
+        assert_eq!(SubspaceMod::get_total_subnets(), 3);
+    });
+}
+
+#[test]
+fn subnet_slot_auction_refunds_the_bid_if_the_subnet_cant_be_created() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::ReservableCurrency;
+
+        setup_subnet_slot_auction(2);
+
+        let bidder = 10;
+        add_balance(bidder, to_nano(50));
+        let balance = get_balance(bidder);
+        assert_ok!(SubspaceMod::bid_subnet_slot(
+            get_origin(bidder),
+            b"auctioned".to_vec(),
+            None,
+            to_nano(10)
+        ));
+        assert_eq!(Balances::reserved_balance(bidder), to_nano(10));
+        let end_block = System::block_number() + 10;
+
+        // The name is taken while the auction is open
+        assert_ok!(SubspaceMod::update_subnet_params(
+            get_origin(1),
+            1,
+            subnet::SubnetParamsPatch {
+                name: Some(b"auctioned".to_vec().try_into().unwrap()),
+                ..Default::default()
+            }
+        ));
+
+        run_to_block(end_block);
+        assert!(SubnetSlotAuction::<Test>::get().is_none());
+        assert_eq!(SubspaceMod::get_netuid_for_name(b"auctioned"), Some(1));
+        assert_eq!(SubspaceMod::get_netuid_for_name(b"test0"), Some(0));
+        assert_eq!(SubspaceMod::get_total_subnets(), 2);
+        assert_eq!(Balances::reserved_balance(bidder), 0);
+        assert_eq!(get_balance(bidder), balance);
+        System::assert_has_event(
+            pallet_subspace::Event::SubnetSlotAuctionFailed(bidder, to_nano(10)).into(),
+        );
+    });
+}
@@ -329,3 +329,136 @@ fn test_normalize_weights_does_not_mutate_when_sum_not_zero() {
         assert_eq!(expected.len(), result.len(), "Length of weights changed?!");
     });
 }
+
+#[test]
+fn weights_are_revealed_on_the_epoch_after_the_commit() {
+    new_test_ext().execute_with(|| {
+        zero_min_validator_stake();
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+
+        let netuid = 1;
+        assert_ok!(register_module(0, 0, 1, false));
+        assert_ok!(register_module(netuid, 0, 1, false));
+        assert_ok!(register_module(netuid, 1, 1, false));
+        update_params!(netuid => { tempo: 25, commit_reveal_weights: true });
+
+        let (uids, weights, salt) = (vec![1], vec![10], b"salt".to_vec());
+        let hash = SubspaceMod::hash_weights(&0, netuid, &uids, &weights, &salt);
+
+        assert_err!(
+            SubspaceMod::set_weights(get_origin(0), netuid, uids.clone(), weights.clone()),
+            Error::<Test>::CommitRevealWeightsEnabled
+        );
+        assert_err!(
+            SubspaceMod::reveal_weights(
+                get_origin(0),
+                netuid,
+                uids.clone(),
+                weights.clone(),
+                salt.clone()
+            ),
+            Error::<Test>::WeightCommitNotFound
+        );
+
+        assert_ok!(SubspaceMod::commit_weights(get_origin(0), netuid, hash));
+        assert_err!(
+            SubspaceMod::reveal_weights(
+                get_origin(0),
+                netuid,
+                uids.clone(),
+                weights.clone(),
+                salt.clone()
+            ),
+            Error::<Test>::RevealTooEarly
+        );
+
+        step_block(25);
+
+        assert_err!(
+            SubspaceMod::reveal_weights(
+                get_origin(0),
+                netuid,
+                uids.clone(),
+                weights.clone(),
+                b"other salt".to_vec()
+            ),
+            Error::<Test>::InvalidWeightReveal
+        );
+        assert_ok!(SubspaceMod::reveal_weights(
+            get_origin(0),
+            netuid,
+            uids,
+            weights,
+            salt
+        ));
+
+        assert_eq!(Weights::<Test>::get(netuid, 0), vec![(1, u16::MAX)]);
+        assert!(WeightCommits::<Test>::get(netuid, 0).is_none());
+    });
+}
+
+#[test]
+fn plaintext_weights_are_cleared_over_several_blocks() {
+    new_test_ext().execute_with(|| {
+        zero_min_validator_stake();
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        MaxRegistrationsPerBlock::<Test>::set(1000);
+
+        let netuid = 1;
+        let n = PLAINTEXT_WEIGHTS_CLEARED_PER_BLOCK + 10;
+        assert_ok!(register_module(0, 0, 1, false));
+        assert_ok!(register_module(netuid, 0, 1, false));
+        MaxRegistrationsPerInterval::<Test>::insert(netuid, 1000);
+        for key in 1..n {
+            assert_ok!(register_module(netuid, key as u32, 1, false));
+        }
+        for uid in 0..n {
+            Weights::<Test>::insert(netuid, uid, vec![(0, u16::MAX)]);
+        }
+        update_params!(netuid => { commit_reveal_weights: true });
+
+        // The highest uids are cleared first, a bounded number per block
+        step_block(1);
+        assert_eq!(Weights::<Test>::iter_prefix(netuid).count(), 10);
+        assert!(Weights::<Test>::get(netuid, n - 1).is_empty());
+        assert_eq!(Weights::<Test>::get(netuid, 9), vec![(0, u16::MAX)]);
+
+        step_block(1);
+        assert_eq!(Weights::<Test>::iter_prefix(netuid).count(), 0);
+        assert!(!PlaintextWeightsClearing::<Test>::contains_key(netuid));
+    });
+}
+
+#[test]
+fn unrevealed_weight_commits_expire() {
+    new_test_ext().execute_with(|| {
+        zero_min_validator_stake();
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+
+        let netuid = 1;
+        assert_ok!(register_module(0, 0, 1, false));
+        assert_ok!(register_module(netuid, 0, 1, false));
+        assert_ok!(register_module(netuid, 1, 1, false));
+        Weights::<Test>::insert(netuid, 0, vec![(1, u16::MAX)]);
+        update_params!(netuid => { tempo: 25, commit_reveal_weights: true });
+
+        // Plaintext weights are dropped once commit-reveal is enabled
+        step_block(1);
+        assert!(Weights::<Test>::get(netuid, 0).is_empty());
+
+        let (uids, weights, salt) = (vec![1], vec![10], b"salt".to_vec());
+        let hash = SubspaceMod::hash_weights(&0, netuid, &uids, &weights, &salt);
+        assert_ok!(SubspaceMod::commit_weights(get_origin(0), netuid, hash));
+
+        step_block(50);
+
+        assert!(WeightCommits::<Test>::get(netuid, 0).is_none());
+        assert_err!(
+            SubspaceMod::reveal_weights(get_origin(0), netuid, uids, weights, salt),
+            Error::<Test>::WeightCommitNotFound
+        );
+    });
+}